@@ -0,0 +1,68 @@
+use crate::SignatureError;
+
+/// Error returned when one or more signatures in a batch fail to verify.
+///
+/// Each failure is reported alongside the index of the offending `(message, signature)` pair in
+/// the batch that was provided for verification.
+#[derive(Debug)]
+pub struct BatchVerificationError {
+    failures: Vec<(usize, SignatureError)>,
+}
+
+impl BatchVerificationError {
+    /// The index and cause of each signature in the batch which failed to verify, in ascending
+    /// order of index.
+    pub fn failures(&self) -> &[(usize, SignatureError)] {
+        &self.failures
+    }
+
+    /// The indices of the signatures in the batch which failed to verify, in ascending order.
+    pub fn failed_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.failures.iter().map(|(index, _)| *index)
+    }
+
+    pub fn into_failures(self) -> Vec<(usize, SignatureError)> {
+        self.failures
+    }
+
+    pub(crate) fn check(mut failures: Vec<(usize, SignatureError)>) -> Result<(), Self> {
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            failures.sort_by_key(|(index, _)| *index);
+            Err(Self { failures })
+        }
+    }
+}
+
+impl std::fmt::Display for BatchVerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} signature(s) failed to verify", self.failures.len())?;
+        if let Some((index, error)) = self.failures.first() {
+            write!(f, ", first failure at index {index}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BatchVerificationError {}
+
+/// Verify each `(message, signature)` pair in `batch` individually using `verify`, collecting any
+/// failures.
+#[cfg(any(feature = "secp256r1", feature = "secp256k1", feature = "zklogin"))]
+pub(crate) fn verify_each<S, F>(
+    batch: &[(&[u8], S)],
+    verify: F,
+) -> Result<(), BatchVerificationError>
+where
+    F: Fn(&[u8], &S) -> Result<(), SignatureError>,
+{
+    let failures = batch
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (message, signature))| {
+            verify(message, signature).err().map(|error| (index, error))
+        })
+        .collect();
+    BatchVerificationError::check(failures)
+}
@@ -1,3 +1,4 @@
+use crate::BatchVerificationError;
 use crate::SignatureError;
 use crate::Signer;
 use crate::Verifier;
@@ -236,6 +237,91 @@ impl Ed25519Verifier {
     pub fn new() -> Self {
         Self {}
     }
+
+    /// Verify a batch of ed25519 `(message, signature)` pairs.
+    ///
+    /// Signatures whose `R` component is a canonically encoded point which isn't of small order
+    /// are verified together using ed25519 batch verification, which amortizes the cost of
+    /// verification across the batch. All other signatures, e.g. ones with a small order `R`
+    /// component, are verified individually. Only if the batch as a whole fails to verify is each
+    /// of its signatures checked individually in order to determine which ones failed.
+    ///
+    /// Unlike individual verification, which uses the cofactorless `verify_strict`, batch
+    /// verification doesn't reject `R` components or public keys with a torsion component: a
+    /// signature made with such points, which `verify_strict` rejects, is accepted as part of a
+    /// batch with a probability of about 1/8. Only the holder of the private key can produce
+    /// such a signature, so this doesn't allow forging signatures, but a batch may accept a
+    /// signature which individual verification rejects.
+    pub fn verify_batch(
+        &self,
+        batch: &[(&[u8], &UserSignature)],
+    ) -> Result<(), BatchVerificationError> {
+        let mut failures = Vec::new();
+        let mut indices = Vec::with_capacity(batch.len());
+        let mut messages = Vec::with_capacity(batch.len());
+        let mut signatures = Vec::with_capacity(batch.len());
+        let mut verifying_keys = Vec::with_capacity(batch.len());
+
+        for (index, (message, signature)) in batch.iter().enumerate() {
+            match Self::parse_for_batch(signature) {
+                Ok((signature, verifying_key)) if Self::is_batchable(&signature) => {
+                    indices.push(index);
+                    messages.push(*message);
+                    signatures.push(signature);
+                    verifying_keys.push(verifying_key);
+                }
+                Ok((signature, verifying_key)) => {
+                    if let Err(e) = verifying_key.verify_strict(message, &signature) {
+                        failures.push((index, e));
+                    }
+                }
+                Err(e) => failures.push((index, e)),
+            }
+        }
+
+        if !indices.is_empty()
+            && ed25519_dalek::verify_batch(&messages, &signatures, &verifying_keys).is_err()
+        {
+            for (i, index) in indices.into_iter().enumerate() {
+                if let Err(e) = verifying_keys[i].verify_strict(messages[i], &signatures[i]) {
+                    failures.push((index, e));
+                }
+            }
+        }
+
+        BatchVerificationError::check(failures)
+    }
+
+    fn parse_for_batch(
+        signature: &UserSignature,
+    ) -> Result<(ed25519_dalek::Signature, ed25519_dalek::VerifyingKey), SignatureError> {
+        let UserSignature::Simple(SimpleSignature::Ed25519 {
+            signature,
+            public_key,
+        }) = signature
+        else {
+            return Err(SignatureError::from_source("not an ed25519 signature"));
+        };
+
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(public_key.inner())
+            .map_err(SignatureError::from_source)?;
+        if verifying_key.is_weak() {
+            return Err(SignatureError::from_source("weak ed25519 public key"));
+        }
+
+        Ok((
+            ed25519_dalek::Signature::from_bytes(signature.inner()),
+            verifying_key,
+        ))
+    }
+
+    /// Whether `signature` can be batch verified: its `R` component must be canonically encoded
+    /// and not of small order, which `verify_strict` rejects but batch verification would accept.
+    fn is_batchable(signature: &ed25519_dalek::Signature) -> bool {
+        let r = curve25519_dalek::edwards::CompressedEdwardsY(*signature.r_bytes());
+        r.decompress()
+            .is_some_and(|point| point.compress() == r && !point.is_small_order())
+    }
 }
 
 impl Verifier<SimpleSignature> for Ed25519Verifier {
@@ -299,4 +385,96 @@ mod test {
             .verify_personal_message(&message, &signature)
             .unwrap();
     }
+
+    #[proptest]
+    fn batch_verification(signers: [Ed25519PrivateKey; 4], message: Vec<u8>) {
+        let verifier = Ed25519Verifier::default();
+        let signatures = signers
+            .iter()
+            .map(|signer| signer.try_sign(&message).unwrap())
+            .collect::<Vec<UserSignature>>();
+        let mut batch = signatures
+            .iter()
+            .map(|signature| (message.as_slice(), signature))
+            .collect::<Vec<_>>();
+        verifier.verify_batch(&batch).unwrap();
+
+        // Swap in a signature over a different message
+        let other = signers[2].try_sign(b"other message").unwrap();
+        batch[2].1 = &other;
+        let error = verifier.verify_batch(&batch).unwrap_err();
+        assert_eq!(error.failed_indices().collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn batch_verification_rejects_small_order_r() {
+        use sha2::Digest;
+
+        let signer = Ed25519PrivateKey::new([7; Ed25519PrivateKey::LENGTH]);
+        let message = b"message";
+
+        // A signature with the identity as its `R` component, satisfying the cofactorless
+        // verification equation `[s]B = R + [k]A` as `s = k * a`
+        let r = curve25519_dalek::EdwardsPoint::default().compress();
+        let public_key = signer.public_key();
+        let k = curve25519_dalek::Scalar::from_hash(
+            sha2::Sha512::new()
+                .chain_update(r.as_bytes())
+                .chain_update(public_key.inner())
+                .chain_update(message),
+        );
+        let s = k * signer.0.to_scalar();
+        let signature = ed25519_dalek::Signature::from_components(r.to_bytes(), s.to_bytes());
+        let signature = UserSignature::Simple(SimpleSignature::Ed25519 {
+            signature: Ed25519Signature::new(signature.to_bytes()),
+            public_key,
+        });
+
+        let verifier = Ed25519Verifier::default();
+        verifier.verify(message, &signature).unwrap_err();
+
+        let valid = signer.try_sign(message).unwrap();
+        let error = verifier
+            .verify_batch(&[(message, &valid), (message, &signature)])
+            .unwrap_err();
+        assert_eq!(error.failed_indices().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn batch_verification_accepts_torsion_r() {
+        use sha2::Digest;
+
+        let signer = Ed25519PrivateKey::new([7; Ed25519PrivateKey::LENGTH]);
+        let public_key = signer.public_key();
+        let verifier = Ed25519Verifier::default();
+
+        // Signatures whose `R` component has a torsion component, `R = [r]B + T`, satisfy the
+        // cofactored but not the cofactorless verification equation. They are rejected
+        // individually, but accepted in a batch about 1 time out of 8
+        let accepted = (0..64u8).any(|i| {
+            let message = [i];
+            let r = curve25519_dalek::Scalar::from(u64::from(i) + 1);
+            let big_r = (curve25519_dalek::constants::ED25519_BASEPOINT_POINT * r
+                + curve25519_dalek::constants::EIGHT_TORSION[1])
+                .compress();
+            let k = curve25519_dalek::Scalar::from_hash(
+                sha2::Sha512::new()
+                    .chain_update(big_r.as_bytes())
+                    .chain_update(public_key.inner())
+                    .chain_update(message),
+            );
+            let s = r + k * signer.0.to_scalar();
+            let signature =
+                ed25519_dalek::Signature::from_components(big_r.to_bytes(), s.to_bytes());
+            assert!(Ed25519Verifier::is_batchable(&signature));
+
+            let signature = UserSignature::Simple(SimpleSignature::Ed25519 {
+                signature: Ed25519Signature::new(signature.to_bytes()),
+                public_key,
+            });
+            verifier.verify(&message, &signature).unwrap_err();
+            verifier.verify_batch(&[(&message, &signature)]).is_ok()
+        });
+        assert!(accepted);
+    }
 }
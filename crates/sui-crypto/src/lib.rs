@@ -1,5 +1,7 @@
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 
+use std::future::Future;
+
 use sui_sdk_types::types::PersonalMessage;
 use sui_sdk_types::types::Transaction;
 use sui_sdk_types::types::UserSignature;
@@ -79,6 +81,65 @@ pub mod multisig;
 #[doc(inline)]
 pub use multisig::UserSignatureVerifier;
 
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(
+        feature = "ed25519",
+        feature = "secp256r1",
+        feature = "secp256k1",
+        feature = "zklogin"
+    )))
+)]
+pub mod remote;
+
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(
+        feature = "ed25519",
+        feature = "secp256r1",
+        feature = "secp256k1",
+        feature = "zklogin"
+    )))
+)]
+pub mod sponsor;
+
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+mod batch;
+
+#[cfg(any(
+    feature = "ed25519",
+    feature = "secp256r1",
+    feature = "secp256k1",
+    feature = "zklogin"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(
+        feature = "ed25519",
+        feature = "secp256r1",
+        feature = "secp256k1",
+        feature = "zklogin"
+    )))
+)]
+pub use batch::BatchVerificationError;
+
 /// Interface for signing user transactions and messages in Sui
 ///
 /// # Note
@@ -110,6 +171,108 @@ impl<T: Signer<UserSignature>> SuiSigner for T {
     }
 }
 
+/// Marker trait for types which are `Send` on all targets except `wasm32`.
+///
+/// Used to bound the futures returned by the asynchronous traits of this crate, whose HTTP
+/// implementations can't produce `Send` futures in the browser.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// Marker trait for types which are `Send` on all targets except `wasm32`.
+///
+/// Used to bound the futures returned by the asynchronous traits of this crate, whose HTTP
+/// implementations can't produce `Send` futures in the browser.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// Marker trait for types which are `Sync` on all targets except `wasm32`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSync: Sync {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+/// Marker trait for types which are `Sync` on all targets except `wasm32`.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSync {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// Asynchronous interface for signing user transactions and messages in Sui
+///
+/// Unlike [`SuiSigner`], a `TransactionSigner` need not hold its private key in memory, allowing
+/// signatures to be produced by a remote service such as a KMS or HSM (see
+/// [`RemoteSigner`](remote::RemoteSigner)). Keys which are held in memory can be used as a
+/// `TransactionSigner` by wrapping them in a [`LocalSigner`].
+///
+/// # Note
+///
+/// Implementors only need to provide [`sign_digest`](TransactionSigner::sign_digest),
+/// `sign_transaction` and `sign_personal_message` handle the proper construction of the signing
+/// message.
+///
+/// The returned futures are `Send` on all targets except `wasm32`, allowing them to be spawned
+/// onto a multi-threaded executor.
+pub trait TransactionSigner: MaybeSend + MaybeSync {
+    /// Sign the provided signing digest, i.e. the hash of an intent message.
+    fn sign_digest(
+        &self,
+        digest: &[u8],
+    ) -> impl Future<Output = Result<UserSignature, SignatureError>> + MaybeSend;
+
+    fn sign_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> impl Future<Output = Result<UserSignature, SignatureError>> + MaybeSend {
+        async move {
+            let msg = transaction.signing_digest();
+            self.sign_digest(&msg).await
+        }
+    }
+
+    fn sign_personal_message(
+        &self,
+        message: &PersonalMessage<'_>,
+    ) -> impl Future<Output = Result<UserSignature, SignatureError>> + MaybeSend {
+        async move {
+            let msg = message.signing_digest();
+            self.sign_digest(&msg).await
+        }
+    }
+}
+
+/// Adapter allowing a signer whose private key is held in memory to be used as a
+/// [`TransactionSigner`].
+#[derive(Clone, Debug)]
+pub struct LocalSigner<S>(S);
+
+impl<S> LocalSigner<S> {
+    pub fn new(signer: S) -> Self {
+        Self(signer)
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: Signer<UserSignature> + MaybeSend + MaybeSync> TransactionSigner for LocalSigner<S> {
+    async fn sign_digest(&self, digest: &[u8]) -> Result<UserSignature, SignatureError> {
+        self.0.try_sign(digest)
+    }
+}
+
 /// Interface for verifying user transactions and messages in Sui
 ///
 /// # Note
@@ -150,3 +313,22 @@ impl<T: Verifier<UserSignature>> SuiVerifier for T {
         self.verify(&message, signature)
     }
 }
+
+/// Drive a future which is expected to complete without ever needing to be woken, e.g. one
+/// produced by a signer whose private key is held in memory.
+#[cfg(all(test, feature = "ed25519"))]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    struct NoopWaker;
+
+    impl std::task::Wake for NoopWaker {
+        fn wake(self: std::sync::Arc<Self>) {}
+    }
+
+    let waker = std::task::Waker::from(std::sync::Arc::new(NoopWaker));
+    let mut future = std::pin::pin!(future);
+    let mut context = std::task::Context::from_waker(&waker);
+    match future.as_mut().poll(&mut context) {
+        std::task::Poll::Ready(output) => output,
+        std::task::Poll::Pending => panic!("future was not ready"),
+    }
+}
@@ -1,3 +1,4 @@
+use crate::BatchVerificationError;
 use crate::SignatureError;
 use crate::Verifier;
 use sui_sdk_types::types::MultisigAggregatedSignature;
@@ -206,6 +207,58 @@ impl UserSignatureVerifier {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Verify a batch of `(message, signature)` pairs, reporting which failed.
+    ///
+    /// Ed25519 signatures are verified together using ed25519 batch verification (see
+    /// [`Ed25519Verifier::verify_batch`](crate::ed25519::Ed25519Verifier::verify_batch)) while
+    /// all other signatures are verified individually, with zklogin signatures sharing the
+    /// configured zklogin verifier.
+    pub fn verify_batch(
+        &self,
+        batch: &[(&[u8], &UserSignature)],
+    ) -> Result<(), BatchVerificationError> {
+        let mut failures = Vec::new();
+
+        #[cfg(feature = "ed25519")]
+        let rest = {
+            let (ed25519, rest): (Vec<_>, Vec<_>) =
+                batch
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .partition(|(_, (_, signature))| {
+                        matches!(
+                            signature,
+                            UserSignature::Simple(
+                                sui_sdk_types::types::SimpleSignature::Ed25519 { .. }
+                            )
+                        )
+                    });
+            let (indices, ed25519): (Vec<_>, Vec<_>) = ed25519.into_iter().unzip();
+
+            if let Err(e) = crate::ed25519::Ed25519Verifier::new().verify_batch(&ed25519) {
+                failures.extend(
+                    e.into_failures()
+                        .into_iter()
+                        .map(|(i, error)| (indices[i], error)),
+                );
+            }
+
+            rest
+        };
+        #[cfg(not(feature = "ed25519"))]
+        let rest = batch.iter().copied().enumerate().collect::<Vec<_>>();
+
+        failures.extend(
+            rest.into_iter()
+                .filter_map(|(index, (message, signature))| {
+                    self.verify(message, signature).err().map(|e| (index, e))
+                }),
+        );
+
+        BatchVerificationError::check(failures)
+    }
 }
 
 #[cfg(feature = "zklogin")]
@@ -0,0 +1,248 @@
+//! Signing with keys held by a remote service, such as a KMS or HSM.
+
+use std::future::Future;
+
+use crate::MaybeSend;
+use crate::MaybeSync;
+use crate::SignatureError;
+use crate::Signer;
+use crate::TransactionSigner;
+use crate::Verifier;
+use sui_sdk_types::types::SimpleSignature;
+use sui_sdk_types::types::UserSignature;
+
+/// A service which holds a private key and signs messages on behalf of a client.
+///
+/// An implementation which talks to a signing service over HTTP is provided by
+/// [`HttpSigningService`] (requires the `remote-signer` feature) and an in-memory
+/// implementation, useful for testing, is provided by [`MockSigningService`].
+///
+/// The returned future is `Send` on all targets except `wasm32`.
+pub trait SigningService: MaybeSend + MaybeSync {
+    /// Sign `message` with the private key held by the service.
+    fn sign(
+        &self,
+        message: &[u8],
+    ) -> impl Future<Output = Result<SimpleSignature, SignatureError>> + MaybeSend;
+}
+
+/// A [`TransactionSigner`] which delegates signing to a [`SigningService`].
+///
+/// Every signature returned by the service is verified against the message it was requested
+/// for before being handed back to the caller, guarding against a misconfigured or misbehaving
+/// service. This requires the feature of the key scheme used by the service, and all of them are
+/// enabled by the `remote-signer` feature.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner<C> {
+    service: C,
+}
+
+impl<C> RemoteSigner<C> {
+    pub fn new(service: C) -> Self {
+        Self { service }
+    }
+
+    pub fn service(&self) -> &C {
+        &self.service
+    }
+}
+
+impl<C: SigningService> TransactionSigner for RemoteSigner<C> {
+    async fn sign_digest(&self, digest: &[u8]) -> Result<UserSignature, SignatureError> {
+        let signature = self.service.sign(digest).await?;
+        crate::simple::SimpleVerifier.verify(digest, &signature)?;
+        Ok(UserSignature::Simple(signature))
+    }
+}
+
+/// A [`SigningService`] backed by a private key held in memory.
+///
+/// This mirrors the behavior of a remote signing service without requiring any network access
+/// and is intended for use in tests.
+#[derive(Clone, Debug)]
+pub struct MockSigningService<S> {
+    key: S,
+}
+
+impl<S> MockSigningService<S> {
+    pub fn new(key: S) -> Self {
+        Self { key }
+    }
+}
+
+impl<S: Signer<SimpleSignature> + MaybeSend + MaybeSync> SigningService for MockSigningService<S> {
+    async fn sign(&self, message: &[u8]) -> Result<SimpleSignature, SignatureError> {
+        self.key.try_sign(message)
+    }
+}
+
+#[cfg(feature = "remote-signer")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "remote-signer")))]
+pub use http::HttpSigningService;
+
+#[cfg(feature = "remote-signer")]
+mod http {
+    use super::SigningService;
+    use crate::SignatureError;
+    use sui_sdk_types::types::SimpleSignature;
+    use sui_sdk_types::types::UserSignature;
+
+    #[derive(serde_derive::Serialize)]
+    struct SignRequest {
+        message: String,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct SignResponse {
+        signature: String,
+    }
+
+    /// A [`SigningService`] which requests signatures from a signing service over HTTP.
+    ///
+    /// The message to be signed is POSTed to the service as a JSON object of the form
+    /// `{"message": "<base64 message>"}` and the service is expected to respond with a JSON
+    /// object of the form `{"signature": "<base64 signature>"}`, where the signature is a
+    /// serialized [`SimpleSignature`], i.e. `flag || signature || public key`.
+    #[derive(Clone, Debug)]
+    pub struct HttpSigningService {
+        url: reqwest::Url,
+        bearer_token: Option<String>,
+        inner: reqwest::Client,
+    }
+
+    impl HttpSigningService {
+        /// Create a new client for the signing service at `url`.
+        pub fn new(url: &str) -> Result<Self, SignatureError> {
+            let url = reqwest::Url::parse(url).map_err(SignatureError::from_source)?;
+            Ok(Self {
+                url,
+                bearer_token: None,
+                inner: reqwest::Client::new(),
+            })
+        }
+
+        /// Authenticate requests to the signing service using the provided bearer token.
+        pub fn with_bearer_token(mut self, token: &str) -> Self {
+            self.bearer_token = Some(token.to_owned());
+            self
+        }
+    }
+
+    impl SigningService for HttpSigningService {
+        async fn sign(&self, message: &[u8]) -> Result<SimpleSignature, SignatureError> {
+            use base64ct::Base64;
+            use base64ct::Encoding;
+
+            let request = SignRequest {
+                message: Base64::encode_string(message),
+            };
+            let mut builder = self.inner.post(self.url.clone()).json(&request);
+            if let Some(token) = &self.bearer_token {
+                builder = builder.bearer_auth(token);
+            }
+
+            let response = builder
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(SignatureError::from_source)?
+                .json::<SignResponse>()
+                .await
+                .map_err(SignatureError::from_source)?;
+
+            match UserSignature::from_base64(&response.signature)
+                .map_err(SignatureError::from_source)?
+            {
+                UserSignature::Simple(signature) => Ok(signature),
+                _ => Err(SignatureError::from_source(
+                    "signing service returned an unsupported signature",
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ed25519"))]
+mod test {
+    use super::*;
+    use crate::block_on;
+    use crate::SuiVerifier;
+    use crate::UserSignatureVerifier;
+    use sui_sdk_types::types::PersonalMessage;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    /// A service which signs with a different key than the one it claims to sign with.
+    struct MismatchedService;
+
+    impl SigningService for MismatchedService {
+        async fn sign(&self, message: &[u8]) -> Result<SimpleSignature, SignatureError> {
+            let SimpleSignature::Ed25519 { signature, .. } =
+                crate::ed25519::Ed25519PrivateKey::new([1; 32]).try_sign(message)?
+            else {
+                unreachable!()
+            };
+            Ok(SimpleSignature::Ed25519 {
+                signature,
+                public_key: crate::ed25519::Ed25519PrivateKey::new([2; 32]).public_key(),
+            })
+        }
+    }
+
+    #[test]
+    fn remote_and_local_signers_agree() {
+        let key = crate::ed25519::Ed25519PrivateKey::new([1; 32]);
+        let message = PersonalMessage(b"hello world".into());
+
+        let local = crate::LocalSigner::new(crate::ed25519::Ed25519PrivateKey::new([1; 32]));
+        let remote = RemoteSigner::new(MockSigningService::new(key));
+
+        let local_signature = block_on(local.sign_personal_message(&message)).unwrap();
+        let remote_signature = block_on(remote.sign_personal_message(&message)).unwrap();
+        // ed25519 signatures are deterministic
+        assert_eq!(local_signature, remote_signature);
+
+        UserSignatureVerifier::new()
+            .verify_personal_message(&message, &remote_signature)
+            .unwrap();
+
+        block_on(RemoteSigner::new(MismatchedService).sign_personal_message(&message)).unwrap_err();
+    }
+
+    #[cfg(feature = "remote-signer")]
+    #[test]
+    fn secp_signing_services() {
+        let message = PersonalMessage(b"hello world".into());
+
+        let k1 = RemoteSigner::new(MockSigningService::new(
+            crate::secp256k1::Secp256k1PrivateKey::new([1; 32]).unwrap(),
+        ));
+        let r1 = RemoteSigner::new(MockSigningService::new(
+            crate::secp256r1::Secp256r1PrivateKey::new([1; 32]),
+        ));
+
+        let verifier = UserSignatureVerifier::new();
+        for signature in [
+            block_on(k1.sign_personal_message(&message)).unwrap(),
+            block_on(r1.sign_personal_message(&message)).unwrap(),
+        ] {
+            verifier
+                .verify_personal_message(&message, &signature)
+                .unwrap();
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn futures_are_send() {
+        fn assert_send<T: Send>(_: T) {}
+
+        let message = PersonalMessage(b"hello world".into());
+        let remote = RemoteSigner::new(MockSigningService::new(
+            crate::ed25519::Ed25519PrivateKey::new([1; 32]),
+        ));
+        assert_send(remote.sign_personal_message(&message));
+        assert_send(remote.service().sign(b"hello world"));
+    }
+}
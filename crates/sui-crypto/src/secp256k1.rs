@@ -1,3 +1,4 @@
+use crate::BatchVerificationError;
 use crate::SignatureError;
 use k256::ecdsa::SigningKey;
 use k256::ecdsa::VerifyingKey;
@@ -238,6 +239,18 @@ impl Secp256k1Verifier {
     pub fn new() -> Self {
         Self {}
     }
+
+    /// Verify a batch of secp256k1 `(message, signature)` pairs, reporting which failed.
+    ///
+    /// ECDSA does not support batch verification so each signature is verified individually.
+    pub fn verify_batch(
+        &self,
+        batch: &[(&[u8], &UserSignature)],
+    ) -> Result<(), BatchVerificationError> {
+        crate::batch::verify_each(batch, |message, signature| {
+            <Self as Verifier<UserSignature>>::verify(self, message, signature)
+        })
+    }
 }
 
 impl Verifier<SimpleSignature> for Secp256k1Verifier {
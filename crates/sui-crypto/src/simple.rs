@@ -635,4 +635,33 @@ mod test {
         let from_pem = SimpleVerifiyingKey::from_pem(&pem).unwrap();
         assert_eq!(pem, from_pem.to_pem().unwrap());
     }
+
+    #[proptest]
+    fn user_signature_batch_verification(
+        ed25519: Ed25519PrivateKey,
+        secp256r1: Secp256r1PrivateKey,
+        secp256k1: Secp256k1PrivateKey,
+        message: Vec<u8>,
+    ) {
+        use crate::Signer;
+        use crate::UserSignatureVerifier;
+
+        let signatures: Vec<UserSignature> = vec![
+            ed25519.try_sign(&message).unwrap(),
+            secp256r1.try_sign(&message).unwrap(),
+            secp256k1.try_sign(&message).unwrap(),
+            ed25519.try_sign(b"other message").unwrap(),
+            secp256k1.try_sign(b"other message").unwrap(),
+        ];
+        let batch = signatures
+            .iter()
+            .map(|signature| (message.as_slice(), signature))
+            .collect::<Vec<_>>();
+
+        let verifier = UserSignatureVerifier::new();
+        verifier.verify_batch(&batch[..3]).unwrap();
+
+        let error = verifier.verify_batch(&batch).unwrap_err();
+        assert_eq!(error.failed_indices().collect::<Vec<_>>(), vec![3, 4]);
+    }
 }
@@ -0,0 +1,708 @@
+//! Sponsored transactions, whose gas is paid for by an address other than the sender.
+//!
+//! Sponsoring a transaction goes through the following steps:
+//!
+//! 1. The sender builds the [`TransactionKind`] to execute and sends it to a sponsor as a
+//!    [`SponsorRequest`].
+//! 2. The sponsor, a [`SponsorService`], attaches its gas objects and budget to the transaction,
+//!    signs it and returns it as a [`SponsoredTransaction`].
+//! 3. The sender checks that the sponsor didn't tamper with the transaction, signs it and
+//!    combines both signatures into a [`SignedTransaction`] with [`SponsoredTransaction::sign`].
+//!
+//! [`Sponsor`] is a reference sponsor service which pays for gas using a fixed set of gas objects,
+//! and can be exposed over any transport or used directly in tests.
+//!
+//! With the `sponsor` feature, requests and sponsored transactions can be serialized with serde,
+//! [`SponsorServer`] serves a [`SponsorService`] over HTTP and [`HttpSponsorService`] requests
+//! sponsorship from such a server.
+
+use std::future::Future;
+
+use crate::MaybeSend;
+use crate::MaybeSync;
+use crate::SignatureError;
+use crate::SuiVerifier;
+use crate::TransactionSigner;
+use crate::UserSignatureVerifier;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Argument;
+use sui_sdk_types::types::Command;
+use sui_sdk_types::types::GasPayment;
+use sui_sdk_types::types::Input;
+use sui_sdk_types::types::ObjectReference;
+use sui_sdk_types::types::SignedTransaction;
+use sui_sdk_types::types::SimpleSignature;
+use sui_sdk_types::types::Transaction;
+use sui_sdk_types::types::TransactionExpiration;
+use sui_sdk_types::types::TransactionKind;
+use sui_sdk_types::types::UserSignature;
+
+/// A request from `sender` for a sponsor to pay for the gas of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "sponsor",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct SponsorRequest {
+    pub sender: Address,
+    pub kind: TransactionKind,
+    pub expiration: TransactionExpiration,
+}
+
+/// A service which pays for the gas of the transactions of other addresses.
+///
+/// The returned future is `Send` on all targets except `wasm32`.
+pub trait SponsorService: MaybeSend + MaybeSync {
+    /// Attach a gas payment owned by the sponsor to the transaction described by `request` and
+    /// sign it, or reject the request.
+    fn sponsor(
+        &self,
+        request: &SponsorRequest,
+    ) -> impl Future<Output = Result<SponsoredTransaction, SponsorError>> + MaybeSend;
+}
+
+/// Error returned by a [`SponsorService`], telling requests which the sponsor refuses to pay for
+/// apart from failures of the sponsor itself.
+#[derive(Debug)]
+pub enum SponsorError {
+    /// The request was rejected by the sponsor, e.g. because it uses the gas coin.
+    Rejected(SignatureError),
+    /// The sponsor failed to sponsor the request, e.g. because its signer is unavailable.
+    Failed(SignatureError),
+}
+
+impl std::fmt::Display for SponsorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rejected(error) => write!(f, "sponsor request rejected: {error}"),
+            Self::Failed(error) => write!(f, "sponsor failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SponsorError {}
+
+/// A transaction whose gas payment was attached and signed by a sponsor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "sponsor",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct SponsoredTransaction {
+    pub transaction: Transaction,
+    pub sponsor_signature: UserSignature,
+}
+
+impl SponsoredTransaction {
+    /// Check that this is the transaction described by `request`, paid for and signed by a
+    /// sponsor other than the sender.
+    ///
+    /// The address of the sponsor is checked against its signature for simple and multisig
+    /// signatures. For other kinds of signatures, only the validity of the signature is checked.
+    pub fn verify(&self, request: &SponsorRequest) -> Result<(), SignatureError> {
+        let Transaction {
+            kind,
+            sender,
+            gas_payment,
+            expiration,
+        } = &self.transaction;
+
+        if sender != &request.sender {
+            return Err(SignatureError::from_source(
+                "sponsored transaction has a different sender",
+            ));
+        }
+        if kind != &request.kind {
+            return Err(SignatureError::from_source(
+                "sponsored transaction has a different kind",
+            ));
+        }
+        if expiration != &request.expiration {
+            return Err(SignatureError::from_source(
+                "sponsored transaction has a different expiration",
+            ));
+        }
+        if gas_payment.owner == request.sender {
+            return Err(SignatureError::from_source(
+                "sponsored transaction is paid for by the sender",
+            ));
+        }
+        if signer_address(&self.sponsor_signature)
+            .is_some_and(|address| address != gas_payment.owner)
+        {
+            return Err(SignatureError::from_source(
+                "sponsor signature is not from the owner of the gas payment",
+            ));
+        }
+
+        UserSignatureVerifier::new().verify_transaction(&self.transaction, &self.sponsor_signature)
+    }
+
+    /// Verify this transaction against `request`, then sign it with `signer`, the sender,
+    /// returning the transaction signed by both the sender and the sponsor.
+    pub async fn sign<S: TransactionSigner>(
+        self,
+        request: &SponsorRequest,
+        signer: &S,
+    ) -> Result<SignedTransaction, SignatureError> {
+        self.verify(request)?;
+        let signature = signer.sign_transaction(&self.transaction).await?;
+
+        Ok(SignedTransaction {
+            transaction: self.transaction,
+            signatures: vec![signature, self.sponsor_signature],
+        })
+    }
+}
+
+/// A reference [`SponsorService`] which pays for gas using a fixed set of gas objects.
+///
+/// Requests are rejected if they:
+///
+/// - are sent by the sponsor itself;
+/// - are not for a programmable transaction;
+/// - use the gas coin, which belongs to the sponsor, as an argument;
+/// - use any of the gas objects of the sponsor as an input.
+///
+/// As the gas objects are fixed, the sponsor can only pay for one transaction at a time: its gas
+/// objects need to be updated with [`set_gas_objects`](Self::set_gas_objects) once a sponsored
+/// transaction is executed.
+#[derive(Clone, Debug)]
+pub struct Sponsor<S> {
+    signer: S,
+    address: Address,
+    gas_objects: Vec<ObjectReference>,
+    gas_price: u64,
+    gas_budget: u64,
+}
+
+impl<S> Sponsor<S> {
+    /// Create a sponsor paying for gas with `gas_objects`, owned by `address` and signed for by
+    /// `signer`, at `gas_price` and with a budget of `gas_budget`.
+    pub fn new(
+        signer: S,
+        address: Address,
+        gas_objects: Vec<ObjectReference>,
+        gas_price: u64,
+        gas_budget: u64,
+    ) -> Self {
+        Self {
+            signer,
+            address,
+            gas_objects,
+            gas_price,
+            gas_budget,
+        }
+    }
+
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub fn gas_objects(&self) -> &[ObjectReference] {
+        &self.gas_objects
+    }
+
+    pub fn set_gas_objects(&mut self, gas_objects: Vec<ObjectReference>) {
+        self.gas_objects = gas_objects;
+    }
+
+    fn check(&self, request: &SponsorRequest) -> Result<(), SignatureError> {
+        if request.sender == self.address {
+            return Err(SignatureError::from_source(
+                "the sponsor does not sponsor its own transactions",
+            ));
+        }
+
+        let TransactionKind::ProgrammableTransaction(ptb) = &request.kind else {
+            return Err(SignatureError::from_source(
+                "only programmable transactions can be sponsored",
+            ));
+        };
+
+        if ptb.commands.iter().flat_map(arguments).any(|argument| {
+            // The gas coin belongs to the sponsor
+            argument == &Argument::Gas
+        }) {
+            return Err(SignatureError::from_source(
+                "sponsored transactions cannot use the gas coin",
+            ));
+        }
+
+        if ptb.inputs.iter().any(|input| match input {
+            Input::ImmutableOrOwned(object) | Input::Receiving(object) => self
+                .gas_objects
+                .iter()
+                .any(|gas| gas.object_id() == object.object_id()),
+            _ => false,
+        }) {
+            return Err(SignatureError::from_source(
+                "sponsored transactions cannot use the gas objects of the sponsor",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: TransactionSigner> SponsorService for Sponsor<S> {
+    async fn sponsor(
+        &self,
+        request: &SponsorRequest,
+    ) -> Result<SponsoredTransaction, SponsorError> {
+        self.check(request).map_err(SponsorError::Rejected)?;
+
+        let transaction = Transaction {
+            kind: request.kind.clone(),
+            sender: request.sender,
+            gas_payment: GasPayment {
+                objects: self.gas_objects.clone(),
+                owner: self.address,
+                price: self.gas_price,
+                budget: self.gas_budget,
+            },
+            expiration: request.expiration,
+        };
+        let sponsor_signature = self
+            .signer
+            .sign_transaction(&transaction)
+            .await
+            .map_err(SponsorError::Failed)?;
+
+        Ok(SponsoredTransaction {
+            transaction,
+            sponsor_signature,
+        })
+    }
+}
+
+#[cfg(feature = "sponsor")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sponsor")))]
+pub use http::HttpResponse;
+#[cfg(feature = "sponsor")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sponsor")))]
+pub use http::HttpSponsorService;
+#[cfg(feature = "sponsor")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sponsor")))]
+pub use http::SponsorServer;
+
+#[cfg(feature = "sponsor")]
+mod http {
+    use super::SponsorError;
+    use super::SponsorRequest;
+    use super::SponsorService;
+    use super::SponsoredTransaction;
+    use crate::SignatureError;
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+    struct ErrorResponse {
+        error: String,
+    }
+
+    fn error_body(error: SponsorError) -> serde_json::Result<Vec<u8>> {
+        serde_json::to_vec(&ErrorResponse {
+            error: error.to_string(),
+        })
+    }
+
+    /// A [`SponsorService`] which requests sponsorship from a sponsor over HTTP.
+    ///
+    /// The request is POSTed to the sponsor as a JSON [`SponsorRequest`] and the sponsor is
+    /// expected to respond with a JSON [`SponsoredTransaction`], or with an error status and a
+    /// JSON object of the form `{"error": "<message>"}`, as done by [`SponsorServer`].
+    #[derive(Clone, Debug)]
+    pub struct HttpSponsorService {
+        url: reqwest::Url,
+        bearer_token: Option<String>,
+        inner: reqwest::Client,
+    }
+
+    impl HttpSponsorService {
+        /// Create a new client for the sponsor at `url`.
+        pub fn new(url: &str) -> Result<Self, SignatureError> {
+            let url = reqwest::Url::parse(url).map_err(SignatureError::from_source)?;
+            Ok(Self {
+                url,
+                bearer_token: None,
+                inner: reqwest::Client::new(),
+            })
+        }
+
+        /// Authenticate requests to the sponsor using the provided bearer token.
+        pub fn with_bearer_token(mut self, token: &str) -> Self {
+            self.bearer_token = Some(token.to_owned());
+            self
+        }
+    }
+
+    impl SponsorService for HttpSponsorService {
+        async fn sponsor(
+            &self,
+            request: &SponsorRequest,
+        ) -> Result<SponsoredTransaction, SponsorError> {
+            let failed = |error| SponsorError::Failed(SignatureError::from_source(error));
+
+            let mut builder = self.inner.post(self.url.clone()).json(request);
+            if let Some(token) = &self.bearer_token {
+                builder = builder.bearer_auth(token);
+            }
+
+            let response = builder.send().await.map_err(failed)?;
+            if response.status().is_client_error() {
+                let response = response.json::<ErrorResponse>().await.map_err(failed)?;
+                return Err(SponsorError::Rejected(SignatureError::from_source(
+                    response.error,
+                )));
+            }
+
+            response
+                .error_for_status()
+                .map_err(failed)?
+                .json::<SponsoredTransaction>()
+                .await
+                .map_err(failed)
+        }
+    }
+
+    /// A response to an HTTP request, produced by [`SponsorServer::handle`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct HttpResponse {
+        /// The HTTP status code of the response.
+        pub status: u16,
+        /// The JSON body of the response.
+        pub body: Vec<u8>,
+    }
+
+    /// A reference server exposing a [`SponsorService`] over HTTP, independently of any HTTP
+    /// framework.
+    ///
+    /// The body of each POST request is handed to [`handle`](Self::handle), which expects a JSON
+    /// [`SponsorRequest`] and responds with:
+    ///
+    /// - `200` and the JSON [`SponsoredTransaction`] if the request was sponsored;
+    /// - `400` and a JSON object of the form `{"error": "<message>"}` if the request is malformed
+    ///   or was rejected by the sponsor, see [`SponsorError::Rejected`];
+    /// - `500` and a JSON object of the same form if the sponsor failed to sponsor the request,
+    ///   see [`SponsorError::Failed`].
+    ///
+    /// Authenticating clients is left to the HTTP framework the server is mounted in.
+    #[derive(Clone, Debug)]
+    pub struct SponsorServer<S> {
+        service: S,
+    }
+
+    impl<S> SponsorServer<S> {
+        pub fn new(service: S) -> Self {
+            Self { service }
+        }
+
+        pub fn service(&self) -> &S {
+            &self.service
+        }
+    }
+
+    impl<S: SponsorService> SponsorServer<S> {
+        /// Handle the JSON `body` of a request for sponsorship.
+        pub async fn handle(&self, body: &[u8]) -> HttpResponse {
+            let result = match serde_json::from_slice::<SponsorRequest>(body) {
+                Ok(request) => self.service.sponsor(&request).await,
+                Err(error) => Err(SponsorError::Rejected(SignatureError::from_source(
+                    format!("malformed sponsor request: {error}"),
+                ))),
+            };
+
+            let (status, body) = match result {
+                Ok(sponsored) => (200, serde_json::to_vec(&sponsored)),
+                Err(error @ SponsorError::Rejected(_)) => (400, error_body(error)),
+                Err(error @ SponsorError::Failed(_)) => (500, error_body(error)),
+            };
+            HttpResponse {
+                status,
+                body: body.expect("serializing to json cannot fail"),
+            }
+        }
+    }
+}
+
+/// The arguments used by `command`.
+fn arguments(command: &Command) -> Vec<&Argument> {
+    match command {
+        Command::MoveCall(call) => call.arguments.iter().collect(),
+        Command::TransferObjects(transfer) => transfer
+            .objects
+            .iter()
+            .chain(std::iter::once(&transfer.address))
+            .collect(),
+        Command::SplitCoins(split) => std::iter::once(&split.coin).chain(&split.amounts).collect(),
+        Command::MergeCoins(merge) => std::iter::once(&merge.coin)
+            .chain(&merge.coins_to_merge)
+            .collect(),
+        Command::Publish(_) => vec![],
+        Command::MakeMoveVector(vector) => vector.elements.iter().collect(),
+        Command::Upgrade(upgrade) => vec![&upgrade.ticket],
+    }
+}
+
+/// The address of the signer of `signature`, if it can be derived from the signature alone.
+fn signer_address(signature: &UserSignature) -> Option<Address> {
+    match signature {
+        UserSignature::Simple(SimpleSignature::Ed25519 { public_key, .. }) => {
+            Some(public_key.to_address())
+        }
+        UserSignature::Simple(SimpleSignature::Secp256k1 { public_key, .. }) => {
+            Some(public_key.to_address())
+        }
+        UserSignature::Simple(SimpleSignature::Secp256r1 { public_key, .. }) => {
+            Some(public_key.to_address())
+        }
+        UserSignature::Multisig(multisig) => Some(multisig.committee().to_address()),
+        UserSignature::ZkLogin(_) | UserSignature::Passkey(_) => None,
+    }
+}
+
+#[cfg(all(test, feature = "ed25519"))]
+mod test {
+    use super::*;
+    use crate::block_on;
+    use crate::ed25519::Ed25519PrivateKey;
+    use crate::LocalSigner;
+    use sui_sdk_types::types::Identifier;
+    use sui_sdk_types::types::MoveCall;
+    use sui_sdk_types::types::ObjectDigest;
+    use sui_sdk_types::types::ObjectId;
+    use sui_sdk_types::types::ProgrammableTransaction;
+    use sui_sdk_types::types::TransferObjects;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn gas_object() -> ObjectReference {
+        ObjectReference::new(ObjectId::new([7; 32]), 1, ObjectDigest::ZERO)
+    }
+
+    fn sponsor() -> Sponsor<LocalSigner<Ed25519PrivateKey>> {
+        let key = Ed25519PrivateKey::new([2; 32]);
+        let address = key.public_key().to_address();
+        Sponsor::new(
+            LocalSigner::new(key),
+            address,
+            vec![gas_object()],
+            1000,
+            10_000_000,
+        )
+    }
+
+    fn request(sender: Address, inputs: Vec<Input>, commands: Vec<Command>) -> SponsorRequest {
+        SponsorRequest {
+            sender,
+            kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs,
+                commands,
+            }),
+            expiration: TransactionExpiration::None,
+        }
+    }
+
+    fn move_call(arguments: Vec<Argument>) -> Command {
+        Command::MoveCall(MoveCall {
+            package: ObjectId::new([3; 32]),
+            module: Identifier::new("counter").unwrap(),
+            function: Identifier::new("increment").unwrap(),
+            type_arguments: vec![],
+            arguments,
+        })
+    }
+
+    #[test]
+    fn sponsored_transaction() {
+        let sender = Ed25519PrivateKey::new([1; 32]);
+        let sender_address = sender.public_key().to_address();
+        let sender = LocalSigner::new(sender);
+        let sponsor = sponsor();
+
+        let request = request(
+            sender_address,
+            vec![Input::Pure { value: vec![1] }],
+            vec![move_call(vec![Argument::Input(0)])],
+        );
+        let sponsored = block_on(sponsor.sponsor(&request)).unwrap();
+        assert_eq!(sponsored.transaction.gas_payment.owner, *sponsor.address());
+        assert_eq!(sponsored.transaction.gas_payment.objects, [gas_object()]);
+
+        let signed = block_on(sponsored.clone().sign(&request, &sender)).unwrap();
+        assert_eq!(signed.transaction, sponsored.transaction);
+        let verifier = UserSignatureVerifier::new();
+        for signature in &signed.signatures {
+            verifier
+                .verify_transaction(&signed.transaction, signature)
+                .unwrap();
+        }
+
+        // The sender rejects a transaction whose kind was tampered with
+        let mut tampered = sponsored.clone();
+        tampered.transaction.kind =
+            TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                inputs: vec![],
+                commands: vec![],
+            });
+        tampered.verify(&request).unwrap_err();
+
+        // The sender rejects a signature which doesn't match the transaction
+        let mut tampered = sponsored.clone();
+        tampered.transaction.gas_payment.budget += 1;
+        tampered.verify(&request).unwrap_err();
+
+        // The sender rejects a signature from another address than the gas owner
+        let mut tampered = sponsored;
+        tampered.transaction.gas_payment.owner = Address::ZERO;
+        tampered.verify(&request).unwrap_err();
+    }
+
+    #[test]
+    fn sponsor_rejects_requests() {
+        let sponsor = sponsor();
+        let sender = Ed25519PrivateKey::new([1; 32]).public_key().to_address();
+
+        // Using the gas coin
+        let gas_request = request(
+            sender,
+            vec![Input::Pure {
+                value: sender.as_bytes().to_vec(),
+            }],
+            vec![Command::TransferObjects(TransferObjects {
+                objects: vec![Argument::Gas],
+                address: Argument::Input(0),
+            })],
+        );
+        block_on(sponsor.sponsor(&gas_request)).unwrap_err();
+
+        // Using the gas objects of the sponsor
+        let input_request = request(
+            sender,
+            vec![Input::ImmutableOrOwned(gas_object())],
+            vec![move_call(vec![Argument::Input(0)])],
+        );
+        block_on(sponsor.sponsor(&input_request)).unwrap_err();
+
+        // Sent by the sponsor itself
+        let own_request = request(*sponsor.address(), vec![], vec![move_call(vec![])]);
+        block_on(sponsor.sponsor(&own_request)).unwrap_err();
+    }
+
+    /// Serve `requests` requests for sponsorship on `listener` with `server`.
+    #[cfg(all(feature = "sponsor", not(target_arch = "wasm32")))]
+    async fn serve<S: SponsorService>(
+        listener: tokio::net::TcpListener,
+        server: &SponsorServer<S>,
+        requests: usize,
+    ) {
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+
+        for _ in 0..requests {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            let body_start = loop {
+                let read = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+                if let Some(position) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break position + 4;
+                }
+            };
+            let content_length = std::str::from_utf8(&request[..body_start])
+                .unwrap()
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().unwrap())
+                })
+                .unwrap();
+            while request.len() < body_start + content_length {
+                let read = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            let response = server.handle(&request[body_start..]).await;
+            let head = format!(
+                "HTTP/1.1 {} \r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                response.status,
+                response.body.len(),
+            );
+            stream.write_all(head.as_bytes()).await.unwrap();
+            stream.write_all(&response.body).await.unwrap();
+        }
+    }
+
+    #[cfg(all(feature = "sponsor", not(target_arch = "wasm32")))]
+    #[tokio::test]
+    async fn http_sponsor() {
+        let server = SponsorServer::new(sponsor());
+        let address = *server.service().address();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client =
+            HttpSponsorService::new(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+
+        let sender = LocalSigner::new(Ed25519PrivateKey::new([1; 32]));
+        let sender_address = Ed25519PrivateKey::new([1; 32]).public_key().to_address();
+        // Sent by the sponsor itself
+        let own_request = request(address, vec![], vec![move_call(vec![])]);
+        let request = request(
+            sender_address,
+            vec![Input::Pure { value: vec![1] }],
+            vec![move_call(vec![Argument::Input(0)])],
+        );
+
+        let (_, sponsored, rejected) = tokio::join!(
+            serve(listener, &server, 2),
+            client.sponsor(&request),
+            client.sponsor(&own_request),
+        );
+
+        let sponsored = sponsored.unwrap();
+        assert_eq!(
+            sponsored,
+            block_on(server.service().sponsor(&request)).unwrap()
+        );
+        let signed = sponsored.sign(&request, &sender).await.unwrap();
+        assert_eq!(signed.signatures.len(), 2);
+
+        assert!(matches!(rejected, Err(SponsorError::Rejected(_))));
+
+        // Malformed requests are rejected
+        let response = server.handle(b"{}").await;
+        assert_eq!(response.status, 400);
+        assert!(
+            serde_json::from_slice::<serde_json::Value>(&response.body).unwrap()["error"]
+                .is_string()
+        );
+
+        // Failures of the sponsor are reported as server errors
+        struct FailingSigner;
+
+        impl TransactionSigner for FailingSigner {
+            async fn sign_digest(&self, _: &[u8]) -> Result<UserSignature, SignatureError> {
+                Err(SignatureError::from_source("signing service unavailable"))
+            }
+        }
+
+        let failing = SponsorServer::new(Sponsor::new(
+            FailingSigner,
+            address,
+            vec![gas_object()],
+            1000,
+            10_000_000,
+        ));
+        let response = failing.handle(&serde_json::to_vec(&request).unwrap()).await;
+        assert_eq!(response.status, 500);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client =
+            HttpSponsorService::new(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let (_, failed) = tokio::join!(serve(listener, &failing, 1), client.sponsor(&request));
+        assert!(matches!(failed, Err(SponsorError::Failed(_))));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::SignatureError;
+use sui_sdk_types::types::Jwk;
+use sui_sdk_types::types::JwkId;
+
+/// Parse a JWKS document, as served from the `jwks_uri` of an OpenID provider, into a list of
+/// [`JwkId`] and [`Jwk`] pairs for the provided `iss`.
+///
+/// Only RSA keys using the RS256 algorithm are supported by zklogin, any other keys present in
+/// the document are skipped.
+pub fn parse_jwks(iss: &str, document: &[u8]) -> Result<Vec<(JwkId, Jwk)>, SignatureError> {
+    #[derive(serde_derive::Deserialize)]
+    struct Document {
+        keys: Vec<Key>,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct Key {
+        kty: String,
+        kid: String,
+        e: String,
+        n: String,
+        alg: Option<String>,
+    }
+
+    let Document { keys } =
+        serde_json::from_slice(document).map_err(SignatureError::from_source)?;
+
+    Ok(keys
+        .into_iter()
+        .filter_map(|key| {
+            // Some providers (e.g. Twitch) omit `alg`, in which case RS256 is assumed
+            let alg = key.alg.unwrap_or_else(|| "RS256".to_owned());
+            let e = key.e.trim_end_matches('=');
+            if key.kty != "RSA" || alg != "RS256" || e != "AQAB" {
+                return None;
+            }
+
+            let id = JwkId {
+                iss: iss.to_owned(),
+                kid: key.kid,
+            };
+            let jwk = Jwk {
+                kty: key.kty,
+                e: e.to_owned(),
+                n: key.n.trim_end_matches('=').to_owned(),
+                alg,
+            };
+            Some((id, jwk))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn parse_google_jwks() {
+        let document = r#"{
+          "keys": [
+            {
+              "e": "AQAB",
+              "kty": "RSA",
+              "n": "oUriU8GqbRw-avcMn95DGW1cpZR1IoM6L7krfrWvLSSCcSX6Ig117o25Yk7QWBiJpaPV0FbP7Y5-DmThZ3SaF0AXW-3BsKPEXfFfeKVc6vBqk3t5mKlNEowjdvNTSzoOXO5UIHwsXaxiJlbMRalaFEUm-2CKgmXl1ss_yGh1OHkfnBiGsfQUndKoHiZuDzBMGw8Sf67am_Ok-4FShK0NuR3-q33aB_3Z7obC71dejSLWFOEcKUVCaw6DGVuLog3x506h1QQ1r0FXKOQxnmqrRgpoHqGSouuG35oZve1vgCU4vLZ6EAgBAbC0KL35I7_0wUDSMpiAvf7iZxzJVbspkQ",
+              "alg": "RS256",
+              "kid": "6f7254101f56e41cf35c9926de84a2d552b4c6f1",
+              "use": "sig"
+            },
+            {
+              "kty": "EC",
+              "kid": "ec-key",
+              "e": "AQAB",
+              "n": "unused",
+              "alg": "ES256"
+            },
+            {
+              "kty": "RSA",
+              "kid": "twitch",
+              "e": "AQAB",
+              "n": "6lq9MQ-q6hcxr7kOUp-tHlHtdcDsVLwVIw13iXUCvuDOeCi0VSuxCCUY6UmMjy53dX00ih2E4Y4UvlrmmurK0eG26b-HMNNAvCGsVXHU3RcRhVoHDaOwHwU72j7bpHn9XbP3Q3jebX6KIfNbei2MiR0Wyb8RZHE-aZhRYO8_-k9G2GycTpvc-2GBsP8VHLUKKfAs2B6sW3q3ymU6M0L-cFXkZ9fHkn9ejs-sqZPhMJxtBPBxoUIUQFTgv4VXTSv914f_YkNw-EjuwbgwXMvpyr06EyfImxHoxsZkFYB-qBYHtaMxTnFsZBr6fn8Ha2JqT1hoP7Z5r5wxDu3GQhKkHw=="
+            }
+          ]
+        }"#;
+
+        let jwks = parse_jwks("https://accounts.google.com", document.as_bytes()).unwrap();
+        assert_eq!(jwks.len(), 2);
+
+        let (id, jwk) = &jwks[0];
+        assert_eq!(id.iss, "https://accounts.google.com");
+        assert_eq!(id.kid, "6f7254101f56e41cf35c9926de84a2d552b4c6f1");
+        assert_eq!(jwk.kty, "RSA");
+        assert_eq!(jwk.alg, "RS256");
+
+        let (id, jwk) = &jwks[1];
+        assert_eq!(id.kid, "twitch");
+        assert_eq!(jwk.alg, "RS256");
+        assert!(!jwk.n.ends_with('='));
+
+        parse_jwks("https://accounts.google.com", b"{}").unwrap_err();
+    }
+}
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
+use crate::BatchVerificationError;
 use crate::SignatureError;
 use poseidon::POSEIDON;
 use signature::Verifier;
+use sui_sdk_types::types::ActiveJwk;
 use sui_sdk_types::types::Claim;
 use sui_sdk_types::types::Jwk;
 use sui_sdk_types::types::JwkId;
@@ -10,15 +12,35 @@ use sui_sdk_types::types::UserSignature;
 use sui_sdk_types::types::ZkLoginAuthenticator;
 use sui_sdk_types::types::ZkLoginInputs;
 
+mod jwks;
 mod poseidon;
+mod prover;
+mod signer;
+mod utils;
 mod verify;
 
+pub use jwks::parse_jwks;
+#[cfg(feature = "zklogin-prover")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "zklogin-prover")))]
+pub use prover::HttpZkLoginProver;
+pub use prover::ProverClaim;
+pub use prover::ZkLoginProofRequest;
+pub use prover::ZkLoginProofResponse;
+pub use prover::ZkLoginProver;
+pub use signer::ZkLoginSigner;
+pub use utils::address_seed;
+pub use utils::generate_nonce;
+pub use utils::generate_randomness;
+pub use utils::zklogin_address;
+
 #[cfg(test)]
 mod tests;
 
 pub struct ZkloginVerifier {
     proof_verifying_key: verify::VerifyingKey,
     jwks: HashMap<JwkId, Jwk>,
+    /// The most recent epoch in which a jwk was known to be active on-chain.
+    jwk_epochs: HashMap<JwkId, u64>,
 }
 
 impl ZkloginVerifier {
@@ -26,6 +48,7 @@ impl ZkloginVerifier {
         Self {
             proof_verifying_key,
             jwks: Default::default(),
+            jwk_epochs: Default::default(),
         }
     }
 
@@ -44,6 +67,64 @@ impl ZkloginVerifier {
     pub fn jwks_mut(&mut self) -> &mut HashMap<JwkId, Jwk> {
         &mut self.jwks
     }
+
+    /// Insert or refresh a set of jwks which are active on-chain, e.g. as found in an
+    /// `AuthenticatorStateUpdate` transaction or the on-chain `AuthenticatorState` object.
+    ///
+    /// The epoch of each [`ActiveJwk`] is tracked so that stale jwks can later be removed via
+    /// [`ZkloginVerifier::expire_jwks`].
+    pub fn update_active_jwks<I>(&mut self, active_jwks: I)
+    where
+        I: IntoIterator<Item = ActiveJwk>,
+    {
+        for ActiveJwk { jwk_id, jwk, epoch } in active_jwks {
+            let last_seen = self.jwk_epochs.entry(jwk_id.clone()).or_insert(epoch);
+            *last_seen = (*last_seen).max(epoch);
+            self.jwks.insert(jwk_id, jwk);
+        }
+    }
+
+    /// Remove jwks which were last active in an epoch lower than `min_epoch`.
+    ///
+    /// This mirrors the expiry performed on-chain by `AuthenticatorStateExpire`: if an issuer has
+    /// no jwks active in or after `min_epoch`, then none of its jwks are removed. Jwks which were
+    /// inserted manually via [`ZkloginVerifier::jwks_mut`] have no known epoch and are never
+    /// expired, while jwks removed via [`ZkloginVerifier::jwks_mut`] are no longer tracked.
+    pub fn expire_jwks(&mut self, min_epoch: u64) {
+        let jwks = &mut self.jwks;
+        self.jwk_epochs.retain(|id, _| jwks.contains_key(id));
+
+        let current_issuers: std::collections::HashSet<String> = self
+            .jwk_epochs
+            .iter()
+            .filter(|(_, epoch)| **epoch >= min_epoch)
+            .map(|(id, _)| id.iss.clone())
+            .collect();
+
+        self.jwk_epochs.retain(|id, epoch| {
+            if *epoch >= min_epoch || !current_issuers.contains(&id.iss) {
+                true
+            } else {
+                jwks.remove(id);
+                false
+            }
+        });
+    }
+}
+
+impl ZkloginVerifier {
+    /// Verify a batch of zklogin `(message, signature)` pairs, reporting which failed.
+    ///
+    /// The groth16 prepared verifying key and the set of known jwks held by this verifier are
+    /// shared across every signature in the batch.
+    pub fn verify_batch(
+        &self,
+        batch: &[(&[u8], &UserSignature)],
+    ) -> Result<(), BatchVerificationError> {
+        crate::batch::verify_each(batch, |message, signature| {
+            <Self as Verifier<UserSignature>>::verify(self, message, signature)
+        })
+    }
 }
 
 impl Verifier<ZkLoginAuthenticator> for ZkloginVerifier {
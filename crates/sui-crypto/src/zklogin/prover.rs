@@ -0,0 +1,237 @@
+use std::future::Future;
+
+use crate::MaybeSend;
+use crate::MaybeSync;
+use crate::SignatureError;
+use sui_sdk_types::types::Bn254FieldElement;
+use sui_sdk_types::types::Claim;
+use sui_sdk_types::types::MultisigMemberPublicKey;
+use sui_sdk_types::types::ZkLoginInputs;
+use sui_sdk_types::types::ZkLoginProof;
+
+/// The claim used to identify a user when one isn't explicitly provided.
+const DEFAULT_KEY_CLAIM_NAME: &str = "sub";
+
+/// A service capable of generating the groth16 proof needed to sign with a zklogin account.
+///
+/// An implementation which talks to a prover over HTTP is provided by [`HttpZkLoginProver`]
+/// (requires the `zklogin-prover` feature).
+///
+/// The returned future is `Send` on all targets except `wasm32`.
+pub trait ZkLoginProver: MaybeSend + MaybeSync {
+    fn prove(
+        &self,
+        request: &ZkLoginProofRequest,
+    ) -> impl Future<Output = Result<ZkLoginProofResponse, SignatureError>> + MaybeSend;
+}
+
+/// A request for a zklogin proof.
+///
+/// This serializes to the JSON body expected by the prover service.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginProofRequest {
+    jwt: String,
+    extended_ephemeral_public_key: String,
+    #[serde(serialize_with = "serialize_u64_as_string")]
+    max_epoch: u64,
+    jwt_randomness: String,
+    salt: String,
+    key_claim_name: String,
+}
+
+impl ZkLoginProofRequest {
+    /// Build a proof request for the provided JWT.
+    ///
+    /// `ephemeral_public_key`, `max_epoch` and `jwt_randomness` must be the same values used to
+    /// compute the nonce included in the JWT, see [`generate_nonce`](super::generate_nonce).
+    pub fn new(
+        jwt: &str,
+        ephemeral_public_key: &MultisigMemberPublicKey,
+        max_epoch: u64,
+        jwt_randomness: &str,
+        salt: &str,
+    ) -> Result<Self, SignatureError> {
+        use base64ct::Base64;
+        use base64ct::Encoding;
+
+        let extended_ephemeral_public_key =
+            super::utils::extended_ephemeral_public_key(ephemeral_public_key)?;
+
+        Ok(Self {
+            jwt: jwt.to_owned(),
+            extended_ephemeral_public_key: Base64::encode_string(&extended_ephemeral_public_key),
+            max_epoch,
+            jwt_randomness: jwt_randomness.to_owned(),
+            salt: salt.to_owned(),
+            key_claim_name: DEFAULT_KEY_CLAIM_NAME.to_owned(),
+        })
+    }
+
+    /// Set the name of the claim used to identify the user, defaults to "sub".
+    pub fn with_key_claim_name(mut self, key_claim_name: &str) -> Self {
+        key_claim_name.clone_into(&mut self.key_claim_name);
+        self
+    }
+
+    pub fn jwt(&self) -> &str {
+        &self.jwt
+    }
+
+    /// The ephemeral public key, prefixed with its signature scheme flag, encoded as base64.
+    pub fn extended_ephemeral_public_key(&self) -> &str {
+        &self.extended_ephemeral_public_key
+    }
+
+    pub fn max_epoch(&self) -> u64 {
+        self.max_epoch
+    }
+
+    pub fn jwt_randomness(&self) -> &str {
+        &self.jwt_randomness
+    }
+
+    pub fn salt(&self) -> &str {
+        &self.salt
+    }
+
+    pub fn key_claim_name(&self) -> &str {
+        &self.key_claim_name
+    }
+}
+
+fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(value)
+}
+
+/// The response of a prover service to a [`ZkLoginProofRequest`].
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginProofResponse {
+    pub proof_points: ZkLoginProof,
+    pub iss_base64_details: ProverClaim,
+    pub header_base64: String,
+}
+
+/// The `issBase64Details` claim as returned by a prover service.
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProverClaim {
+    pub value: String,
+    pub index_mod_4: u8,
+}
+
+impl ZkLoginProofResponse {
+    /// Combine the proof with the address seed of the account to form [`ZkLoginInputs`].
+    pub fn into_inputs(self, address_seed: Bn254FieldElement) -> ZkLoginInputs {
+        ZkLoginInputs {
+            proof_points: self.proof_points,
+            iss_base64_details: Claim {
+                value: self.iss_base64_details.value,
+                index_mod_4: self.iss_base64_details.index_mod_4,
+            },
+            header_base64: self.header_base64,
+            address_seed,
+        }
+    }
+}
+
+/// The subset of the claims of a JWT needed to derive an address seed.
+pub(super) struct JwtClaims(serde_json::Map<String, serde_json::Value>);
+
+impl JwtClaims {
+    pub(super) fn from_jwt(jwt: &str) -> Result<Self, SignatureError> {
+        use base64ct::Base64UrlUnpadded;
+        use base64ct::Encoding;
+
+        let mut parts = jwt.split('.');
+        let (Some(_header), Some(payload), Some(_signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(SignatureError::from_source("invalid jwt"));
+        };
+
+        let payload = Base64UrlUnpadded::decode_vec(payload.trim_end_matches('='))
+            .map_err(|e| SignatureError::from_source(e.to_string()))?;
+        serde_json::from_slice(&payload)
+            .map(Self)
+            .map_err(SignatureError::from_source)
+    }
+
+    pub(super) fn claim(&self, name: &str) -> Result<&str, SignatureError> {
+        self.0
+            .get(name)
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| SignatureError::from_source(format!("jwt missing '{name}' claim")))
+    }
+
+    /// The `aud` claim may either be a single string or an array containing a single string.
+    pub(super) fn aud(&self) -> Result<&str, SignatureError> {
+        match self.0.get("aud") {
+            Some(serde_json::Value::String(aud)) => Ok(aud),
+            Some(serde_json::Value::Array(auds)) if auds.len() == 1 => auds[0]
+                .as_str()
+                .ok_or_else(|| SignatureError::from_source("invalid 'aud' claim")),
+            _ => Err(SignatureError::from_source("invalid 'aud' claim")),
+        }
+    }
+}
+
+#[cfg(feature = "zklogin-prover")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "zklogin-prover")))]
+pub use http::HttpZkLoginProver;
+
+#[cfg(feature = "zklogin-prover")]
+mod http {
+    use super::ZkLoginProofRequest;
+    use super::ZkLoginProofResponse;
+    use super::ZkLoginProver;
+    use crate::SignatureError;
+
+    const DEV_PROVER_URL: &str = "https://prover-dev.mystenlabs.com/v1";
+
+    /// A [`ZkLoginProver`] which requests proofs from a prover service over HTTP.
+    #[derive(Clone, Debug)]
+    pub struct HttpZkLoginProver {
+        url: reqwest::Url,
+        inner: reqwest::Client,
+    }
+
+    impl HttpZkLoginProver {
+        /// Create a new prover client for the prover service at `url`.
+        pub fn new(url: &str) -> Result<Self, SignatureError> {
+            let url = reqwest::Url::parse(url).map_err(SignatureError::from_source)?;
+            Ok(Self {
+                url,
+                inner: reqwest::Client::new(),
+            })
+        }
+
+        /// Create a new prover client for the Mysten Labs hosted prover for devnet,
+        /// <https://prover-dev.mystenlabs.com/v1>.
+        pub fn new_dev() -> Self {
+            Self::new(DEV_PROVER_URL).expect("Invalid prover URL")
+        }
+    }
+
+    impl ZkLoginProver for HttpZkLoginProver {
+        async fn prove(
+            &self,
+            request: &ZkLoginProofRequest,
+        ) -> Result<ZkLoginProofResponse, SignatureError> {
+            self.inner
+                .post(self.url.clone())
+                .json(request)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(SignatureError::from_source)?
+                .json::<ZkLoginProofResponse>()
+                .await
+                .map_err(SignatureError::from_source)
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::SignatureError;
+use signature::Signer;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::SimpleSignature;
+use sui_sdk_types::types::UserSignature;
+use sui_sdk_types::types::ZkLoginAuthenticator;
+use sui_sdk_types::types::ZkLoginInputs;
+
+use super::prover::JwtClaims;
+use super::prover::ZkLoginProofRequest;
+use super::prover::ZkLoginProver;
+
+/// A signer for a zklogin account.
+///
+/// A `ZkLoginSigner` pairs the [`ZkLoginInputs`] obtained from a prover with the ephemeral key
+/// whose public key was committed to in the nonce of the JWT used to generate the proof.
+/// Signatures are produced by signing with the ephemeral key and wrapping the result in a
+/// [`ZkLoginAuthenticator`].
+pub struct ZkLoginSigner<S> {
+    inputs: ZkLoginInputs,
+    max_epoch: u64,
+    ephemeral_key: S,
+}
+
+impl<S> ZkLoginSigner<S> {
+    pub fn new(inputs: ZkLoginInputs, max_epoch: u64, ephemeral_key: S) -> Self {
+        Self {
+            inputs,
+            max_epoch,
+            ephemeral_key,
+        }
+    }
+
+    /// Request a zklogin proof from `prover` and build a signer from the result.
+    ///
+    /// The address seed is derived locally from the `sub` and `aud` claims of the JWT in the
+    /// request along with the user's salt.
+    pub async fn from_prover<P>(
+        prover: &P,
+        request: &ZkLoginProofRequest,
+        ephemeral_key: S,
+    ) -> Result<Self, SignatureError>
+    where
+        P: ZkLoginProver,
+    {
+        let claims = JwtClaims::from_jwt(request.jwt())?;
+        let address_seed = super::address_seed(
+            request.salt(),
+            request.key_claim_name(),
+            claims.claim(request.key_claim_name())?,
+            claims.aud()?,
+        )?;
+        let inputs = prover.prove(request).await?.into_inputs(address_seed);
+
+        Ok(Self::new(inputs, request.max_epoch(), ephemeral_key))
+    }
+
+    pub fn inputs(&self) -> &ZkLoginInputs {
+        &self.inputs
+    }
+
+    pub fn max_epoch(&self) -> u64 {
+        self.max_epoch
+    }
+
+    pub fn ephemeral_key(&self) -> &S {
+        &self.ephemeral_key
+    }
+
+    /// Return the address of the zklogin account this signer signs for.
+    pub fn address(&self) -> Result<Address, SignatureError> {
+        super::zklogin_identifier_from_inputs(&self.inputs)
+            .map(|identifier| identifier.to_address_padded())
+    }
+}
+
+impl<S: Signer<SimpleSignature>> Signer<ZkLoginAuthenticator> for ZkLoginSigner<S> {
+    fn try_sign(&self, msg: &[u8]) -> Result<ZkLoginAuthenticator, SignatureError> {
+        let signature = self.ephemeral_key.try_sign(msg)?;
+        Ok(ZkLoginAuthenticator {
+            inputs: self.inputs.clone(),
+            max_epoch: self.max_epoch,
+            signature,
+        })
+    }
+}
+
+impl<S: Signer<SimpleSignature>> Signer<UserSignature> for ZkLoginSigner<S> {
+    fn try_sign(&self, msg: &[u8]) -> Result<UserSignature, SignatureError> {
+        <Self as Signer<ZkLoginAuthenticator>>::try_sign(self, msg)
+            .map(|authenticator| UserSignature::ZkLogin(Box::new(authenticator)))
+    }
+}
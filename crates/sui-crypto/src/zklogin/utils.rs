@@ -0,0 +1,219 @@
+use std::str::FromStr;
+
+use crate::SignatureError;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Bn254FieldElement;
+use sui_sdk_types::types::MultisigMemberPublicKey;
+use sui_sdk_types::types::ZkLoginPublicIdentifier;
+
+use super::verify::bn254_to_fr;
+use super::verify::gen_address_seed_with_salt_hash;
+use super::verify::public_key_bytes_to_frs;
+use super::verify::U256;
+use super::POSEIDON;
+
+/// The name of the claim used to derive a zklogin address, i.e. the OpenID subject.
+const SUB: &str = "sub";
+
+/// Number of bytes of the poseidon hash that are retained when computing a nonce.
+const NONCE_LENGTH: usize = 20;
+
+/// Compute the address seed for a zklogin account.
+///
+/// The address seed is defined as `poseidon(hash(name), hash(value), hash(aud), poseidon(salt))`
+/// where `salt` is the user's salt encoded as a base 10 integer, `name` is the name of the claim
+/// used to identify the user (i.e. "sub"), `value` is the value of that claim and `aud` is the
+/// client id of the application the JWT was issued for.
+pub fn address_seed(
+    salt: &str,
+    name: &str,
+    value: &str,
+    aud: &str,
+) -> Result<Bn254FieldElement, SignatureError> {
+    let salt = Bn254FieldElement::from_str(salt).map_err(SignatureError::from_source)?;
+    let salt_hash = POSEIDON
+        .hash(&[bn254_to_fr(&salt)])
+        .map_err(SignatureError::from_source)?;
+    let seed = gen_address_seed_with_salt_hash(salt_hash, name, value, aud)?;
+    Bn254FieldElement::from_str(&seed).map_err(SignatureError::from_source)
+}
+
+/// Derive the zklogin address of a user from the `iss`, `aud` and `sub` claims of their JWT along
+/// with their salt.
+///
+/// The address is derived from the padded form of the address seed, see
+/// [`ZkLoginPublicIdentifier::to_address_padded`].
+pub fn zklogin_address(
+    iss: &str,
+    aud: &str,
+    sub: &str,
+    salt: &str,
+) -> Result<Address, SignatureError> {
+    let address_seed = address_seed(salt, SUB, sub, aud)?;
+    ZkLoginPublicIdentifier::new(iss.to_owned(), address_seed)
+        .map(|identifier| identifier.to_address_padded())
+        .ok_or_else(|| SignatureError::from_source("invalid iss"))
+}
+
+/// Generate the nonce to be included in an OAuth request.
+///
+/// The nonce commits to the ephemeral public key that will be used to sign transactions, the
+/// epoch after which the ephemeral key expires and a random value encoded as a base 10 integer.
+/// It is computed as the base64url encoding of the last 20 bytes of
+/// `poseidon(eph_pk_0, eph_pk_1, max_epoch, randomness)`.
+pub fn generate_nonce(
+    public_key: &MultisigMemberPublicKey,
+    max_epoch: u64,
+    randomness: &str,
+) -> Result<String, SignatureError> {
+    use base64ct::Base64UrlUnpadded;
+    use base64ct::Encoding;
+
+    let (first, second) = ephemeral_public_key_to_frs(public_key)?;
+    let max_epoch = Fr::from_be_bytes_mod_order(U256::from(max_epoch).to_be().digits());
+    let randomness =
+        Bn254FieldElement::from_str(randomness).map_err(SignatureError::from_source)?;
+
+    let hash = POSEIDON
+        .hash(&[first, second, max_epoch, bn254_to_fr(&randomness)])
+        .map_err(SignatureError::from_source)?;
+    let hash =
+        Bn254FieldElement::from_str(&hash.to_string()).map_err(SignatureError::from_source)?;
+    let padded = hash.padded();
+
+    Ok(Base64UrlUnpadded::encode_string(
+        &padded[padded.len() - NONCE_LENGTH..],
+    ))
+}
+
+/// Generate randomness suitable for use with [`generate_nonce`].
+///
+/// Returns a random 128-bit integer encoded in base 10.
+pub fn generate_randomness<R>(mut rng: R) -> String
+where
+    R: rand_core::RngCore + rand_core::CryptoRng,
+{
+    let mut buf = [0; 16];
+    rng.fill_bytes(&mut buf);
+    u128::from_be_bytes(buf).to_string()
+}
+
+fn ephemeral_public_key_to_frs(
+    public_key: &MultisigMemberPublicKey,
+) -> Result<(Fr, Fr), SignatureError> {
+    extended_ephemeral_public_key(public_key).map(|buf| public_key_bytes_to_frs(&buf))
+}
+
+/// Return the bytes of an ephemeral public key prefixed with its signature scheme flag.
+pub(super) fn extended_ephemeral_public_key(
+    public_key: &MultisigMemberPublicKey,
+) -> Result<Vec<u8>, SignatureError> {
+    let mut buf = Vec::with_capacity(34);
+
+    match public_key {
+        MultisigMemberPublicKey::Ed25519(public_key) => {
+            buf.push(public_key.scheme().to_u8());
+            buf.extend_from_slice(public_key.inner());
+        }
+        MultisigMemberPublicKey::Secp256k1(public_key) => {
+            buf.push(public_key.scheme().to_u8());
+            buf.extend_from_slice(public_key.inner());
+        }
+        MultisigMemberPublicKey::Secp256r1(public_key) => {
+            buf.push(public_key.scheme().to_u8());
+            buf.extend_from_slice(public_key.inner());
+        }
+        MultisigMemberPublicKey::ZkLogin(_) => {
+            return Err(SignatureError::from_source(
+                "zklogin identifiers cannot be used as an ephemeral public key",
+            ))
+        }
+    }
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sui_sdk_types::types::Ed25519PublicKey;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn address_seed_and_address() {
+        let salt = "206703048842351542647799591018316385612";
+        let iss = "https://accounts.google.com";
+        let aud = "25769832374-famecqrhe2gkebt5fvqms2263046lj96.apps.googleusercontent.com";
+        let sub = "106294049240999307923";
+
+        let seed = address_seed(salt, "sub", sub, aud).unwrap();
+        let address = zklogin_address(iss, aud, sub, salt).unwrap();
+
+        let expected = ZkLoginPublicIdentifier::new(iss.to_owned(), seed)
+            .unwrap()
+            .to_address_padded();
+        assert_eq!(address, expected);
+
+        // Different salts lead to different addresses
+        let other = zklogin_address(iss, aud, sub, "1").unwrap();
+        assert_ne!(address, other);
+
+        // Invalid salts are rejected
+        zklogin_address(iss, aud, sub, "not a number").unwrap_err();
+    }
+
+    #[test]
+    fn known_answers() {
+        // The inputs of the mainnet Google proof checked by
+        // `verify::test::test_verify_zklogin_google`, which commits to the resulting address seed
+        // and so to the address derived from it.
+        let salt = "206703048842351542647799591018316385612";
+        let iss = "https://accounts.google.com";
+        let aud = "25769832374-famecqrhe2gkebt5fvqms2263046lj96.apps.googleusercontent.com";
+        let sub = "106294049240999307923";
+
+        let seed = address_seed(salt, "sub", sub, aud).unwrap();
+        assert_eq!(
+            seed.to_string(),
+            "13319968244245342702944364608316777772547259798425697923099390355538529931211"
+        );
+        assert_eq!(
+            zklogin_address(iss, aud, sub, salt).unwrap(),
+            "0xa64ae946d5efd2dea396cb2fe81837f028c32f2b2f211176b65a3a152deb35a2"
+                .parse()
+                .unwrap()
+        );
+
+        // The nonce of the Twitch JWT behind the proof of `tests::test_zklogin_material`: its
+        // ephemeral key is the public key of the private key of that fixture, with the fixture's
+        // max epoch of 10 and this randomness
+        let public_key = MultisigMemberPublicKey::Ed25519(Ed25519PublicKey::new([
+            185, 198, 238, 22, 48, 239, 62, 113, 17, 68, 166, 72, 219, 6, 187, 178, 40, 79, 114,
+            116, 207, 190, 229, 63, 252, 238, 80, 60, 193, 164, 146, 0,
+        ]));
+        assert_eq!(
+            generate_nonce(&public_key, 10, "100681567828351849884072155819400689117").unwrap(),
+            "hTPpgF7XAKbW37rEUS6pEVZqmoI"
+        );
+    }
+
+    #[test]
+    fn nonce() {
+        let public_key = MultisigMemberPublicKey::Ed25519(Ed25519PublicKey::new([
+            185, 198, 238, 22, 48, 239, 62, 113, 17, 68, 166, 72, 219, 6, 187, 178, 40, 79, 114,
+            116, 207, 190, 229, 63, 252, 238, 80, 60, 193, 164, 146, 0,
+        ]));
+        let randomness = "100681567828351849884072155819400689117";
+
+        let nonce = generate_nonce(&public_key, 10, randomness).unwrap();
+        // base64url encoding of 20 bytes without padding
+        assert_eq!(nonce.len(), 27);
+        assert_eq!(nonce, generate_nonce(&public_key, 10, randomness).unwrap());
+        assert_ne!(nonce, generate_nonce(&public_key, 11, randomness).unwrap());
+        assert_ne!(nonce, generate_nonce(&public_key, 10, "1").unwrap());
+    }
+}
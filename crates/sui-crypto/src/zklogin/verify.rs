@@ -82,7 +82,7 @@ fn bn254_to_fq(f: &Bn254FieldElement) -> Fq {
     Fq::from_be_bytes_mod_order(f.padded())
 }
 
-fn bn254_to_fr(f: &Bn254FieldElement) -> Fr {
+pub(crate) fn bn254_to_fr(f: &Bn254FieldElement) -> Fr {
     Fr::from_be_bytes_mod_order(f.padded())
 }
 
@@ -318,6 +318,11 @@ pub fn public_key_to_frs(signature: &SimpleSignature) -> (Fr, Fr) {
         }
     };
 
+    public_key_bytes_to_frs(buf)
+}
+
+/// Convert a public key, prefixed with the signature scheme flag, to two Bn254Frs
+pub(crate) fn public_key_bytes_to_frs(buf: &[u8]) -> (Fr, Fr) {
     //TODO this comment is wrong...
     // Split the bytes deterministically such that the first element contains the first 128
     // bits of the hash, and the second element contains the latter ones.
@@ -0,0 +1,332 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::Error;
+use sui_sdk_types::types::CheckpointData;
+use sui_sdk_types::types::CheckpointSequenceNumber;
+
+/// File extension used for checkpoint files, which are named `<sequence number>.chk`.
+pub const CHECKPOINT_FILE_SUFFIX: &str = "chk";
+
+/// Flag, stored as the first byte of a checkpoint file, indicating that the payload is BCS
+/// encoded.
+const BCS_ENCODING: u8 = 1;
+
+/// The magic number which starts every zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Decode the contents of a checkpoint file.
+///
+/// A checkpoint file consists of a single byte indicating how the payload is encoded followed by
+/// the payload itself. Currently the only supported encoding is BCS. The file as a whole may
+/// optionally be compressed using zstd, which is detected automatically.
+pub fn decode_checkpoint(bytes: &[u8]) -> Result<CheckpointData, Error> {
+    if bytes.starts_with(&ZSTD_MAGIC) {
+        let decompressed = zstd::decode_all(bytes).map_err(Error::Decompression)?;
+        return decode_envelope(&decompressed);
+    }
+
+    decode_envelope(bytes)
+}
+
+fn decode_envelope(bytes: &[u8]) -> Result<CheckpointData, Error> {
+    let Some((&encoding, payload)) = bytes.split_first() else {
+        return Err(Error::InvalidEnvelope("empty checkpoint file".to_owned()));
+    };
+
+    match encoding {
+        BCS_ENCODING => bcs::from_bytes(payload).map_err(Into::into),
+        encoding => Err(Error::InvalidEnvelope(format!(
+            "unknown encoding {encoding}"
+        ))),
+    }
+}
+
+/// Encode a checkpoint into the format used by checkpoint files.
+pub fn encode_checkpoint(checkpoint: &CheckpointData) -> Vec<u8> {
+    let mut bytes = vec![BCS_ENCODING];
+    bcs::serialize_into(&mut bytes, checkpoint).expect("serialization should not fail");
+    bytes
+}
+
+/// Reader for checkpoint files stored on the local filesystem.
+///
+/// An `ArchiveReader` can be opened on either a single checkpoint file or a directory containing
+/// checkpoint files. Checkpoint files are located by name when the reader is opened, with any
+/// file in a directory not named `<sequence number>.chk` being ignored, but are only read and
+/// decoded when they are accessed.
+///
+/// Checkpoints are always yielded in order of their sequence number, however no check is made
+/// that the set of checkpoints is contiguous, use [`ArchiveReader::sequence_numbers`] to inspect
+/// which checkpoints are available.
+#[derive(Clone, Debug)]
+pub struct ArchiveReader {
+    files: BTreeMap<CheckpointSequenceNumber, PathBuf>,
+}
+
+impl ArchiveReader {
+    /// Open a checkpoint file or a directory of checkpoint files.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let io_error = |source| Error::Io {
+            path: path.to_owned(),
+            source,
+        };
+
+        let mut files = BTreeMap::new();
+        if path.metadata().map_err(io_error)?.is_dir() {
+            for entry in std::fs::read_dir(path).map_err(io_error)? {
+                let entry = entry.map_err(io_error)?;
+                let path = entry.path();
+                if let Some(sequence_number) = sequence_number_from_path(&path) {
+                    files.insert(sequence_number, path);
+                }
+            }
+        } else {
+            let sequence_number = sequence_number_from_path(path).ok_or_else(|| {
+                io_error(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "not a checkpoint file",
+                ))
+            })?;
+            files.insert(sequence_number, path.to_owned());
+        }
+
+        Ok(Self { files })
+    }
+
+    /// The number of checkpoints available to this reader.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// The sequence numbers of the checkpoints available to this reader, in ascending order.
+    pub fn sequence_numbers(&self) -> impl Iterator<Item = CheckpointSequenceNumber> + '_ {
+        self.files.keys().copied()
+    }
+
+    /// Read a single checkpoint, returning `None` if it isn't available to this reader.
+    pub fn read(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<Option<CheckpointData>, Error> {
+        self.files
+            .get(&sequence_number)
+            .map(|path| read_checkpoint_file(sequence_number, path))
+            .transpose()
+    }
+
+    /// Iterate over all checkpoints in order.
+    pub fn iter(&self) -> Checkpoints<'_> {
+        self.iter_from(0)
+    }
+
+    /// Iterate, in order, over all checkpoints with a sequence number of at least `start`.
+    pub fn iter_from(&self, start: CheckpointSequenceNumber) -> Checkpoints<'_> {
+        Checkpoints {
+            inner: self.files.range(start..),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ArchiveReader {
+    type Item = Result<CheckpointData, Error>;
+    type IntoIter = Checkpoints<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the checkpoints of an [`ArchiveReader`], see [`ArchiveReader::iter`].
+#[derive(Clone, Debug)]
+pub struct Checkpoints<'a> {
+    inner: std::collections::btree_map::Range<'a, CheckpointSequenceNumber, PathBuf>,
+}
+
+impl Iterator for Checkpoints<'_> {
+    type Item = Result<CheckpointData, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(sequence_number, path)| read_checkpoint_file(*sequence_number, path))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+fn sequence_number_from_path(path: &Path) -> Option<CheckpointSequenceNumber> {
+    if path.extension()? != CHECKPOINT_FILE_SUFFIX {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+fn read_checkpoint_file(
+    sequence_number: CheckpointSequenceNumber,
+    path: &Path,
+) -> Result<CheckpointData, Error> {
+    let bytes = std::fs::read(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+    let checkpoint = decode_checkpoint(&bytes)?;
+
+    let found = checkpoint.checkpoint_summary.checkpoint.sequence_number;
+    if found != sequence_number {
+        return Err(Error::SequenceNumberMismatch {
+            expected: sequence_number,
+            found,
+        });
+    }
+
+    Ok(checkpoint)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use sui_sdk_types::types::Bls12381Signature;
+    use sui_sdk_types::types::CheckpointContents;
+    use sui_sdk_types::types::CheckpointContentsDigest;
+    use sui_sdk_types::types::CheckpointSummary;
+    use sui_sdk_types::types::GasCostSummary;
+    use sui_sdk_types::types::SignedCheckpointSummary;
+    use sui_sdk_types::types::ValidatorAggregatedSignature;
+
+    pub(crate) fn checkpoint(sequence_number: CheckpointSequenceNumber) -> CheckpointData {
+        CheckpointData {
+            checkpoint_summary: SignedCheckpointSummary {
+                checkpoint: CheckpointSummary {
+                    epoch: 0,
+                    sequence_number,
+                    network_total_transactions: 0,
+                    content_digest: CheckpointContentsDigest::ZERO,
+                    previous_digest: None,
+                    epoch_rolling_gas_cost_summary: GasCostSummary::new(0, 0, 0, 0),
+                    timestamp_ms: sequence_number * 1000,
+                    checkpoint_commitments: vec![],
+                    end_of_epoch_data: None,
+                    version_specific_data: vec![],
+                },
+                signature: ValidatorAggregatedSignature {
+                    epoch: 0,
+                    signature: Bls12381Signature::new([0; Bls12381Signature::LENGTH]),
+                    bitmap: roaring::RoaringBitmap::new(),
+                },
+            },
+            checkpoint_contents: CheckpointContents::new(vec![]),
+            transactions: vec![],
+        }
+    }
+
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("sui-data-ingestion-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    pub(crate) fn write_checkpoint(dir: &Path, checkpoint: &CheckpointData) {
+        let sequence_number = checkpoint.checkpoint_summary.checkpoint.sequence_number;
+        std::fs::write(
+            dir.join(format!("{sequence_number}.{CHECKPOINT_FILE_SUFFIX}")),
+            encode_checkpoint(checkpoint),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn envelope() {
+        let checkpoint = checkpoint(3);
+        let bytes = encode_checkpoint(&checkpoint);
+        assert_eq!(bytes[0], BCS_ENCODING);
+        assert_eq!(decode_checkpoint(&bytes).unwrap(), checkpoint);
+
+        let mut unknown_encoding = bytes.clone();
+        unknown_encoding[0] = 0;
+        assert!(matches!(
+            decode_checkpoint(&unknown_encoding),
+            Err(Error::InvalidEnvelope(_))
+        ));
+
+        let mut trailing_bytes = bytes.clone();
+        trailing_bytes.push(0);
+        assert!(matches!(
+            decode_checkpoint(&trailing_bytes),
+            Err(Error::Bcs(_))
+        ));
+
+        assert!(matches!(
+            decode_checkpoint(&[]),
+            Err(Error::InvalidEnvelope(_))
+        ));
+
+        let compressed = zstd::encode_all(bytes.as_slice(), 0).unwrap();
+        assert_eq!(decode_checkpoint(&compressed).unwrap(), checkpoint);
+        assert!(matches!(
+            decode_checkpoint(&[0x28, 0xb5, 0x2f, 0xfd, 0]),
+            Err(Error::Decompression(_))
+        ));
+    }
+
+    #[test]
+    fn read_directory() {
+        let dir = temp_dir("read-directory");
+        for sequence_number in [2, 0, 1, 10] {
+            write_checkpoint(&dir, &checkpoint(sequence_number));
+        }
+        std::fs::write(dir.join("README"), "not a checkpoint").unwrap();
+        std::fs::write(dir.join("latest.chk"), "not a checkpoint").unwrap();
+
+        let reader = ArchiveReader::open(&dir).unwrap();
+        assert_eq!(reader.len(), 4);
+        assert_eq!(
+            reader.sequence_numbers().collect::<Vec<_>>(),
+            vec![0, 1, 2, 10]
+        );
+
+        let sequence_numbers = reader
+            .iter()
+            .map(|checkpoint| {
+                checkpoint
+                    .unwrap()
+                    .checkpoint_summary
+                    .checkpoint
+                    .sequence_number
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sequence_numbers, vec![0, 1, 2, 10]);
+        assert_eq!(reader.iter_from(2).count(), 2);
+
+        assert_eq!(reader.read(1).unwrap().unwrap(), checkpoint(1));
+        assert!(reader.read(5).unwrap().is_none());
+
+        // A single file can be opened directly
+        let reader = ArchiveReader::open(dir.join("10.chk")).unwrap();
+        assert_eq!(reader.sequence_numbers().collect::<Vec<_>>(), vec![10]);
+        ArchiveReader::open(dir.join("README")).unwrap_err();
+
+        // The contents of a file must match its name
+        std::fs::rename(dir.join("10.chk"), dir.join("11.chk")).unwrap();
+        let reader = ArchiveReader::open(&dir).unwrap();
+        assert!(matches!(
+            reader.read(11),
+            Err(Error::SequenceNumberMismatch {
+                expected: 11,
+                found: 10
+            })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
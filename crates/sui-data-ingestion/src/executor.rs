@@ -0,0 +1,306 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::CheckpointSource;
+use crate::Error;
+use crate::WatermarkFile;
+use crate::Worker;
+use futures::StreamExt;
+use sui_sdk_types::types::CheckpointSequenceNumber;
+
+/// The default number of checkpoints processed concurrently.
+const DEFAULT_CONCURRENCY: usize = 10;
+
+/// Runs a [`Worker`] against every checkpoint produced by a [`CheckpointSource`].
+///
+/// Up to `concurrency` checkpoints are processed at a time, each on its own tokio task. The
+/// source is only polled for more checkpoints when there is capacity to process them, providing
+/// backpressure to sources which are able to produce checkpoints faster than they can be
+/// processed.
+///
+/// Progress is tracked using a watermark: the sequence number of the lowest checkpoint which
+/// hasn't been processed yet. If a [`WatermarkFile`] is configured, the watermark is persisted
+/// each time it advances and ingestion resumes from it when the executor is next run.
+pub struct IngestionExecutor<W> {
+    worker: Arc<W>,
+    concurrency: usize,
+    watermark_file: Option<WatermarkFile>,
+    start: CheckpointSequenceNumber,
+    end: Option<CheckpointSequenceNumber>,
+}
+
+impl<W: Worker> IngestionExecutor<W> {
+    pub fn new(worker: W) -> Self {
+        Self {
+            worker: Arc::new(worker),
+            concurrency: DEFAULT_CONCURRENCY,
+            watermark_file: None,
+            start: 0,
+            end: None,
+        }
+    }
+
+    /// Set the maximum number of checkpoints processed concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concurrency` is 0.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        assert!(concurrency > 0, "concurrency must be non-zero");
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Persist progress to, and resume from, the provided watermark file.
+    pub fn with_watermark_file(mut self, watermark_file: WatermarkFile) -> Self {
+        self.watermark_file = Some(watermark_file);
+        self
+    }
+
+    /// Set the checkpoint to start ingesting from when no watermark has been persisted yet.
+    /// Defaults to 0.
+    pub fn with_start(mut self, start: CheckpointSequenceNumber) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Stop ingesting once checkpoint `end` has been processed.
+    pub fn with_end(mut self, end: CheckpointSequenceNumber) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Ingest checkpoints from `source` until either the source is exhausted, the configured end
+    /// checkpoint has been processed, or an error occurs.
+    ///
+    /// On success the final watermark is returned, i.e. the sequence number of the next
+    /// checkpoint to be processed. If processing a checkpoint fails, ingestion stops and the
+    /// error is returned; the watermark is left at the failed checkpoint so that it is retried
+    /// on the next run. Any other checkpoints still being processed at that point are not waited
+    /// on.
+    pub async fn run<S: CheckpointSource>(
+        self,
+        source: S,
+    ) -> Result<CheckpointSequenceNumber, Error> {
+        let start = match &self.watermark_file {
+            Some(watermark_file) => watermark_file.load().await?.unwrap_or(self.start),
+            None => self.start,
+        };
+        // Stop as soon as the end checkpoint has been produced rather than waiting on the source
+        // for the one after it, which may never arrive when tailing a directory.
+        let limit = match self.end {
+            Some(end) => end.checked_sub(start).map_or(0, |remaining| {
+                usize::try_from(remaining)
+                    .unwrap_or(usize::MAX)
+                    .saturating_add(1)
+            }),
+            None => usize::MAX,
+        };
+
+        let mut expected = start;
+        let worker = self.worker;
+        let results = source
+            .into_stream(start)
+            .take(limit)
+            .map(move |checkpoint| {
+                // Checkpoints must be delivered in order, without gaps, for the watermark to be
+                // meaningful.
+                let checkpoint = checkpoint.and_then(|checkpoint| {
+                    let found = checkpoint.checkpoint_summary.checkpoint.sequence_number;
+                    if found != expected {
+                        return Err(Error::SequenceNumberMismatch { expected, found });
+                    }
+                    expected += 1;
+                    Ok(checkpoint)
+                });
+
+                let worker = worker.clone();
+                async move {
+                    let checkpoint = checkpoint?;
+                    let sequence_number = checkpoint.checkpoint_summary.checkpoint.sequence_number;
+                    tokio::spawn(async move { worker.process_checkpoint(&checkpoint).await })
+                        .await
+                        .map_err(Into::into)
+                        .and_then(|result| result)
+                        .map_err(|source| Error::Worker {
+                            sequence_number,
+                            source,
+                        })?;
+                    Ok::<_, Error>(sequence_number)
+                }
+            })
+            .buffer_unordered(self.concurrency);
+        let mut results = std::pin::pin!(results);
+
+        let mut watermark = start;
+        let mut completed = BTreeSet::new();
+        while let Some(result) = results.next().await {
+            completed.insert(result?);
+
+            let previous = watermark;
+            while completed.remove(&watermark) {
+                watermark += 1;
+            }
+
+            if watermark != previous {
+                if let Some(watermark_file) = &self.watermark_file {
+                    watermark_file.store(watermark).await?;
+                }
+            }
+        }
+
+        Ok(watermark)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::archive::test::checkpoint;
+    use crate::archive::test::temp_dir;
+    use crate::archive::test::write_checkpoint;
+    use crate::BoxError;
+    use crate::DirectorySource;
+    use crate::StreamSource;
+    use sui_sdk_types::types::CheckpointData;
+
+    /// Records the checkpoints it processes, failing on `fail_on` if set.
+    #[derive(Clone, Default)]
+    struct RecordingWorker {
+        processed: Arc<Mutex<Vec<CheckpointSequenceNumber>>>,
+        fail_on: Option<CheckpointSequenceNumber>,
+    }
+
+    impl RecordingWorker {
+        fn processed(&self) -> Vec<CheckpointSequenceNumber> {
+            let mut processed = self.processed.lock().unwrap().clone();
+            processed.sort();
+            processed
+        }
+    }
+
+    impl Worker for RecordingWorker {
+        async fn process_checkpoint(&self, checkpoint: &CheckpointData) -> Result<(), BoxError> {
+            let sequence_number = checkpoint.checkpoint_summary.checkpoint.sequence_number;
+            if self.fail_on == Some(sequence_number) {
+                return Err(format!("failed on {sequence_number}").into());
+            }
+            self.processed.lock().unwrap().push(sequence_number);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn resume_from_watermark() {
+        let dir = temp_dir("resume-from-watermark");
+        let checkpoints = dir.join("checkpoints");
+        std::fs::create_dir(&checkpoints).unwrap();
+        let watermark_file = WatermarkFile::new(dir.join("watermark"));
+        for sequence_number in 0..10 {
+            write_checkpoint(&checkpoints, &checkpoint(sequence_number));
+        }
+
+        let worker = RecordingWorker::default();
+        let watermark = IngestionExecutor::new(worker.clone())
+            .with_concurrency(3)
+            .with_watermark_file(watermark_file.clone())
+            .run(DirectorySource::new(&checkpoints))
+            .await
+            .unwrap();
+        assert_eq!(watermark, 10);
+        assert_eq!(watermark_file.load().await.unwrap(), Some(10));
+        assert_eq!(worker.processed(), (0..10).collect::<Vec<_>>());
+
+        // Processing fails part way through the next batch
+        for sequence_number in 10..15 {
+            write_checkpoint(&checkpoints, &checkpoint(sequence_number));
+        }
+        let worker = RecordingWorker {
+            fail_on: Some(12),
+            ..Default::default()
+        };
+        let error = IngestionExecutor::new(worker.clone())
+            .with_concurrency(1)
+            .with_watermark_file(watermark_file.clone())
+            .run(DirectorySource::new(&checkpoints))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Worker {
+                sequence_number: 12,
+                ..
+            }
+        ));
+        assert_eq!(worker.processed(), vec![10, 11]);
+        assert_eq!(watermark_file.load().await.unwrap(), Some(12));
+
+        // Resuming picks up from the failed checkpoint
+        let worker = RecordingWorker::default();
+        let watermark = IngestionExecutor::new(worker.clone())
+            .with_watermark_file(watermark_file.clone())
+            .with_end(13)
+            .run(DirectorySource::new(&checkpoints))
+            .await
+            .unwrap();
+        assert_eq!(watermark, 14);
+        assert_eq!(worker.processed(), vec![12, 13]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn end_while_polling() {
+        let dir = temp_dir("end-while-polling");
+        for sequence_number in 0..3 {
+            write_checkpoint(&dir, &checkpoint(sequence_number));
+        }
+
+        // The source waits forever for checkpoint 3, so the executor must stop once checkpoint 2
+        // is produced without polling for the next one.
+        let worker = RecordingWorker::default();
+        let watermark = tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            IngestionExecutor::new(worker.clone())
+                .with_start(1)
+                .with_end(2)
+                .run(
+                    DirectorySource::new(&dir)
+                        .with_poll_interval(std::time::Duration::from_millis(10)),
+                ),
+        )
+        .await
+        .expect("executor should stop at the end checkpoint")
+        .unwrap();
+        assert_eq!(watermark, 3);
+        assert_eq!(worker.processed(), vec![1, 2]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_source() {
+        let stream = futures::stream::iter(
+            [0, 1, 2, 3, 5].map(|sequence_number| Ok::<_, BoxError>(checkpoint(sequence_number))),
+        );
+
+        // Checkpoints before the start are skipped, while gaps are reported
+        let worker = RecordingWorker::default();
+        let error = IngestionExecutor::new(worker.clone())
+            .with_concurrency(1)
+            .with_start(2)
+            .run(StreamSource::new(stream))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::SequenceNumberMismatch {
+                expected: 4,
+                found: 5
+            }
+        ));
+        assert_eq!(worker.processed(), vec![2, 3]);
+    }
+}
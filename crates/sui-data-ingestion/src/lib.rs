@@ -0,0 +1,114 @@
+//! Utilities for reading and processing the checkpoint data produced by Sui's data ingestion
+//! framework.
+//!
+//! Checkpoints are stored one per file, named `<sequence number>.chk`, with each file holding a
+//! BCS encoded [`CheckpointData`] wrapped in a small envelope describing how the payload is
+//! encoded, optionally compressed using zstd. See [`ArchiveReader`] for reading checkpoints from a
+//! local file or directory.
+//!
+//! For continuous processing, an [`IngestionExecutor`] drives a [`Worker`] over every checkpoint
+//! produced by a [`CheckpointSource`], with bounded concurrency and a persisted watermark allowing
+//! ingestion to resume after a restart.
+
+use std::path::PathBuf;
+
+use sui_sdk_types::types::CheckpointSequenceNumber;
+
+mod archive;
+mod executor;
+mod object_set;
+mod source;
+mod watermark;
+mod worker;
+
+pub use archive::decode_checkpoint;
+pub use archive::encode_checkpoint;
+pub use archive::ArchiveReader;
+pub use archive::Checkpoints;
+pub use archive::CHECKPOINT_FILE_SUFFIX;
+pub use executor::IngestionExecutor;
+pub use object_set::LiveObjectSet;
+pub use source::CheckpointSource;
+pub use source::DirectorySource;
+#[cfg(feature = "graphql")]
+pub use source::GraphQlSource;
+pub use source::StreamSource;
+pub use sui_sdk_types::types::CheckpointData;
+pub use watermark::WatermarkFile;
+pub use worker::Worker;
+
+/// A boxed error, as returned by [`Worker`]s and [`StreamSource`]s.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Errors that can occur when reading or processing checkpoint data.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An IO error occurred while accessing `path`.
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The envelope surrounding a checkpoint was malformed or uses an unsupported encoding.
+    InvalidEnvelope(String),
+    /// A compressed checkpoint file could not be decompressed.
+    Decompression(std::io::Error),
+    /// The checkpoint payload could not be decoded.
+    Bcs(bcs::Error),
+    /// A checkpoint source failed to produce a checkpoint.
+    Source(BoxError),
+    /// A worker failed to process a checkpoint.
+    Worker {
+        sequence_number: CheckpointSequenceNumber,
+        source: BoxError,
+    },
+    /// A different checkpoint was found than the one expected, e.g. a checkpoint file contained a
+    /// different checkpoint than its name indicated or a source skipped a checkpoint.
+    SequenceNumberMismatch {
+        expected: CheckpointSequenceNumber,
+        found: CheckpointSequenceNumber,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "unable to read {}: {source}", path.display()),
+            Error::InvalidEnvelope(reason) => write!(f, "invalid checkpoint envelope: {reason}"),
+            Error::Decompression(e) => write!(f, "unable to decompress checkpoint: {e}"),
+            Error::Bcs(e) => write!(f, "unable to decode checkpoint: {e}"),
+            Error::Source(e) => write!(f, "checkpoint source failed: {e}"),
+            Error::Worker {
+                sequence_number,
+                source,
+            } => write!(
+                f,
+                "failed to process checkpoint {sequence_number}: {source}"
+            ),
+            Error::SequenceNumberMismatch { expected, found } => {
+                write!(
+                    f,
+                    "expected checkpoint {expected} but found checkpoint {found}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Decompression(e) => Some(e),
+            Error::Bcs(e) => Some(e),
+            Error::Source(e) | Error::Worker { source: e, .. } => Some(e.as_ref()),
+            Error::InvalidEnvelope(_) | Error::SequenceNumberMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<bcs::Error> for Error {
+    fn from(e: bcs::Error) -> Self {
+        Error::Bcs(e)
+    }
+}
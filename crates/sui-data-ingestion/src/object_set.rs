@@ -0,0 +1,391 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::Error;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::CheckpointData;
+use sui_sdk_types::types::CheckpointSequenceNumber;
+use sui_sdk_types::types::CheckpointTransaction;
+use sui_sdk_types::types::Object;
+use sui_sdk_types::types::ObjectData;
+use sui_sdk_types::types::ObjectId;
+use sui_sdk_types::types::ObjectOut;
+use sui_sdk_types::types::Owner;
+use sui_sdk_types::types::StructTag;
+use sui_sdk_types::types::TransactionEffects;
+
+/// An in-memory set of live objects, maintained by applying checkpoints in sequence.
+///
+/// For each transaction in a checkpoint the objects written by the transaction replace any
+/// previous versions held by the set, while objects which the transaction's effects report as
+/// deleted or wrapped are removed. Objects read by a transaction but not modified are added to
+/// the set if not already present, allowing the set to be built up starting from any checkpoint,
+/// with the understanding that it only contains the objects which have been observed since.
+///
+/// Objects are indexed by their id, their owning [`Address`] and, for Move objects, their type.
+#[derive(Clone, Debug, Default)]
+pub struct LiveObjectSet {
+    checkpoint: Option<CheckpointSequenceNumber>,
+    objects: BTreeMap<ObjectId, Object>,
+    by_owner: HashMap<Address, BTreeSet<ObjectId>>,
+    by_type: HashMap<StructTag, BTreeSet<ObjectId>>,
+}
+
+impl LiveObjectSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The sequence number of the last checkpoint applied to this set.
+    pub fn checkpoint(&self) -> Option<CheckpointSequenceNumber> {
+        self.checkpoint
+    }
+
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    pub fn get(&self, object_id: &ObjectId) -> Option<&Object> {
+        self.objects.get(object_id)
+    }
+
+    /// Iterate over all objects in the set, in order of their id.
+    pub fn iter(&self) -> impl Iterator<Item = &Object> + '_ {
+        self.objects.values()
+    }
+
+    /// Iterate over all objects owned by `owner`, in order of their id.
+    pub fn objects_owned_by(&self, owner: &Address) -> impl Iterator<Item = &Object> + '_ {
+        self.lookup(self.by_owner.get(owner))
+    }
+
+    /// Iterate over all Move objects of type `object_type`, in order of their id.
+    pub fn objects_of_type(&self, object_type: &StructTag) -> impl Iterator<Item = &Object> + '_ {
+        self.lookup(self.by_type.get(object_type))
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        ids: Option<&'a BTreeSet<ObjectId>>,
+    ) -> impl Iterator<Item = &'a Object> + 'a {
+        ids.into_iter()
+            .flatten()
+            .filter_map(|object_id| self.objects.get(object_id))
+    }
+
+    /// Apply a checkpoint to this set.
+    ///
+    /// Checkpoints must be applied in order, once a checkpoint has been applied only the
+    /// checkpoint immediately following it can be applied.
+    pub fn apply_checkpoint(&mut self, checkpoint: &CheckpointData) -> Result<(), Error> {
+        let found = checkpoint.checkpoint_summary.checkpoint.sequence_number;
+        if let Some(expected) = self.checkpoint.map(|checkpoint| checkpoint + 1) {
+            if found != expected {
+                return Err(Error::SequenceNumberMismatch { expected, found });
+            }
+        }
+
+        for transaction in &checkpoint.transactions {
+            self.apply_transaction(transaction);
+        }
+        self.checkpoint = Some(found);
+
+        Ok(())
+    }
+
+    fn apply_transaction(&mut self, transaction: &CheckpointTransaction) {
+        for object in &transaction.input_objects {
+            let is_newer = match self.objects.get(&object.object_id()) {
+                Some(existing) => existing.version() < object.version(),
+                None => true,
+            };
+            if is_newer {
+                self.insert(object.clone());
+            }
+        }
+
+        for object_id in removed_objects(&transaction.effects) {
+            self.remove(&object_id);
+        }
+
+        for object in &transaction.output_objects {
+            self.insert(object.clone());
+        }
+    }
+
+    fn insert(&mut self, object: Object) {
+        let object_id = object.object_id();
+        self.remove(&object_id);
+
+        if let Owner::Address(owner) = object.owner() {
+            self.by_owner.entry(*owner).or_default().insert(object_id);
+        }
+        if let ObjectData::Struct(move_struct) = object.data() {
+            self.by_type
+                .entry(move_struct.object_type().clone())
+                .or_default()
+                .insert(object_id);
+        }
+        self.objects.insert(object_id, object);
+    }
+
+    fn remove(&mut self, object_id: &ObjectId) -> Option<Object> {
+        let object = self.objects.remove(object_id)?;
+
+        if let Owner::Address(owner) = object.owner() {
+            remove_from_index(&mut self.by_owner, owner, object_id);
+        }
+        if let ObjectData::Struct(move_struct) = object.data() {
+            remove_from_index(&mut self.by_type, move_struct.object_type(), object_id);
+        }
+
+        Some(object)
+    }
+
+    /// Write a snapshot of this set to `path`, which can later be loaded using
+    /// [`LiveObjectSet::restore`].
+    ///
+    /// The snapshot is written to a temporary file which is then renamed to `path`, ensuring an
+    /// existing snapshot is never left partially overwritten.
+    pub fn snapshot<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let objects = self.objects.values().collect::<Vec<_>>();
+        let bytes = bcs::to_bytes(&(self.checkpoint, objects))?;
+
+        let mut tmp = path.to_owned().into_os_string();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, bytes)
+            .and_then(|()| std::fs::rename(&tmp, path))
+            .map_err(|source| Error::Io {
+                path: path.to_owned(),
+                source,
+            })
+    }
+
+    /// Restore a set from a snapshot previously written by [`LiveObjectSet::snapshot`].
+    pub fn restore<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        let (checkpoint, objects): (Option<CheckpointSequenceNumber>, Vec<Object>) =
+            bcs::from_bytes(&bytes)?;
+
+        let mut set = Self {
+            checkpoint,
+            ..Default::default()
+        };
+        for object in objects {
+            set.insert(object);
+        }
+        Ok(set)
+    }
+}
+
+/// The ids of the objects which no longer exist at the top level, i.e. were deleted or wrapped,
+/// after a transaction has been executed.
+fn removed_objects(effects: &TransactionEffects) -> Vec<ObjectId> {
+    match effects {
+        TransactionEffects::V1(effects) => effects
+            .deleted
+            .iter()
+            .chain(&effects.unwrapped_then_deleted)
+            .chain(&effects.wrapped)
+            .map(|object_ref| *object_ref.object_id())
+            .collect(),
+        TransactionEffects::V2(effects) => effects
+            .changed_objects
+            .iter()
+            .filter(|changed| matches!(changed.change.output_state, ObjectOut::NotExist))
+            .map(|changed| changed.object_id)
+            .collect(),
+    }
+}
+
+fn remove_from_index<K: std::hash::Hash + Eq>(
+    index: &mut HashMap<K, BTreeSet<ObjectId>>,
+    key: &K,
+    object_id: &ObjectId,
+) {
+    if let Some(ids) = index.get_mut(key) {
+        ids.remove(object_id);
+        if ids.is_empty() {
+            index.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::archive::test::checkpoint;
+    use crate::archive::test::temp_dir;
+    use sui_sdk_types::types::ChangedObject;
+    use sui_sdk_types::types::EffectsObjectChange;
+    use sui_sdk_types::types::ExecutionStatus;
+    use sui_sdk_types::types::GasCostSummary;
+    use sui_sdk_types::types::GasPayment;
+    use sui_sdk_types::types::IdOperation;
+    use sui_sdk_types::types::MoveStruct;
+    use sui_sdk_types::types::ObjectDigest;
+    use sui_sdk_types::types::ObjectIn;
+    use sui_sdk_types::types::ProgrammableTransaction;
+    use sui_sdk_types::types::SignedTransaction;
+    use sui_sdk_types::types::Transaction;
+    use sui_sdk_types::types::TransactionDigest;
+    use sui_sdk_types::types::TransactionEffectsV2;
+    use sui_sdk_types::types::TransactionExpiration;
+    use sui_sdk_types::types::TransactionKind;
+
+    const ALICE: Address = Address::new([0xa; 32]);
+    const BOB: Address = Address::new([0xb; 32]);
+
+    fn coin_type() -> StructTag {
+        "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap()
+    }
+
+    fn object(id: u8, version: u64, owner: Address, object_type: StructTag) -> Object {
+        let mut contents = vec![id; ObjectId::LENGTH];
+        contents.extend_from_slice(&version.to_le_bytes());
+        Object::new(
+            ObjectData::Struct(MoveStruct::new(object_type, true, version, contents).unwrap()),
+            Owner::Address(owner),
+            TransactionDigest::ZERO,
+            0,
+        )
+    }
+
+    fn transaction(
+        input_objects: Vec<Object>,
+        output_objects: Vec<Object>,
+        removed: Vec<ObjectId>,
+    ) -> CheckpointTransaction {
+        let changed_objects = removed
+            .into_iter()
+            .map(|object_id| ChangedObject {
+                object_id,
+                change: EffectsObjectChange {
+                    input_state: ObjectIn::Exist {
+                        version: 1,
+                        digest: ObjectDigest::ZERO,
+                        owner: Owner::Address(ALICE),
+                    },
+                    output_state: ObjectOut::NotExist,
+                    id_operation: IdOperation::Deleted,
+                },
+            })
+            .collect();
+
+        CheckpointTransaction {
+            transaction: SignedTransaction {
+                transaction: Transaction {
+                    kind: TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+                        inputs: vec![],
+                        commands: vec![],
+                    }),
+                    sender: ALICE,
+                    gas_payment: GasPayment {
+                        objects: vec![],
+                        owner: ALICE,
+                        price: 1000,
+                        budget: 1000,
+                    },
+                    expiration: TransactionExpiration::None,
+                },
+                signatures: vec![],
+            },
+            effects: TransactionEffects::V2(Box::new(TransactionEffectsV2 {
+                status: ExecutionStatus::Success,
+                epoch: 0,
+                gas_used: GasCostSummary::new(0, 0, 0, 0),
+                transaction_digest: TransactionDigest::ZERO,
+                gas_object_index: None,
+                events_digest: None,
+                dependencies: vec![],
+                lamport_version: 2,
+                changed_objects,
+                unchanged_shared_objects: vec![],
+                auxiliary_data_digest: None,
+            })),
+            events: None,
+            input_objects,
+            output_objects,
+        }
+    }
+
+    #[test]
+    fn apply_checkpoints() {
+        let nft_type: StructTag = "0x1234::nft::Nft".parse().unwrap();
+        let mut set = LiveObjectSet::new();
+
+        let mut first = checkpoint(5);
+        first.transactions.push(transaction(
+            vec![object(1, 1, ALICE, coin_type())],
+            vec![
+                object(1, 2, ALICE, coin_type()),
+                object(2, 2, ALICE, nft_type.clone()),
+                object(3, 2, BOB, coin_type()),
+            ],
+            vec![],
+        ));
+        set.apply_checkpoint(&first).unwrap();
+        assert_eq!(set.checkpoint(), Some(5));
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.objects_owned_by(&ALICE).count(), 2);
+        assert_eq!(set.objects_of_type(&coin_type()).count(), 2);
+        assert_eq!(set.objects_of_type(&nft_type).count(), 1);
+
+        // Checkpoints must be applied in order
+        assert!(matches!(
+            set.apply_checkpoint(&checkpoint(7)),
+            Err(Error::SequenceNumberMismatch {
+                expected: 6,
+                found: 7
+            })
+        ));
+
+        // Transfer the nft to bob and delete alice's coin, reading an older version of bob's coin
+        let mut second = checkpoint(6);
+        second.transactions.push(transaction(
+            vec![
+                object(1, 2, ALICE, coin_type()),
+                object(2, 2, ALICE, nft_type.clone()),
+                object(3, 1, BOB, coin_type()),
+            ],
+            vec![object(2, 3, BOB, nft_type.clone())],
+            vec![ObjectId::new([1; 32])],
+        ));
+        set.apply_checkpoint(&second).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.get(&ObjectId::new([1; 32])).is_none());
+        assert_eq!(set.get(&ObjectId::new([3; 32])).unwrap().version(), 2);
+        assert_eq!(set.objects_owned_by(&ALICE).count(), 0);
+        assert_eq!(
+            set.objects_owned_by(&BOB)
+                .map(Object::object_id)
+                .collect::<Vec<_>>(),
+            vec![ObjectId::new([2; 32]), ObjectId::new([3; 32])]
+        );
+        assert_eq!(set.objects_of_type(&coin_type()).count(), 1);
+
+        // Round-trip through a snapshot
+        let dir = temp_dir("live-object-set");
+        let path = dir.join("snapshot");
+        set.snapshot(&path).unwrap();
+        let restored = LiveObjectSet::restore(&path).unwrap();
+        assert_eq!(restored.checkpoint(), Some(6));
+        assert_eq!(
+            restored.iter().collect::<Vec<_>>(),
+            set.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(restored.objects_owned_by(&BOB).count(), 2);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,188 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::BoxError;
+use crate::Error;
+use crate::CHECKPOINT_FILE_SUFFIX;
+use futures::Stream;
+use futures::StreamExt;
+use sui_sdk_types::types::CheckpointData;
+use sui_sdk_types::types::CheckpointSequenceNumber;
+
+/// A source of checkpoints for an [`IngestionExecutor`](crate::IngestionExecutor).
+///
+/// Two sources are provided: [`DirectorySource`], which reads checkpoint files from a local
+/// directory, and [`StreamSource`], which adapts any stream of checkpoints. With the `graphql`
+/// feature enabled, `GraphQlSource` fetches checkpoints from a GraphQL server. Other sources can
+/// be built by implementing this trait or by wrapping a stream in a [`StreamSource`].
+pub trait CheckpointSource {
+    /// Produce a stream of checkpoints in order, starting with checkpoint `start`.
+    fn into_stream(
+        self,
+        start: CheckpointSequenceNumber,
+    ) -> impl Stream<Item = Result<CheckpointData, Error>> + Send;
+}
+
+/// A [`CheckpointSource`] which reads checkpoint files, named `<sequence number>.chk`, from a
+/// local directory.
+///
+/// By default the stream ends at the first checkpoint which is missing from the directory.
+/// Alternatively, if a poll interval is configured, the directory is tailed with the source
+/// waiting for missing checkpoints to be written, e.g. by a fullnode, before continuing.
+#[derive(Clone, Debug)]
+pub struct DirectorySource {
+    path: PathBuf,
+    poll_interval: Option<Duration>,
+}
+
+impl DirectorySource {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+            poll_interval: None,
+        }
+    }
+
+    /// Wait for missing checkpoints to appear, checking for them every `poll_interval`.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    async fn next_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Option<Result<CheckpointData, Error>> {
+        let path = self
+            .path
+            .join(format!("{sequence_number}.{CHECKPOINT_FILE_SUFFIX}"));
+
+        loop {
+            match tokio::fs::read(&path).await {
+                Ok(bytes) => return Some(crate::decode_checkpoint(&bytes)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    // When not tailing the directory the stream ends at the first gap
+                    let poll_interval = self.poll_interval?;
+                    tokio::time::sleep(poll_interval).await;
+                }
+                Err(source) => return Some(Err(Error::Io { path, source })),
+            }
+        }
+    }
+}
+
+impl CheckpointSource for DirectorySource {
+    fn into_stream(
+        self,
+        start: CheckpointSequenceNumber,
+    ) -> impl Stream<Item = Result<CheckpointData, Error>> + Send {
+        futures::stream::unfold(Some(start), move |sequence_number| {
+            let source = self.clone();
+            async move {
+                let sequence_number = sequence_number?;
+                let checkpoint = source.next_checkpoint(sequence_number).await?;
+                // Stop after the first error
+                let next = checkpoint.is_ok().then_some(sequence_number + 1);
+                Some((checkpoint, next))
+            }
+        })
+    }
+}
+
+/// A [`CheckpointSource`] which adapts an existing stream of checkpoints.
+///
+/// Checkpoints preceding the requested starting checkpoint are skipped, allowing a stream which
+/// starts earlier than the persisted watermark to be resumed from.
+#[derive(Debug)]
+pub struct StreamSource<S> {
+    stream: S,
+}
+
+impl<S> StreamSource<S> {
+    pub fn new(stream: S) -> Self {
+        Self { stream }
+    }
+}
+
+impl<S, E> CheckpointSource for StreamSource<S>
+where
+    S: Stream<Item = Result<CheckpointData, E>> + Send,
+    E: Into<BoxError>,
+{
+    fn into_stream(
+        self,
+        start: CheckpointSequenceNumber,
+    ) -> impl Stream<Item = Result<CheckpointData, Error>> + Send {
+        self.stream
+            .map(|checkpoint| checkpoint.map_err(|e| Error::Source(e.into())))
+            .filter(move |checkpoint| {
+                let skip = matches!(
+                    checkpoint,
+                    Ok(checkpoint) if checkpoint.checkpoint_summary.checkpoint.sequence_number < start
+                );
+                std::future::ready(!skip)
+            })
+    }
+}
+
+/// A [`CheckpointSource`] which fetches checkpoints from a GraphQL server using
+/// [`Client::checkpoint_data`](sui_graphql_client::Client::checkpoint_data).
+///
+/// By default the stream ends at the first checkpoint which the server doesn't know about yet.
+/// Alternatively, if a poll interval is configured, the source waits for new checkpoints to be
+/// produced before continuing.
+#[cfg(feature = "graphql")]
+pub struct GraphQlSource {
+    client: sui_graphql_client::Client,
+    poll_interval: Option<Duration>,
+}
+
+#[cfg(feature = "graphql")]
+impl GraphQlSource {
+    pub fn new(client: sui_graphql_client::Client) -> Self {
+        Self {
+            client,
+            poll_interval: None,
+        }
+    }
+
+    /// Wait for new checkpoints to be produced, checking for them every `poll_interval`.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    async fn next_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Option<Result<CheckpointData, Error>> {
+        loop {
+            match self.client.checkpoint_data(sequence_number).await {
+                Ok(Some(checkpoint)) => return Some(Ok(checkpoint)),
+                Ok(None) => {
+                    // When not polling the stream ends at the latest checkpoint
+                    let poll_interval = self.poll_interval?;
+                    tokio::time::sleep(poll_interval).await;
+                }
+                Err(e) => return Some(Err(Error::Source(e.into()))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "graphql")]
+impl CheckpointSource for GraphQlSource {
+    fn into_stream(
+        self,
+        start: CheckpointSequenceNumber,
+    ) -> impl Stream<Item = Result<CheckpointData, Error>> + Send {
+        futures::stream::unfold(Some((self, start)), |state| async move {
+            let (source, sequence_number) = state?;
+            let checkpoint = source.next_checkpoint(sequence_number).await?;
+            // Stop after the first error
+            let next = checkpoint.is_ok().then_some((source, sequence_number + 1));
+            Some((checkpoint, next))
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::Error;
+use sui_sdk_types::types::CheckpointSequenceNumber;
+
+/// A file used to persist the progress of an [`IngestionExecutor`](crate::IngestionExecutor)
+/// so that ingestion can resume where it left off after a restart or crash.
+///
+/// The file holds the sequence number of the next checkpoint to be processed, encoded as a base
+/// 10 integer. Updates are written to a temporary file which is then renamed over the watermark
+/// file, ensuring the file is never left partially written.
+#[derive(Clone, Debug)]
+pub struct WatermarkFile {
+    path: PathBuf,
+}
+
+impl WatermarkFile {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load the persisted watermark, returning `None` if one hasn't been persisted yet.
+    pub async fn load(&self) -> Result<Option<CheckpointSequenceNumber>, Error> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => return Err(self.io_error(source)),
+        };
+
+        contents
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| self.io_error(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))
+    }
+
+    /// Persist `watermark`, the sequence number of the next checkpoint to be processed.
+    pub async fn store(&self, watermark: CheckpointSequenceNumber) -> Result<(), Error> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+
+        tokio::fs::write(&tmp, watermark.to_string())
+            .await
+            .map_err(|source| self.io_error(source))?;
+        tokio::fs::rename(&tmp, &self.path)
+            .await
+            .map_err(|source| self.io_error(source))
+    }
+
+    fn io_error(&self, source: std::io::Error) -> Error {
+        Error::Io {
+            path: self.path.clone(),
+            source,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use std::future::Future;
+
+use crate::BoxError;
+use sui_sdk_types::types::CheckpointData;
+
+/// A unit of processing which is run against every checkpoint ingested by an
+/// [`IngestionExecutor`](crate::IngestionExecutor).
+///
+/// Checkpoints may be processed concurrently, and so potentially out of order, by a single
+/// worker. A checkpoint is only considered processed, and the watermark allowed to advance past
+/// it, once `process_checkpoint` has returned successfully. After a crash a worker may therefore
+/// be handed checkpoints it had already processed, so processing should be idempotent.
+pub trait Worker: Send + Sync + 'static {
+    fn process_checkpoint(
+        &self,
+        checkpoint: &CheckpointData,
+    ) -> impl Future<Output = Result<(), BoxError>> + Send;
+}
@@ -0,0 +1,262 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Estimation of the gas price and gas budget of a transaction.
+//!
+//! A [`GasEstimator`] turns the [`GasCostSummary`] of a dry run into a [`GasEstimate`], detailing
+//! how the gas price and the gas budget were chosen. Use [`Client::estimate_gas`] to dry run a
+//! transaction and estimate its gas in one go.
+//!
+//! [`Client::estimate_gas`]: crate::Client::estimate_gas
+
+use crate::query_types::ProtocolConfigs;
+
+use sui_types::types::GasCostSummary;
+
+/// How the gas price of a transaction is chosen, relative to the reference gas price of the
+/// current epoch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GasPricePolicy {
+    /// Pay the reference gas price.
+    #[default]
+    Reference,
+    /// Pay the reference gas price plus a tip, in MIST per gas unit, to prioritize the
+    /// transaction when the network is congested.
+    Tip(u64),
+}
+
+/// Estimates the gas price and gas budget of a transaction from the results of a dry run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasEstimator {
+    price_policy: GasPricePolicy,
+    safety_margin_percent: u64,
+}
+
+impl GasEstimator {
+    /// The default safety margin added to the gas used by the dry run, as a percentage.
+    pub const DEFAULT_SAFETY_MARGIN_PERCENT: u64 = 10;
+
+    pub fn new() -> Self {
+        Self {
+            price_policy: GasPricePolicy::default(),
+            safety_margin_percent: Self::DEFAULT_SAFETY_MARGIN_PERCENT,
+        }
+    }
+
+    /// Choose the gas price according to `price_policy`.
+    pub fn with_price_policy(mut self, price_policy: GasPricePolicy) -> Self {
+        self.price_policy = price_policy;
+        self
+    }
+
+    /// Add `percent` percent of the gas used by the dry run to the budget, accounting for changes
+    /// in the state of the chain between the dry run and the execution of the transaction.
+    pub fn with_safety_margin_percent(mut self, percent: u64) -> Self {
+        self.safety_margin_percent = percent;
+        self
+    }
+
+    pub fn price_policy(&self) -> GasPricePolicy {
+        self.price_policy
+    }
+
+    pub fn safety_margin_percent(&self) -> u64 {
+        self.safety_margin_percent
+    }
+
+    /// The gas price to use given the `reference_gas_price` of the current epoch, capped to the
+    /// `max_gas_price` of the protocol config if one is provided.
+    pub fn gas_price(&self, reference_gas_price: u64, config: Option<&ProtocolConfigs>) -> u64 {
+        let tip = match self.price_policy {
+            GasPricePolicy::Reference => 0,
+            GasPricePolicy::Tip(tip) => tip,
+        };
+        let price = reference_gas_price.saturating_add(tip);
+
+        match config.and_then(|config| config_u64(config, "max_gas_price")) {
+            Some(max_gas_price) => price.min(max_gas_price),
+            None => price,
+        }
+    }
+
+    /// Estimate the gas of a transaction from the `cost_summary` of a dry run executed at the
+    /// gas price returned by [`gas_price`](Self::gas_price).
+    ///
+    /// The budget covers the computation and storage costs of the dry run, without deducting the
+    /// storage rebate which is only credited once the transaction completes, plus the safety
+    /// margin. It is then clamped between the minimal and maximal budgets of the protocol config,
+    /// if one is provided.
+    pub fn estimate(
+        &self,
+        reference_gas_price: u64,
+        cost_summary: GasCostSummary,
+        config: Option<&ProtocolConfigs>,
+    ) -> GasEstimate {
+        let gas_price = self.gas_price(reference_gas_price, config);
+        let gas_used = cost_summary
+            .computation_cost
+            .saturating_add(cost_summary.storage_cost);
+        let safety_margin = gas_used.saturating_mul(self.safety_margin_percent) / 100;
+
+        let min_budget = config
+            .and_then(|config| config_u64(config, "base_tx_cost_fixed"))
+            .map(|base_cost| base_cost.saturating_mul(gas_price));
+        let max_budget = config.and_then(|config| config_u64(config, "max_tx_gas"));
+
+        let mut budget = gas_used.saturating_add(safety_margin);
+        let mut clamped = None;
+        if let Some(min_budget) = min_budget.filter(|min_budget| budget < *min_budget) {
+            budget = min_budget;
+            clamped = Some(BudgetClamp::Min);
+        }
+        if let Some(max_budget) = max_budget.filter(|max_budget| budget > *max_budget) {
+            budget = max_budget;
+            clamped = Some(BudgetClamp::Max);
+        }
+
+        GasEstimate {
+            reference_gas_price,
+            gas_price,
+            net_cost: gas_used.saturating_sub(cost_summary.storage_rebate),
+            cost_summary,
+            safety_margin,
+            min_budget,
+            max_budget,
+            clamped,
+            budget,
+        }
+    }
+}
+
+impl Default for GasEstimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The bound of the protocol config a gas budget was clamped to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BudgetClamp {
+    /// The budget was raised to the minimal budget of a transaction.
+    Min,
+    /// The budget was lowered to the maximal budget of a transaction.
+    Max,
+}
+
+/// The gas price and gas budget estimated for a transaction, along with how they were chosen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasEstimate {
+    /// The reference gas price of the current epoch.
+    pub reference_gas_price: u64,
+    /// The gas price to use, including any tip.
+    pub gas_price: u64,
+    /// The gas costs of the dry run.
+    pub cost_summary: GasCostSummary,
+    /// The cost the sender is expected to pay once the storage rebate is credited, in MIST.
+    pub net_cost: u64,
+    /// The safety margin added to the gas used by the dry run, in MIST.
+    pub safety_margin: u64,
+    /// The minimal budget of a transaction at `gas_price`, if known.
+    pub min_budget: Option<u64>,
+    /// The maximal budget of a transaction, if known.
+    pub max_budget: Option<u64>,
+    /// The bound the budget was clamped to, if any.
+    pub clamped: Option<BudgetClamp>,
+    /// The gas budget to use, in MIST.
+    pub budget: u64,
+}
+
+impl GasEstimate {
+    /// The tip paid on top of the reference gas price, in MIST per gas unit.
+    pub fn tip(&self) -> u64 {
+        self.gas_price.saturating_sub(self.reference_gas_price)
+    }
+}
+
+impl std::fmt::Display for GasEstimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "gas price {} (reference {} + tip {}), budget {}: computation {} + storage {} + margin {}",
+            self.gas_price,
+            self.reference_gas_price,
+            self.tip(),
+            self.budget,
+            self.cost_summary.computation_cost,
+            self.cost_summary.storage_cost,
+            self.safety_margin,
+        )?;
+        match self.clamped {
+            Some(BudgetClamp::Min) => write!(f, ", raised to the minimal budget")?,
+            Some(BudgetClamp::Max) => write!(f, ", lowered to the maximal budget")?,
+            None => {}
+        }
+        write!(
+            f,
+            "; expected net cost {} after a storage rebate of {}",
+            self.net_cost, self.cost_summary.storage_rebate
+        )
+    }
+}
+
+fn config_u64(config: &ProtocolConfigs, key: &str) -> Option<u64> {
+    config.config(key)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_types::ProtocolConfigAttr;
+
+    fn protocol_config(configs: &[(&str, &str)]) -> ProtocolConfigs {
+        ProtocolConfigs {
+            protocol_version: 1,
+            feature_flags: vec![],
+            configs: configs
+                .iter()
+                .map(|(key, value)| ProtocolConfigAttr {
+                    key: key.to_string(),
+                    value: Some(value.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_gas_estimate() {
+        let config = protocol_config(&[
+            ("max_gas_price", "100000"),
+            ("max_tx_gas", "50000000000"),
+            ("base_tx_cost_fixed", "1000"),
+        ]);
+        let cost_summary = GasCostSummary::new(1_000_000, 2_000_000, 1_500_000, 15_000);
+
+        let estimator = GasEstimator::new().with_price_policy(GasPricePolicy::Tip(250));
+        let estimate = estimator.estimate(750, cost_summary.clone(), Some(&config));
+        assert_eq!(estimate.gas_price, 1000);
+        assert_eq!(estimate.tip(), 250);
+        assert_eq!(estimate.safety_margin, 300_000);
+        assert_eq!(estimate.net_cost, 1_500_000);
+        assert_eq!(estimate.min_budget, Some(1_000_000));
+        assert_eq!(estimate.clamped, None);
+        assert_eq!(estimate.budget, 3_300_000);
+
+        // The gas price is capped to the maximal gas price
+        let estimator = GasEstimator::new().with_price_policy(GasPricePolicy::Tip(u64::MAX));
+        assert_eq!(estimator.gas_price(750, Some(&config)), 100_000);
+        assert_eq!(estimator.gas_price(750, None), u64::MAX);
+
+        // The budget is clamped to the bounds of the protocol config
+        let estimate =
+            GasEstimator::new().estimate(1000, GasCostSummary::new(1000, 0, 0, 0), Some(&config));
+        assert_eq!(estimate.clamped, Some(BudgetClamp::Min));
+        assert_eq!(estimate.budget, 1_000_000);
+        let estimate = GasEstimator::new().estimate(
+            1000,
+            GasCostSummary::new(50_000_000_000, 0, 0, 0),
+            Some(&config),
+        );
+        assert_eq!(estimate.clamped, Some(BudgetClamp::Max));
+        assert_eq!(estimate.budget, 50_000_000_000);
+    }
+}
@@ -5,18 +5,28 @@
 
 pub mod error;
 pub mod faucet;
+pub mod gas;
+pub mod mvr;
 pub mod query_types;
 pub mod streams;
 
 use error::Error;
+use gas::GasEstimate;
+use gas::GasEstimator;
+use mvr::MvrOverrides;
+use mvr::MvrResolver;
 use query_types::ActiveValidatorsArgs;
 use query_types::ActiveValidatorsQuery;
+use query_types::AddressDefaultSuins;
 use query_types::BalanceArgs;
 use query_types::BalanceQuery;
 use query_types::ChainIdentifierQuery;
 use query_types::CheckpointArgs;
 use query_types::CheckpointId;
 use query_types::CheckpointQuery;
+use query_types::CheckpointTransactionBlock;
+use query_types::CheckpointTransactionsArgs;
+use query_types::CheckpointTransactionsQuery;
 use query_types::CheckpointsArgs;
 use query_types::CheckpointsQuery;
 use query_types::CoinMetadata;
@@ -24,6 +34,7 @@ use query_types::CoinMetadataArgs;
 use query_types::CoinMetadataQuery;
 use query_types::DefaultSuinsNameQuery;
 use query_types::DefaultSuinsNameQueryArgs;
+use query_types::DomainAddress;
 use query_types::DryRunArgs;
 use query_types::DryRunQuery;
 use query_types::DynamicFieldArgs;
@@ -31,6 +42,7 @@ use query_types::DynamicFieldConnectionArgs;
 use query_types::DynamicFieldQuery;
 use query_types::DynamicFieldsOwnerQuery;
 use query_types::DynamicObjectFieldQuery;
+use query_types::EpochProtocolVersionQuery;
 use query_types::EpochSummaryArgs;
 use query_types::EpochSummaryQuery;
 use query_types::EventFilter;
@@ -42,11 +54,15 @@ use query_types::LatestPackageQuery;
 use query_types::MoveFunction;
 use query_types::MoveModule;
 use query_types::MovePackageVersionFilter;
+use query_types::MvrNameArgs;
+use query_types::MvrPackageQuery;
+use query_types::MvrTypeQuery;
 use query_types::NormalizedMoveFunctionQuery;
 use query_types::NormalizedMoveFunctionQueryArgs;
 use query_types::NormalizedMoveModuleQuery;
 use query_types::NormalizedMoveModuleQueryArgs;
 use query_types::ObjectFilter;
+use query_types::ObjectKey;
 use query_types::ObjectQuery;
 use query_types::ObjectQueryArgs;
 use query_types::ObjectsQuery;
@@ -74,26 +90,55 @@ use query_types::TransactionBlockQuery;
 use query_types::TransactionBlocksEffectsQuery;
 use query_types::TransactionBlocksQuery;
 use query_types::TransactionBlocksQueryArgs;
+use query_types::TransactionEffectsPageArgs;
+use query_types::TransactionEventsQuery;
 use query_types::TransactionMetadata;
+use query_types::TransactionObjectChangesQuery;
 use query_types::TransactionsFilter;
 use query_types::Validator;
 use streams::stream_paginated_query;
 
 use sui_types::types::framework::Coin;
+use sui_types::types::framework::Field;
+use sui_types::types::framework::PoolTokenExchangeRate;
+use sui_types::types::framework::StakedSui;
+use sui_types::types::kiosk::Rule;
+use sui_types::types::suins::NameRecord;
+use sui_types::types::suins::SuinsName;
+use sui_types::types::ActiveJwk;
 use sui_types::types::Address;
+use sui_types::types::Bls12381PublicKey;
+use sui_types::types::Bls12381Signature;
+use sui_types::types::CheckpointCommitment;
+use sui_types::types::CheckpointContents;
+use sui_types::types::CheckpointData;
+use sui_types::types::CheckpointDigest;
 use sui_types::types::CheckpointSequenceNumber;
 use sui_types::types::CheckpointSummary;
+use sui_types::types::CheckpointTransaction;
+use sui_types::types::CheckpointTransactionInfo;
 use sui_types::types::Digest;
+use sui_types::types::EndOfEpochData;
+use sui_types::types::EndOfEpochTransactionKind;
 use sui_types::types::Event;
+use sui_types::types::ExecutionStatus;
 use sui_types::types::MovePackage;
 use sui_types::types::Object;
+use sui_types::types::ObjectData;
+use sui_types::types::ObjectId;
+use sui_types::types::SignedCheckpointSummary;
 use sui_types::types::SignedTransaction;
+use sui_types::types::StructTag;
 use sui_types::types::Transaction;
 use sui_types::types::TransactionDigest;
 use sui_types::types::TransactionEffects;
+use sui_types::types::TransactionEvents;
 use sui_types::types::TransactionKind;
 use sui_types::types::TypeTag;
 use sui_types::types::UserSignature;
+use sui_types::types::ValidatorAggregatedSignature;
+use sui_types::types::ValidatorCommitteeMember;
+use sui_types::types::Version;
 
 use base64ct::Encoding;
 use cynic::serde;
@@ -102,9 +147,12 @@ use cynic::MutationBuilder;
 use cynic::Operation;
 use cynic::QueryBuilder;
 use futures::Stream;
+use futures::TryStreamExt;
 use reqwest::Url;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::error::Kind;
@@ -117,6 +165,12 @@ const TESTNET_HOST: &str = "https://sui-testnet.mystenlabs.com/graphql";
 const DEVNET_HOST: &str = "https://sui-devnet.mystenlabs.com/graphql";
 const LOCAL_HOST: &str = "http://localhost:9125/graphql";
 static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
+/// The address of the `0x2::authenticator_state::AuthenticatorState` system object.
+const AUTHENTICATOR_STATE_ADDRESS: Address = {
+    let mut address = [0u8; Address::LENGTH];
+    address[Address::LENGTH - 1] = 0x7;
+    Address::new(address)
+};
 
 // ===========================================================================
 // Output Types
@@ -153,6 +207,16 @@ pub struct DynamicFieldOutput {
     pub value_as_json: Option<serde_json::Value>,
 }
 
+/// A `0x3::staking_pool::StakedSui` object, along with its estimated rewards.
+#[derive(Clone, Debug)]
+pub struct Stake {
+    /// The staked SUI.
+    pub staked_sui: StakedSui,
+    /// The estimated rewards earned by the stake, in MIST. This is `None` if the stake has not
+    /// been activated yet, or if its validator is no longer active.
+    pub estimated_reward: Option<u64>,
+}
+
 /// Helper struct for passing a value that has a type that implements Serialize, for the dynamic
 /// fields API.
 pub struct NameValue(Vec<u8>);
@@ -273,6 +337,7 @@ pub struct Client {
     inner: reqwest::Client,
 
     service_config: std::sync::OnceLock<ServiceConfig>,
+    mvr: MvrResolver,
 }
 
 impl Client {
@@ -288,6 +353,7 @@ impl Client {
             rpc,
             inner: reqwest::Client::builder().user_agent(USER_AGENT).build()?,
             service_config: Default::default(),
+            mvr: Default::default(),
         };
         Ok(client)
     }
@@ -321,6 +387,18 @@ impl Client {
         Ok(())
     }
 
+    /// Resolve Move Registry names using `overrides` before querying the GraphQL server, and
+    /// clear the names resolved so far.
+    pub fn set_mvr_overrides(&mut self, overrides: MvrOverrides) {
+        self.mvr = MvrResolver::new(overrides);
+    }
+
+    /// Forget the Move Registry names resolved so far, e.g. so that names of upgraded packages
+    /// resolve to their latest version. Overrides are kept.
+    pub fn clear_mvr_cache(&self) {
+        self.mvr.clear();
+    }
+
     /// Return the URL for the GraphQL server.
     fn rpc_server(&self) -> &str {
         self.rpc.as_str()
@@ -343,6 +421,13 @@ impl Client {
         (after, before, first, last)
     }
 
+    /// The number of items fetched per request by the batched queries, the max page size of the
+    /// service.
+    async fn batch_size(&self) -> Result<usize> {
+        let max_page_size = self.max_page_size().await?;
+        Ok(usize::try_from(max_page_size).unwrap_or_default().max(1))
+    }
+
     /// Lazily fetch the max page size
     pub async fn max_page_size(&self) -> Result<i32> {
         self.service_config().await.map(|cfg| cfg.max_page_size)
@@ -481,6 +566,119 @@ impl Client {
         }
     }
 
+    /// Return the `StakedSui` objects owned by `address`, along with their estimated rewards.
+    ///
+    /// The rewards of a stake are estimated from the exchange rates of its staking pool at the
+    /// epoch the stake was activated, read from the pool's exchange rates table, and at the
+    /// current epoch, given by the pool's current balances.
+    pub async fn staked_suis(&self, address: Address) -> Result<Vec<Stake>> {
+        let staked_sui_type = StructTag::staked_sui().to_string();
+        let filter = ObjectFilter {
+            type_: Some(staked_sui_type.as_str()),
+            owner: Some(address),
+            object_ids: None,
+            object_keys: None,
+        };
+        let staked_suis: Vec<StakedSui> = self
+            .objects_stream(Some(filter), Direction::default())
+            .await
+            .try_filter_map(|object| async move { Ok(StakedSui::try_from_object(&object)) })
+            .try_collect()
+            .await?;
+        if staked_suis.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let validators: Vec<Validator> = stream_paginated_query(
+            |filter| self.active_validators(None, filter),
+            Direction::default(),
+        )
+        .try_collect()
+        .await?;
+
+        let response = self.epoch_summary(None).await?;
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+        let current_epoch = response
+            .data
+            .and_then(|d| d.epoch)
+            .ok_or_else(Error::empty_response_error)?
+            .epoch_id;
+
+        // The exchange rates table of each pool is read once, and shared by all of its stakes
+        let mut exchange_rates = HashMap::new();
+        let mut stakes = Vec::with_capacity(staked_suis.len());
+        for staked_sui in staked_suis {
+            let pool_id = Address::from(*staked_sui.pool_id());
+            let epoch = staked_sui.stake_activation_epoch();
+            let validator = validators
+                .iter()
+                .find(|validator| validator.staking_pool_id == pool_id);
+
+            let estimated_reward = match validator {
+                // Pending stakes haven't earned any rewards yet
+                Some(validator) if epoch <= current_epoch => {
+                    if !exchange_rates.contains_key(&pool_id) {
+                        let rates = self.pool_token_exchange_rates(validator).await?;
+                        exchange_rates.insert(pool_id, rates);
+                    }
+                    let activation_rate = pool_token_exchange_rate_at_epoch(
+                        validator,
+                        exchange_rates[&pool_id].as_ref(),
+                        epoch,
+                    );
+                    let current_rate = current_exchange_rate(validator)?;
+                    activation_rate
+                        .zip(current_rate)
+                        .map(|(activation_rate, current_rate)| {
+                            staked_sui.estimated_reward(&activation_rate, &current_rate)
+                        })
+                }
+                _ => None,
+            };
+            stakes.push(Stake {
+                staked_sui,
+                estimated_reward,
+            });
+        }
+        Ok(stakes)
+    }
+
+    /// The exchange rates recorded in the exchange rates table of the staking pool of `validator`,
+    /// keyed by epoch, read by paging through the dynamic fields of the table.
+    async fn pool_token_exchange_rates(
+        &self,
+        validator: &Validator,
+    ) -> Result<Option<BTreeMap<u64, PoolTokenExchangeRate>>> {
+        let Some(table) = &validator.exchange_rates_table else {
+            return Ok(None);
+        };
+
+        self.dynamic_fields_stream(table.address, Direction::default())
+            .await
+            .try_filter_map(|field| async move {
+                let Some((_, value)) = &field.value else {
+                    return Ok(None);
+                };
+                if field.name.type_ != TypeTag::U64 {
+                    return Ok(None);
+                }
+                let epoch = bcs::from_bytes::<u64>(&field.name.bcs)?;
+                let (sui_amount, pool_token_amount) = bcs::from_bytes::<(u64, u64)>(value)?;
+                Ok(Some((
+                    epoch,
+                    PoolTokenExchangeRate {
+                        sui_amount,
+                        pool_token_amount,
+                    },
+                )))
+            })
+            .try_collect()
+            .await
+            .map(Some)
+    }
+
     /// The total number of transaction blocks in the network by the end of the provided
     /// checkpoint digest.
     pub async fn total_transaction_blocks_by_digest(&self, digest: Digest) -> Result<Option<u64>> {
@@ -724,6 +922,298 @@ impl Client {
             .map(|c| c.sequence_number))
     }
 
+    /// Get the full [`CheckpointData`] for the checkpoint with the provided sequence number,
+    /// including every transaction in the checkpoint along with its effects, events, and input and
+    /// output objects. Returns `Ok(None)` if the checkpoint is not known to the server.
+    ///
+    /// This paginates through the checkpoint's transactions, as well as through the events and
+    /// object changes of any transaction that do not fit in a single page, so fetching a large
+    /// checkpoint will trigger a lot of requests.
+    ///
+    /// Note that the GraphQL schema does not expose the BCS of a checkpoint summary, so the summary
+    /// is reconstructed from the fields that are available and the contents fetched from the
+    /// server:
+    ///
+    /// - the end of epoch data of the last checkpoint of an epoch is read from the committee and
+    ///   protocol version of the next epoch, and the live object set digest of the epoch;
+    /// - the version specific data, which records the randomness rounds of the checkpoint since
+    ///   it was introduced, is derived from the checkpoint's randomness state updates.
+    ///
+    /// The digest of the reconstructed summary is checked against the checkpoint digest reported
+    /// by the server and an error is returned if they differ, rather than returning a summary
+    /// which doesn't match the one signed by the validators. The schema doesn't expose which
+    /// validators signed the checkpoint either, and the signers can't be recovered from the
+    /// aggregated signature, so its signers bitmap is empty.
+    pub async fn checkpoint_data(
+        &self,
+        seq_num: CheckpointSequenceNumber,
+    ) -> Result<Option<CheckpointData>> {
+        let checkpoint_id = || CheckpointId {
+            digest: None,
+            sequence_number: Some(seq_num),
+        };
+
+        let operation = CheckpointQuery::build(CheckpointArgs {
+            id: checkpoint_id(),
+        });
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let Some(checkpoint) = response
+            .data
+            .ok_or(Error::empty_response_error())?
+            .checkpoint
+        else {
+            return Ok(None);
+        };
+
+        let signature = base64ct::Base64::decode_vec(&checkpoint.validator_signatures.0)?
+            .try_into()
+            .map(Bls12381Signature::new)
+            .map_err(|_| {
+                Error::from_error(Kind::Parse, "Invalid length for validator signatures")
+            })?;
+        let digest = CheckpointDigest::from_base58(&checkpoint.digest)?;
+        let live_object_set_digest = checkpoint
+            .epoch
+            .as_ref()
+            .and_then(|epoch| epoch.live_object_set_digest.as_deref())
+            .map(Digest::from_base58)
+            .transpose()?;
+        let mut summary: CheckpointSummary = checkpoint.try_into()?;
+
+        let mut transactions = vec![];
+        let mut cursor = None;
+        loop {
+            let after: Option<String> = cursor.take();
+            let operation = CheckpointTransactionsQuery::build(CheckpointTransactionsArgs {
+                id: checkpoint_id(),
+                after: after.as_deref(),
+            });
+            let response = self.run_query(&operation).await?;
+
+            if let Some(errors) = response.errors {
+                return Err(Error::graphql_error(errors));
+            }
+
+            let connection = response
+                .data
+                .and_then(|d| d.checkpoint)
+                .map(|c| c.transaction_blocks)
+                .ok_or(Error::empty_response_error())?;
+
+            for node in connection.nodes {
+                transactions.push(self.checkpoint_transaction(node).await?);
+            }
+
+            match connection.page_info.end_cursor {
+                Some(end_cursor) if connection.page_info.has_next_page => cursor = Some(end_cursor),
+                _ => break,
+            }
+        }
+
+        let checkpoint_contents = CheckpointContents::new(
+            transactions
+                .iter()
+                .map(|tx| CheckpointTransactionInfo {
+                    transaction: tx.transaction.transaction.digest(),
+                    effects: tx.effects.digest(),
+                    signatures: tx.transaction.signatures.clone(),
+                })
+                .collect(),
+        );
+        // The summary converted from the GraphQL checkpoint carries the checkpoint's own digest in
+        // place of the contents digest, which can be computed now that the contents are known.
+        summary.content_digest = checkpoint_contents.digest();
+        if transactions
+            .last()
+            .is_some_and(|tx| is_change_epoch(&tx.transaction.transaction.kind))
+        {
+            summary.end_of_epoch_data = Some(self.end_of_epoch_data(summary.epoch).await?);
+        }
+        let summary = checkpoint_summary_candidates(summary, &transactions, live_object_set_digest)?
+            .into_iter()
+            .find(|summary| summary.digest() == digest)
+            .ok_or_else(|| {
+                Error::from_error(
+                    Kind::Other,
+                    format!(
+                        "Reconstructed summary of checkpoint {seq_num} does not match its digest {digest}"
+                    ),
+                )
+            })?;
+
+        Ok(Some(CheckpointData {
+            checkpoint_summary: SignedCheckpointSummary {
+                signature: ValidatorAggregatedSignature {
+                    epoch: summary.epoch,
+                    signature,
+                    bitmap: Default::default(),
+                },
+                checkpoint: summary,
+            },
+            checkpoint_contents,
+            transactions,
+        }))
+    }
+
+    /// The end of epoch data recorded in the last checkpoint of `epoch`: the committee and protocol
+    /// version of the next epoch. The epoch commitments are left empty.
+    async fn end_of_epoch_data(&self, epoch: u64) -> Result<EndOfEpochData> {
+        let next_epoch = epoch + 1;
+        let validators: Vec<Validator> = stream_paginated_query(
+            |filter| self.active_validators(Some(next_epoch), filter),
+            Direction::default(),
+        )
+        .try_collect()
+        .await?;
+        let mut next_epoch_committee = validators
+            .into_iter()
+            .map(|validator| {
+                let public_key = validator
+                    .credentials
+                    .and_then(|credentials| credentials.protocol_pub_key)
+                    .ok_or_else(|| {
+                        Error::from_error(Kind::Other, "Validator protocol public key is missing")
+                    })?;
+                let public_key =
+                    Bls12381PublicKey::from_bytes(base64ct::Base64::decode_vec(&public_key.0)?)
+                        .map_err(|e| Error::from_error(Kind::Parse, e))?;
+                let stake = validator
+                    .voting_power
+                    .ok_or_else(|| {
+                        Error::from_error(Kind::Other, "Validator voting power is missing")
+                    })?
+                    .try_into()?;
+                Ok(ValidatorCommitteeMember { public_key, stake })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Committees are sorted by the public keys of their members
+        next_epoch_committee.sort_by(|a, b| a.public_key.cmp(&b.public_key));
+
+        let operation = EpochProtocolVersionQuery::build(EpochSummaryArgs {
+            id: Some(next_epoch),
+        });
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let next_epoch_protocol_version = response
+            .data
+            .and_then(|d| d.epoch)
+            .ok_or_else(Error::empty_response_error)?
+            .protocol_configs
+            .protocol_version;
+
+        Ok(EndOfEpochData {
+            next_epoch_committee,
+            next_epoch_protocol_version,
+            epoch_commitments: vec![],
+        })
+    }
+
+    /// Assemble a [`CheckpointTransaction`] from a transaction returned as part of a checkpoint,
+    /// fetching any of its events and object changes that did not fit in the first page.
+    async fn checkpoint_transaction(
+        &self,
+        node: CheckpointTransactionBlock,
+    ) -> Result<CheckpointTransaction> {
+        let transaction: SignedTransaction = query_types::TransactionBlock {
+            bcs: node.bcs,
+            signatures: node.signatures,
+        }
+        .try_into()?;
+        let fx = node.effects.ok_or_else(|| {
+            Error::from_error(Kind::Other, "Transaction effects in checkpoint are missing")
+        })?;
+        let effects =
+            bcs::from_bytes::<TransactionEffects>(&base64ct::Base64::decode_vec(&fx.bcs.0)?)?;
+        let digest = transaction.transaction.digest().to_string();
+
+        let mut events = vec![];
+        let mut connection = fx.events;
+        loop {
+            for event in connection.nodes {
+                events.push(bcs::from_bytes::<Event>(&base64ct::Base64::decode_vec(
+                    &event.bcs.0,
+                )?)?);
+            }
+
+            let after = match connection.page_info.end_cursor {
+                Some(end_cursor) if connection.page_info.has_next_page => end_cursor,
+                _ => break,
+            };
+            let operation = TransactionEventsQuery::build(TransactionEffectsPageArgs {
+                digest: digest.clone(),
+                after: Some(&after),
+            });
+            let response = self.run_query(&operation).await?;
+
+            if let Some(errors) = response.errors {
+                return Err(Error::graphql_error(errors));
+            }
+
+            connection = response
+                .data
+                .and_then(|d| d.transaction_block)
+                .and_then(|tx| tx.effects)
+                .map(|fx| fx.events)
+                .ok_or(Error::empty_response_error())?;
+        }
+
+        let mut input_objects = vec![];
+        let mut output_objects = vec![];
+        let mut connection = fx.object_changes;
+        loop {
+            for change in connection.nodes {
+                if let Some(bcs) = change.input_state.and_then(|o| o.bcs) {
+                    input_objects.push(bcs::from_bytes::<Object>(&base64ct::Base64::decode_vec(
+                        &bcs.0,
+                    )?)?);
+                }
+                if let Some(bcs) = change.output_state.and_then(|o| o.bcs) {
+                    output_objects.push(bcs::from_bytes::<Object>(&base64ct::Base64::decode_vec(
+                        &bcs.0,
+                    )?)?);
+                }
+            }
+
+            let after = match connection.page_info.end_cursor {
+                Some(end_cursor) if connection.page_info.has_next_page => end_cursor,
+                _ => break,
+            };
+            let operation = TransactionObjectChangesQuery::build(TransactionEffectsPageArgs {
+                digest: digest.clone(),
+                after: Some(&after),
+            });
+            let response = self.run_query(&operation).await?;
+
+            if let Some(errors) = response.errors {
+                return Err(Error::graphql_error(errors));
+            }
+
+            connection = response
+                .data
+                .and_then(|d| d.transaction_block)
+                .and_then(|tx| tx.effects)
+                .map(|fx| fx.object_changes)
+                .ok_or(Error::empty_response_error())?;
+        }
+
+        Ok(CheckpointTransaction {
+            transaction,
+            effects,
+            events: (!events.is_empty()).then_some(TransactionEvents(events)),
+            input_objects,
+            output_objects,
+        })
+    }
+
     // ===========================================================================
     // Dynamic Field(s) API
     // ===========================================================================
@@ -817,6 +1307,77 @@ impl Client {
         Ok(result)
     }
 
+    /// Fetch the dynamic field named `name`, a value of the Move type `name_type`, on the object
+    /// at `address`, decoding its value, of the Move type `value_type`, into a `V`.
+    ///
+    /// The id of the field object is derived locally from `address` and `name`, and the field is
+    /// decoded from the object's BCS contents, so `name` and `V` must have the same layout as
+    /// the Move values they represent. A field whose value is not of type `value_type` is
+    /// reported as an error. This can be used to look up the entries of a `0x2::table::Table` or
+    /// a `0x2::bag::Bag` by using the table or bag's id as `address`.
+    ///
+    /// This returns `Ok(None)` if there is no such dynamic field.
+    pub async fn typed_dynamic_field<K, V>(
+        &self,
+        address: Address,
+        name_type: &TypeTag,
+        name: &K,
+        value_type: &TypeTag,
+    ) -> Result<Option<Field<K, V>>>
+    where
+        K: Serialize + DeserializeOwned,
+        V: DeserializeOwned,
+    {
+        let id = ObjectId::from(address).derive_dynamic_field_id(name_type, name)?;
+        let Some(object) = self.object(id.into(), None).await? else {
+            return Ok(None);
+        };
+
+        Field::try_from_object(&object, name_type, value_type)
+            .map(Some)
+            .ok_or_else(|| {
+                Error::from_error(
+                    Kind::Deserialization,
+                    format!("object {id} is not a dynamic field of the expected type"),
+                )
+            })
+    }
+
+    /// Fetch the object stored in the dynamic object field named `name`, a value of the Move type
+    /// `name_type`, on the object at `address`.
+    ///
+    /// The id of the field object is derived locally from `address` and `name`, see
+    /// [`Client::typed_dynamic_field`].
+    ///
+    /// This returns `Ok(None)` if there is no such dynamic object field.
+    pub async fn typed_dynamic_object_field<K>(
+        &self,
+        address: Address,
+        name_type: &TypeTag,
+        name: &K,
+    ) -> Result<Option<Object>>
+    where
+        K: Serialize + DeserializeOwned,
+    {
+        let id = ObjectId::from(address).derive_dynamic_object_field_id(name_type, name)?;
+        let Some(object) = self.object(id.into(), None).await? else {
+            return Ok(None);
+        };
+
+        let wrapper_type = TypeTag::Struct(Box::new(StructTag::dynamic_object_field_wrapper(
+            name_type.clone(),
+        )));
+        let id_type = TypeTag::Struct(Box::new(StructTag::object_id()));
+        let field = Field::<K, ObjectId>::try_from_object(&object, &wrapper_type, &id_type)
+            .ok_or_else(|| {
+                Error::from_error(
+                    Kind::Deserialization,
+                    format!("object {id} is not a dynamic object field of the expected type"),
+                )
+            })?;
+        self.object(field.value.into(), None).await
+    }
+
     /// Get a page of dynamic fields for the provided address. Note that this will also fetch
     /// dynamic fields on wrapped objects.
     ///
@@ -867,6 +1428,23 @@ impl Client {
         )
     }
 
+    /// Return the rules of the `0x2::transfer_policy::TransferPolicy` at `policy`, read from its
+    /// dynamic fields, for resolving purchases with [`KioskTransaction::purchase`].
+    ///
+    /// [`KioskTransaction::purchase`]: sui_types::types::kiosk::KioskTransaction::purchase
+    pub async fn transfer_policy_rules(&self, policy: Address) -> Result<Vec<Rule>> {
+        self.dynamic_fields_stream(policy, Direction::default())
+            .await
+            .try_filter_map(|field| async move {
+                Ok(field
+                    .value
+                    .as_ref()
+                    .and_then(|(_, value)| Rule::from_dynamic_field(&field.name.type_, value)))
+            })
+            .try_collect()
+            .await
+    }
+
     // ===========================================================================
     // Epoch API
     // ===========================================================================
@@ -1073,6 +1651,58 @@ impl Client {
         )
     }
 
+    /// Return the objects with the provided ids, at the provided versions or at their latest
+    /// versions if `None`.
+    ///
+    /// The objects are fetched in batches of the service's max page size, and are returned in the
+    /// order of `objects`. If an object does not exist (e.g., due to pruning), its entry is `None`.
+    pub async fn multi_get_objects(
+        &self,
+        objects: &[(ObjectId, Option<Version>)],
+    ) -> Result<Vec<Option<Object>>> {
+        let batch_size = self.batch_size().await?;
+        let (versioned, latest): (Vec<_>, Vec<_>) =
+            objects.iter().partition(|(_, version)| version.is_some());
+        let mut found = HashMap::new();
+
+        for batch in latest.chunks(batch_size) {
+            let filter = ObjectFilter {
+                type_: None,
+                owner: None,
+                object_ids: Some(batch.iter().map(|(id, _)| (*id).into()).collect()),
+                object_keys: None,
+            };
+            let page = self.objects(Some(filter), batch_pagination(batch)).await?;
+            for object in page.into_parts().1 {
+                found.insert((object.object_id(), None), object);
+            }
+        }
+
+        for batch in versioned.chunks(batch_size) {
+            let object_keys = batch
+                .iter()
+                .filter_map(|(id, version)| {
+                    version.map(|version| ObjectKey {
+                        object_id: (*id).into(),
+                        version,
+                    })
+                })
+                .collect();
+            let filter = ObjectFilter {
+                type_: None,
+                owner: None,
+                object_ids: None,
+                object_keys: Some(object_keys),
+            };
+            let page = self.objects(Some(filter), batch_pagination(batch)).await?;
+            for object in page.into_parts().1 {
+                found.insert((object.object_id(), Some(object.version())), object);
+            }
+        }
+
+        Ok(objects.iter().map(|key| found.get(key).cloned()).collect())
+    }
+
     /// Return the object's bcs content [`Vec<u8>`] based on the provided [`Address`].
     pub async fn object_bcs(&self, object_id: Address) -> Result<Option<Vec<u8>>> {
         let operation = ObjectQuery::build(ObjectQueryArgs {
@@ -1268,13 +1898,19 @@ impl Client {
     }
 
     /// Fetch a package by its name (using Move Registry Service)
+    ///
+    /// If the name was already resolved by [`Client::resolve_mvr_package`], or is overridden
+    /// (see [`Client::set_mvr_overrides`]), the package is fetched by its address instead.
     pub async fn package_by_name(&self, name: &str) -> Result<Option<MovePackage>> {
+        if let Some(address) = self.mvr.package(name) {
+            return self.package(address, None).await;
+        }
+
         let operation = PackageByNameQuery::build(PackageByNameArgs { name });
 
         let response = self.run_query(&operation).await?;
 
         if let Some(errors) = response.errors {
-            println!("{:?}", errors);
             return Err(Error::graphql_error(errors));
         }
 
@@ -1286,6 +1922,83 @@ impl Client {
             .and_then(|bcs| bcs::from_bytes::<MovePackage>(&bcs).ok()))
     }
 
+    /// Resolve the Move Registry name of a package, e.g. `@mysten/kiosk`, to the address of its
+    /// latest version.
+    ///
+    /// Resolved names are cached, with or without their leading `@`, until
+    /// [`Client::clear_mvr_cache`] is called.
+    pub async fn resolve_mvr_package(&self, name: &str) -> Result<Option<Address>> {
+        if let Some(address) = self.mvr.package(name) {
+            return Ok(Some(address));
+        }
+
+        let operation = MvrPackageQuery::build(MvrNameArgs { name });
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let address = response
+            .data
+            .and_then(|x| x.package_by_name)
+            .map(|package| package.address);
+        if let Some(address) = address {
+            self.mvr.insert_package(name, address);
+        }
+        Ok(address)
+    }
+
+    /// Resolve a type referring to packages by their Move Registry name, e.g.
+    /// `@mysten/kiosk::kiosk::Kiosk`, to a [`StructTag`].
+    ///
+    /// Unlike [`Client::resolve_mvr_package`], the addresses in the resolved type are those of
+    /// the packages which first defined each type. Types within packages overridden with
+    /// [`MvrOverrides::with_package`] resolve to the overridden address instead. Resolved types
+    /// are cached until [`Client::clear_mvr_cache`] is called.
+    ///
+    /// See [`Client::resolve_mvr_type_tag`] to resolve types which may not be structs, such as
+    /// `vector<@mysten/kiosk::kiosk::Kiosk>`.
+    pub async fn resolve_mvr_type(&self, name: &str) -> Result<StructTag> {
+        if let Some(type_) = self.mvr.type_(name) {
+            return Ok(type_);
+        }
+
+        let operation = MvrTypeQuery::build(MvrNameArgs { name });
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let type_: StructTag = response
+            .data
+            .ok_or_else(Error::empty_response_error)?
+            .type_by_name
+            .repr
+            .parse()?;
+        self.mvr.insert_type(name, type_.clone());
+        Ok(type_)
+    }
+
+    /// Resolve a type which may refer to packages by their Move Registry name to a [`TypeTag`],
+    /// e.g. `vector<@mysten/kiosk::kiosk::Kiosk>` or `u64`.
+    ///
+    /// Struct types are resolved by [`Client::resolve_mvr_type`], and types which don't refer to
+    /// any Move Registry name are parsed without querying the GraphQL service.
+    pub async fn resolve_mvr_type_tag(&self, name: &str) -> Result<TypeTag> {
+        let (depth, element) = mvr::strip_vectors(name);
+        let mut type_tag = if element.contains('@') {
+            TypeTag::Struct(Box::new(self.resolve_mvr_type(element).await?))
+        } else {
+            element.parse()?
+        };
+        for _ in 0..depth {
+            type_tag = TypeTag::Vector(Box::new(type_tag));
+        }
+        Ok(type_tag)
+    }
+
     /// The Move packages that exist in the network, optionally filtered to be strictly before
     /// beforeCheckpoint and/or strictly after afterCheckpoint.
     ///
@@ -1382,6 +2095,59 @@ impl Client {
         self.dry_run(tx_bytes, skip_checks, Some(tx_meta)).await
     }
 
+    /// Estimate the gas price and gas budget of a [`Transaction`] by dry running it.
+    ///
+    /// The gas price is chosen by `estimator` relative to the reference gas price of the current
+    /// epoch, and the transaction is dry run at that price. If the budget of `tx` is zero, the dry
+    /// run uses the maximal budget of the protocol config instead, which the gas payment of `tx`
+    /// must be able to cover. The returned [`GasEstimate`] details how the gas price and budget
+    /// were chosen.
+    ///
+    /// This fails if the dry run fails, or if the transaction fails during the dry run.
+    pub async fn estimate_gas(
+        &self,
+        tx: &Transaction,
+        estimator: &GasEstimator,
+    ) -> Result<GasEstimate> {
+        let reference_gas_price = self
+            .reference_gas_price(None)
+            .await?
+            .ok_or_else(Error::empty_response_error)?;
+        let config = self.protocol_config(None).await?;
+
+        let mut tx = tx.clone();
+        tx.gas_payment.price = estimator.gas_price(reference_gas_price, config.as_ref());
+        if tx.gas_payment.budget == 0 {
+            if let Some(max_budget) = config
+                .as_ref()
+                .and_then(|config| config.config("max_tx_gas"))
+            {
+                tx.gas_payment.budget = max_budget.parse()?;
+            }
+        }
+
+        let DryRunResult { effects, error } = self.dry_run_tx(&tx, None).await?;
+        if let Some(error) = error {
+            return Err(Error::from_error(
+                Kind::Other,
+                format!("Dry run failed: {error}"),
+            ));
+        }
+        let effects = effects.ok_or_else(Error::empty_response_error)?;
+        if let ExecutionStatus::Failure { error, command } = effects.status() {
+            return Err(Error::from_error(
+                Kind::Other,
+                format!("Transaction failed during dry run in command {command:?}: {error:?}"),
+            ));
+        }
+
+        Ok(estimator.estimate(
+            reference_gas_price,
+            effects.gas_summary().clone(),
+            config.as_ref(),
+        ))
+    }
+
     /// Internal implementation of the dry run API.
     async fn dry_run(
         &self,
@@ -1557,6 +2323,45 @@ impl Client {
         )
     }
 
+    /// Get the transactions with the provided digests.
+    ///
+    /// The transactions are fetched in batches of the service's max page size, and are returned
+    /// in the order of `digests`. If a transaction does not exist, its entry is `None`.
+    pub async fn multi_get_transactions(
+        &self,
+        digests: &[Digest],
+    ) -> Result<Vec<Option<SignedTransaction>>> {
+        let batch_size = self.batch_size().await?;
+        let mut found = HashMap::new();
+
+        for batch in digests.chunks(batch_size) {
+            let transaction_ids: Vec<_> = batch.iter().map(ToString::to_string).collect();
+            let filter = TransactionsFilter {
+                function: None,
+                kind: None,
+                after_checkpoint: None,
+                at_checkpoint: None,
+                before_checkpoint: None,
+                affected_address: None,
+                sent_address: None,
+                input_object: None,
+                changed_object: None,
+                transaction_ids: Some(transaction_ids.iter().map(String::as_str).collect()),
+            };
+            let page = self
+                .transactions(Some(filter), batch_pagination(batch))
+                .await?;
+            for transaction in page.into_parts().1 {
+                found.insert(Digest::from(transaction.transaction.digest()), transaction);
+            }
+        }
+
+        Ok(digests
+            .iter()
+            .map(|digest| found.get(digest).cloned())
+            .collect())
+    }
+
     /// Execute a transaction.
     pub async fn execute_tx(
         &self,
@@ -1667,6 +2472,49 @@ impl Client {
         Ok(response.data.and_then(|p| p.package).and_then(|p| p.module))
     }
 
+    // ===========================================================================
+    // zkLogin API
+    // ===========================================================================
+
+    /// Return the JWKs that are currently active on-chain, as stored in the
+    /// `0x2::authenticator_state::AuthenticatorState` object.
+    ///
+    /// Each [`ActiveJwk`] carries the most recent epoch in which it was validated, which can be
+    /// used to keep a zklogin verifier's set of JWKs up to date and to expire stale entries.
+    pub async fn active_jwks(&self) -> Result<Vec<ActiveJwk>> {
+        #[derive(serde::Deserialize)]
+        struct AuthenticatorState {
+            _id: Address,
+            version: u64,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct AuthenticatorStateInner {
+            _version: u64,
+            active_jwks: Vec<ActiveJwk>,
+        }
+
+        let Some(bcs) = self
+            .move_object_contents_bcs(AUTHENTICATOR_STATE_ADDRESS, None)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+        let state: AuthenticatorState = bcs::from_bytes(&bcs)?;
+
+        // The inner state is stored as a dynamic field keyed by its version
+        let Some(inner) = self
+            .dynamic_field(AUTHENTICATOR_STATE_ADDRESS, TypeTag::U64, state.version)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+        let inner_type = TypeTag::from_str("0x2::authenticator_state::AuthenticatorStateInner")?;
+        let inner: AuthenticatorStateInner = inner.deserialize_value(&inner_type)?;
+
+        Ok(inner.active_jwks)
+    }
+
     // ===========================================================================
     // SuiNS
     // ===========================================================================
@@ -1700,17 +2548,374 @@ impl Client {
             .and_then(|d| d.address)
             .and_then(|a| a.default_suins_name))
     }
+
+    /// Get the addresses the provided Suins domain names, or subnames, resolve to, batching as
+    /// many names per request as the limits of the service allow.
+    ///
+    /// The addresses are returned in the order of `domains`.
+    pub async fn resolve_suins_to_addresses(
+        &self,
+        domains: &[&str],
+    ) -> Result<Vec<Option<Address>>> {
+        let results = self
+            .run_batch_query::<_, DomainAddress>("String!", domains, 2, |variable| {
+                format!("resolveSuinsAddress(domain: ${variable}) {{ address }}")
+            })
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.map(|domain| domain.address))
+            .collect())
+    }
+
+    /// Get the default Suins domain names of the provided addresses, batching as many addresses
+    /// per request as the limits of the service allow.
+    ///
+    /// The names are returned in the order of `addresses`.
+    pub async fn default_suins_names(&self, addresses: &[Address]) -> Result<Vec<Option<String>>> {
+        let results = self
+            .run_batch_query::<_, AddressDefaultSuins>("SuiAddress!", addresses, 2, |variable| {
+                format!("address(address: ${variable}) {{ defaultSuinsName }}")
+            })
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.and_then(|address| address.default_suins_name))
+            .collect())
+    }
+
+    /// Get the record of the Suins name, or subname, `name` from the registry of the SuiNS
+    /// shared object `suins`, holding its target address, expiration and user data such as its
+    /// avatar.
+    ///
+    /// This returns `Ok(None)` if the name is not registered.
+    pub async fn suins_name_record(
+        &self,
+        suins: ObjectId,
+        name: &SuinsName,
+    ) -> Result<Option<NameRecord>> {
+        let (package, registry) = self.suins_registry(suins).await?;
+        let domain_type = TypeTag::from_str(&format!("{package}::domain::Domain"))?;
+        let labels = name.domain_labels();
+        let id = registry.derive_dynamic_field_id(&domain_type, &labels)?;
+        let Some(object) = self.object(id.into(), None).await? else {
+            return Ok(None);
+        };
+
+        suins_name_record(&object, &labels).map(Some)
+    }
+
+    /// Get the package defining the SuiNS types and the id of the `Table<Domain, NameRecord>` of
+    /// the registry of the SuiNS shared object `suins`.
+    async fn suins_registry(&self, suins: ObjectId) -> Result<(Address, ObjectId)> {
+        let object = self.object(suins.into(), None).await?.ok_or_else(|| {
+            Error::from_error(Kind::Other, format!("SuiNS object {suins} not found"))
+        })?;
+        let (package, id) = suins_registry_field(&object)?;
+        let field = self.object(id.into(), None).await?.ok_or_else(|| {
+            Error::from_error(Kind::Other, format!("SuiNS registry {id} not found"))
+        })?;
+
+        Ok((package, suins_registry_table(&field)?))
+    }
+
+    /// Run a query made of one field per value of a batch, where `field` builds the field of a
+    /// value given the name of the variable, of type `variable_type`, holding it, and selects a
+    /// `T` with `nodes` nodes. This returns the result of each field in the order of `values`.
+    ///
+    /// The fields are split into as many queries as needed for each to stay within the
+    /// `maxQueryNodes` and `maxQueryPayloadSize` limits of the service.
+    async fn run_batch_query<V, T>(
+        &self,
+        variable_type: &str,
+        values: &[V],
+        nodes: usize,
+        field: impl Fn(&str) -> String,
+    ) -> Result<Vec<Option<T>>>
+    where
+        V: Serialize,
+        T: DeserializeOwned,
+    {
+        if values.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let config = self.service_config().await?;
+        let max_nodes = usize::try_from(config.max_query_nodes).unwrap_or_default();
+        let max_payload_size = usize::try_from(config.max_query_payload_size).unwrap_or_default();
+
+        let mut results = Vec::with_capacity(values.len());
+        let mut remaining = values;
+        while !remaining.is_empty() {
+            // Always include at least one field, leaving it to the service to reject it if it
+            // doesn't fit on its own
+            let mut len = 0;
+            let mut payload_size = "{\"query\":\"query() {  }\",\"variables\":{}}".len();
+            for (i, value) in remaining.iter().enumerate() {
+                let alias = format!("b{i}");
+                let value =
+                    serde_json::to_string(value).map_err(|e| Error::from_error(Kind::Other, e))?;
+                let field_size = format!("${alias}: {variable_type}, ").len()
+                    + format!("{alias}: {} ", field(&alias)).len()
+                    + format!("\"{alias}\":{value},").len();
+                if len > 0
+                    && ((len + 1) * nodes > max_nodes
+                        || payload_size + field_size > max_payload_size)
+                {
+                    break;
+                }
+                len += 1;
+                payload_size += field_size;
+            }
+
+            let (batch, rest) = remaining.split_at(len);
+            remaining = rest;
+            results.extend(self.run_batch(variable_type, batch, &field).await?);
+        }
+        Ok(results)
+    }
+
+    /// Run a single query made of one field per value of `batch`, see
+    /// [`Client::run_batch_query`].
+    async fn run_batch<V, T>(
+        &self,
+        variable_type: &str,
+        batch: &[V],
+        field: impl Fn(&str) -> String,
+    ) -> Result<Vec<Option<T>>>
+    where
+        V: Serialize,
+        T: DeserializeOwned,
+    {
+        let aliases: Vec<_> = (0..batch.len()).map(|i| format!("b{i}")).collect();
+        let declarations = aliases
+            .iter()
+            .map(|alias| format!("${alias}: {variable_type}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let fields = aliases
+            .iter()
+            .map(|alias| format!("{alias}: {}", field(alias)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let variables: HashMap<_, _> = aliases.iter().zip(batch).collect();
+
+        let operation = Operation::<HashMap<String, Option<T>>, _>::new(
+            format!("query({declarations}) {{ {fields} }}"),
+            variables,
+        );
+        let response = self.run_query(&operation).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(Error::graphql_error(errors));
+        }
+
+        let mut data = response.data.ok_or_else(Error::empty_response_error)?;
+        Ok(aliases
+            .iter()
+            .map(|alias| data.remove(alias).flatten())
+            .collect())
+    }
+}
+
+/// The exchange rate of the staking pool of `validator` at `epoch` given its recorded `rates`, as
+/// computed by `0x3::staking_pool::pool_token_exchange_rate_at_epoch`: the rate recorded at the
+/// latest epoch no later than `epoch`, or the initial 1:1 rate if none was recorded since the pool
+/// was activated.
+fn pool_token_exchange_rate_at_epoch(
+    validator: &Validator,
+    rates: Option<&BTreeMap<u64, PoolTokenExchangeRate>>,
+    epoch: u64,
+) -> Option<PoolTokenExchangeRate> {
+    let (Some(rates), Some(activation_epoch)) = (rates, validator.staking_pool_activation_epoch)
+    else {
+        return None;
+    };
+
+    Some(
+        rates
+            .range(..=epoch)
+            .next_back()
+            .filter(|(recorded_at, _)| **recorded_at >= activation_epoch)
+            .map(|(_, rate)| *rate)
+            .unwrap_or(PoolTokenExchangeRate {
+                sui_amount: 0,
+                pool_token_amount: 0,
+            }),
+    )
+}
+
+/// Whether a transaction of kind `kind` ends its epoch.
+fn is_change_epoch(kind: &TransactionKind) -> bool {
+    match kind {
+        TransactionKind::ChangeEpoch(_) => true,
+        TransactionKind::EndOfEpoch(transactions) => transactions
+            .iter()
+            .any(|tx| matches!(tx, EndOfEpochTransactionKind::ChangeEpoch(_))),
+        _ => false,
+    }
+}
+
+/// The possible summaries of a checkpoint, given `summary` reconstructed from the fields exposed
+/// by the GraphQL schema, and the checkpoint's transactions.
+///
+/// Whether a summary records commitments and version specific data depends on the protocol
+/// version it was produced with, which isn't known, so a candidate is returned for each option:
+///
+/// - the epoch commitments of an end of epoch checkpoint are either empty or the live object set
+///   digest of the epoch, `live_object_set_digest`;
+/// - the version specific data is either empty or a `CheckpointVersionSpecificData::V1`, holding
+///   the rounds of the randomness state updates in the checkpoint.
+fn checkpoint_summary_candidates(
+    summary: CheckpointSummary,
+    transactions: &[CheckpointTransaction],
+    live_object_set_digest: Option<Digest>,
+) -> Result<Vec<CheckpointSummary>> {
+    let randomness_rounds = transactions
+        .iter()
+        .filter_map(|tx| match &tx.transaction.transaction.kind {
+            TransactionKind::RandomnessStateUpdate(update) => Some(update.randomness_round),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // BCS encoding of the `V1` variant followed by its rounds
+    let mut version_specific_data_v1 = vec![0];
+    version_specific_data_v1.extend(bcs::to_bytes(&randomness_rounds)?);
+
+    let epoch_commitments = [
+        vec![],
+        live_object_set_digest
+            .map(|digest| vec![CheckpointCommitment::EcmhLiveObjectSet { digest }])
+            .unwrap_or_default(),
+    ];
+
+    let mut candidates = vec![];
+    for version_specific_data in [vec![], version_specific_data_v1] {
+        for commitments in &epoch_commitments {
+            let mut candidate = summary.clone();
+            candidate.version_specific_data = version_specific_data.clone();
+            if let Some(end_of_epoch_data) = &mut candidate.end_of_epoch_data {
+                end_of_epoch_data.epoch_commitments = commitments.clone();
+            }
+            candidates.push(candidate);
+        }
+    }
+    Ok(candidates)
+}
+
+/// The current exchange rate of the staking pool of `validator`, given by its balances.
+fn current_exchange_rate(validator: &Validator) -> Result<Option<PoolTokenExchangeRate>> {
+    let (Some(sui_balance), Some(pool_token_balance)) = (
+        &validator.staking_pool_sui_balance,
+        &validator.pool_token_balance,
+    ) else {
+        return Ok(None);
+    };
+    Ok(Some(PoolTokenExchangeRate {
+        sui_amount: sui_balance.0.parse()?,
+        pool_token_amount: pool_token_balance.0.parse()?,
+    }))
+}
+
+/// The pagination filter fetching all of the items of `batch` in a single page.
+fn batch_pagination<T>(batch: &[T]) -> PaginationFilter {
+    PaginationFilter {
+        // Batches are at most the max page size, which is an `i32`
+        limit: Some(batch.len() as i32),
+        ..Default::default()
+    }
+}
+
+/// The package defining the SuiNS types and the id of the dynamic field holding the registry of
+/// the SuiNS object `suins`.
+fn suins_registry_field(suins: &Object) -> Result<(Address, ObjectId)> {
+    let ObjectData::Struct(suins_struct) = suins.data() else {
+        return Err(Error::from_error(
+            Kind::Deserialization,
+            format!("object {} is not a SuiNS object", suins.object_id()),
+        ));
+    };
+    let package = suins_struct.object_type().address;
+
+    // `SuiNS { id: UID, balance: Balance<SUI> }` holds its registry in a dynamic field under a
+    // `RegistryKey<Registry>`, which has no fields and so is serialized as its `dummy_field: bool`
+    let key_type = TypeTag::from_str(&format!(
+        "{package}::suins::RegistryKey<{package}::registry::Registry>"
+    ))?;
+    let id = suins_struct
+        .object_id()
+        .derive_dynamic_field_id(&key_type, &false)?;
+
+    Ok((package, id))
+}
+
+/// The id of the `Table<Domain, NameRecord>` of the SuiNS registry held in the dynamic field
+/// `field`.
+fn suins_registry_table(field: &Object) -> Result<ObjectId> {
+    // `Field<RegistryKey, Registry> { id: UID, name: RegistryKey, value: Registry }`, where the
+    // registry starts with its `Table<Domain, NameRecord>`, itself starting with its id
+    let start = ObjectId::LENGTH + 1;
+    move_object_contents(field)
+        .and_then(|contents| contents.get(start..start + ObjectId::LENGTH))
+        .map(|id| ObjectId::new(id.try_into().unwrap()))
+        .ok_or_else(|| {
+            Error::from_error(
+                Kind::Deserialization,
+                format!("object {} is not a SuiNS registry", field.object_id()),
+            )
+        })
+}
+
+/// The record held in the dynamic field `field` of the SuiNS registry, stored under the domain
+/// `labels`.
+fn suins_name_record(field: &Object, labels: &[String]) -> Result<NameRecord> {
+    // `Field<Domain, NameRecord> { id: UID, name: Domain, value: NameRecord }`
+    let name_len = bcs::to_bytes(labels)?.len();
+    move_object_contents(field)
+        .and_then(|contents| contents.get(ObjectId::LENGTH + name_len..))
+        .and_then(NameRecord::from_bcs)
+        .ok_or_else(|| {
+            Error::from_error(
+                Kind::Deserialization,
+                format!("object {} is not a SuiNS name record", field.object_id()),
+            )
+        })
+}
+
+/// The contents of a Move object, or `None` if `object` is a package.
+fn move_object_contents(object: &Object) -> Option<&[u8]> {
+    match object.data() {
+        ObjectData::Struct(move_struct) => Some(move_struct.contents()),
+        ObjectData::Package(_) => None,
+    }
 }
 
 // This function is used in tests to create a new client instance for the local server.
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use base64ct::Encoding;
     use futures::StreamExt;
+    use sui_types::types::suins::NameRecord;
+    use sui_types::types::suins::SuinsName;
+    use sui_types::types::Address;
+    use sui_types::types::Digest;
     use sui_types::types::Ed25519PublicKey;
+    use sui_types::types::MoveStruct;
+    use sui_types::types::Object;
+    use sui_types::types::ObjectData;
+    use sui_types::types::ObjectId;
+    use sui_types::types::Owner;
+    use sui_types::types::TransactionDigest;
     use sui_types::types::TypeTag;
 
     use crate::faucet::FaucetClient;
+    use crate::suins_name_record;
+    use crate::suins_registry_field;
+    use crate::suins_registry_table;
     use crate::BcsName;
     use crate::Client;
     use crate::Direction;
@@ -1748,6 +2953,101 @@ mod tests {
         assert!(client.set_rpc_server("9125/graphql").is_err());
     }
 
+    /// A Move object of type `type_` holding `contents`.
+    fn move_object(type_: &str, contents: Vec<u8>) -> Object {
+        let move_struct = MoveStruct::new(type_.parse().unwrap(), false, 1, contents).unwrap();
+        Object::new(
+            ObjectData::Struct(move_struct),
+            Owner::Shared(1),
+            TransactionDigest::ZERO,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_suins_objects() {
+        // The layout of the mainnet SuiNS object and of the fields of its registry
+        let package = "0xd22b24490e0bae52676651b4f56660a5ff8022a2576e0089f79b3c88d44e08f0";
+        let suins_id: ObjectId =
+            "0x6e0ddefc0ad98889c04bab9639e512c21766c5e6366f89e696956d9be6952871"
+                .parse()
+                .unwrap();
+        let table_id: ObjectId =
+            "0xe64cd9db9f829c6cc405d9790bd71567ae07259855f4fba6f02c84f52298c106"
+                .parse()
+                .unwrap();
+
+        // `SuiNS { id: UID, balance: Balance<SUI> }`
+        let suins = move_object(
+            &format!("{package}::suins::SuiNS"),
+            bcs::to_bytes(&(suins_id, 1_000_000_000u64)).unwrap(),
+        );
+        let (registry_package, registry_field_id) = suins_registry_field(&suins).unwrap();
+        assert_eq!(registry_package, package.parse::<Address>().unwrap());
+        assert_eq!(
+            registry_field_id,
+            "0x0f08060ed5dfdaff5d38fecf4954310e840adb5eacbf69c5d155cd3e9a8e1653"
+                .parse()
+                .unwrap()
+        );
+
+        // `Field<RegistryKey<Registry>, Registry>`, where `Registry { registry: Table<Domain,
+        // NameRecord>, reverse_registry: Table<address, Domain> }`
+        let reverse_table_id = ObjectId::new([1; 32]);
+        let registry = move_object(
+            &format!(
+                "0x2::dynamic_field::Field<{package}::suins::RegistryKey<{package}::registry::Registry>, {package}::registry::Registry>"
+            ),
+            bcs::to_bytes(&(
+                registry_field_id,
+                false,
+                (table_id, 250_000u64),
+                (reverse_table_id, 50_000u64),
+            ))
+            .unwrap(),
+        );
+        assert_eq!(suins_registry_table(&registry).unwrap(), table_id);
+
+        // `Field<Domain, NameRecord>` of `example.sui`
+        let labels = SuinsName::from_str("example.sui").unwrap().domain_labels();
+        let record_field_id: ObjectId =
+            "0xb14395960677cf28319f973a9e04c7622b0c112296af7c9c262d9cb4ffbb90cc"
+                .parse()
+                .unwrap();
+        let domain_type = TypeTag::from_str(&format!("{package}::domain::Domain")).unwrap();
+        assert_eq!(
+            table_id
+                .derive_dynamic_field_id(&domain_type, &labels)
+                .unwrap(),
+            record_field_id
+        );
+        let record = NameRecord {
+            nft_id: ObjectId::new([2; 32]),
+            expiration_timestamp_ms: 1_735_689_600_000,
+            target_address: Some(Address::new([3; 32])),
+            data: vec![(NameRecord::AVATAR.to_owned(), "0x4".to_owned())],
+        };
+        let field = move_object(
+            &format!(
+                "0x2::dynamic_field::Field<{package}::domain::Domain, {package}::name_record::NameRecord>"
+            ),
+            bcs::to_bytes(&(
+                record_field_id,
+                &labels,
+                record.nft_id,
+                record.expiration_timestamp_ms,
+                record.target_address,
+                &record.data,
+            ))
+            .unwrap(),
+        );
+        assert_eq!(suins_name_record(&field, &labels).unwrap(), record);
+
+        // Objects of other types are rejected
+        suins_registry_table(&suins).unwrap_err();
+        suins_name_record(&registry, &labels).unwrap_err();
+    }
+
     #[tokio::test]
     async fn test_balance_query() {
         let client = test_client();
@@ -1875,6 +3175,51 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_checkpoint_data_query() {
+        let client = test_client();
+        let checkpoint = client.checkpoint_data(0).await;
+        assert!(
+            checkpoint.is_ok(),
+            "Checkpoint data query failed for {} network. Error: {}",
+            client.rpc_server(),
+            checkpoint.unwrap_err()
+        );
+        let checkpoint = checkpoint.unwrap().unwrap();
+        assert_eq!(checkpoint.checkpoint_summary.checkpoint.sequence_number, 0);
+        assert_eq!(
+            checkpoint.checkpoint_contents.transactions().len(),
+            checkpoint.transactions.len()
+        );
+        // The genesis checkpoint creates the system objects
+        assert!(!checkpoint.transactions[0].output_objects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_end_of_epoch_checkpoint_data_query() {
+        let client = test_client();
+        // The last checkpoint of the first epoch, which must have ended
+        let total_checkpoints = client
+            .epoch_total_checkpoints(Some(0))
+            .await
+            .unwrap()
+            .unwrap();
+        let seq_num = total_checkpoints - 1;
+        assert!(seq_num > 0);
+
+        let checkpoint = client.checkpoint_data(seq_num).await;
+        assert!(
+            checkpoint.is_ok(),
+            "Checkpoint data query failed for {} network. Error: {}",
+            client.rpc_server(),
+            checkpoint.unwrap_err()
+        );
+        let summary = checkpoint.unwrap().unwrap().checkpoint_summary.checkpoint;
+        assert_eq!(summary.sequence_number, seq_num);
+        let end_of_epoch_data = summary.end_of_epoch_data.unwrap();
+        assert!(!end_of_epoch_data.next_epoch_committee.is_empty());
+    }
+
     #[tokio::test]
     async fn test_epoch_total_checkpoints_query() {
         let client = test_client();
@@ -1952,6 +3297,26 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_multi_get_objects_query() {
+        let client = test_client();
+        let keys: Vec<(ObjectId, Option<u64>)> = vec![
+            ("0x5".parse().unwrap(), None),
+            ("0x6".parse().unwrap(), Some(1)),
+            ("0x1234".parse().unwrap(), None),
+            ("0x5".parse().unwrap(), None),
+        ];
+        let objects = client.multi_get_objects(&keys).await.unwrap();
+        assert_eq!(objects.len(), keys.len());
+        assert_eq!(objects[0].as_ref().map(|o| o.object_id()), Some(keys[0].0));
+        assert_eq!(
+            objects[1].as_ref().map(|o| (o.object_id(), o.version())),
+            Some((keys[1].0, 1))
+        );
+        assert!(objects[2].is_none());
+        assert_eq!(objects[3], objects[0]);
+    }
+
     #[tokio::test]
     async fn test_object_bcs_query() {
         let client = test_client();
@@ -2032,6 +3397,32 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_multi_get_transactions_query() {
+        let client = test_client();
+        let transactions = client
+            .transactions(None, PaginationFilter::default())
+            .await
+            .unwrap();
+        let mut digests: Vec<Digest> = transactions
+            .data()
+            .iter()
+            .rev()
+            .map(|tx| tx.transaction.digest().into())
+            .collect();
+        digests.push(Digest::ZERO);
+
+        let fetched = client.multi_get_transactions(&digests).await.unwrap();
+        assert_eq!(fetched.len(), digests.len());
+        assert!(fetched.last().unwrap().is_none());
+        for (digest, tx) in digests.iter().zip(&fetched[..digests.len() - 1]) {
+            assert_eq!(
+                Digest::from(tx.as_ref().unwrap().transaction.digest()),
+                *digest
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_transactions_query() {
         let client = test_client();
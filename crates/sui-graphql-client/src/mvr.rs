@@ -0,0 +1,215 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of Move Registry (MVR) names, such as `@mysten/kiosk`, to the packages and types
+//! they refer to.
+//!
+//! Names are resolved by [`Client::resolve_mvr_package`], [`Client::resolve_mvr_type`] and
+//! [`Client::resolve_mvr_type_tag`], which cache the results of the GraphQL service until
+//! [`Client::clear_mvr_cache`] is called, e.g. to pick up the new version of an upgraded package.
+//! [`MvrOverrides`] allow resolving names without querying the service, e.g. in tests or on a
+//! local network where the registry isn't available.
+//!
+//! [`Client::clear_mvr_cache`]: crate::Client::clear_mvr_cache
+//! [`Client::resolve_mvr_package`]: crate::Client::resolve_mvr_package
+//! [`Client::resolve_mvr_type`]: crate::Client::resolve_mvr_type
+//! [`Client::resolve_mvr_type_tag`]: crate::Client::resolve_mvr_type_tag
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use sui_types::types::Address;
+use sui_types::types::NamedAddresses;
+use sui_types::types::StructTag;
+
+/// Names resolved without querying the GraphQL service.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MvrOverrides {
+    packages: HashMap<String, Address>,
+    types: HashMap<String, StructTag>,
+}
+
+impl MvrOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve the package named `name`, e.g. `@mysten/kiosk`, to `address`.
+    ///
+    /// Types within the package, such as `@mysten/kiosk::kiosk::Kiosk`, also resolve to
+    /// `address` unless they are overridden with [`with_type`](Self::with_type). Whereas the
+    /// GraphQL service resolves types to the address of the package version which first defined
+    /// them, an overridden package is assumed to have defined all of its types: if `address` is
+    /// an upgraded version of the package, override the types defined by earlier versions with
+    /// [`with_type`](Self::with_type).
+    pub fn with_package(mut self, name: &str, address: Address) -> Self {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.packages.insert(name.to_owned(), address);
+        self
+    }
+
+    /// Resolve the type named `name`, e.g. `@mysten/kiosk::kiosk::Kiosk`, to `type_`.
+    pub fn with_type(mut self, name: &str, type_: StructTag) -> Self {
+        self.types.insert(name.to_owned(), type_);
+        self
+    }
+
+    pub fn package(&self, name: &str) -> Option<Address> {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.packages.get(name).copied()
+    }
+
+    /// Resolve the type named `name` from the overrides, if it is overridden or all of the
+    /// packages it refers to are.
+    ///
+    /// Types within an overridden package resolve to the overridden address of the package, see
+    /// [`with_package`](Self::with_package).
+    pub fn type_(&self, name: &str) -> Option<StructTag> {
+        if let Some(type_) = self.types.get(name) {
+            return Some(type_.clone());
+        }
+
+        let mut names = NamedAddresses::empty();
+        for package in package_names(name) {
+            names.insert(package, self.package(package)?);
+        }
+        names.parse_struct_tag(name).ok()
+    }
+}
+
+/// The state of MVR name resolution of a client: its overrides and the names resolved so far.
+#[derive(Debug, Default)]
+pub(crate) struct MvrResolver {
+    overrides: MvrOverrides,
+    packages: Mutex<HashMap<String, Address>>,
+    types: Mutex<HashMap<String, StructTag>>,
+}
+
+impl MvrResolver {
+    pub(crate) fn new(overrides: MvrOverrides) -> Self {
+        Self {
+            overrides,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn package(&self, name: &str) -> Option<Address> {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.overrides
+            .package(name)
+            .or_else(|| self.packages.lock().unwrap().get(name).copied())
+    }
+
+    pub(crate) fn insert_package(&self, name: &str, address: Address) {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.packages
+            .lock()
+            .unwrap()
+            .insert(name.to_owned(), address);
+    }
+
+    pub(crate) fn type_(&self, name: &str) -> Option<StructTag> {
+        self.overrides
+            .type_(name)
+            .or_else(|| self.types.lock().unwrap().get(name).cloned())
+    }
+
+    pub(crate) fn insert_type(&self, name: &str, type_: StructTag) {
+        self.types.lock().unwrap().insert(name.to_owned(), type_);
+    }
+
+    /// Forget the names resolved so far, keeping the overrides.
+    pub(crate) fn clear(&self) {
+        self.packages.lock().unwrap().clear();
+        self.types.lock().unwrap().clear();
+    }
+}
+
+/// Split the `vector` wrappers off of the type named `name`, returning how deeply the remaining
+/// element type is nested within vectors, e.g. `(2, "@mysten/kiosk::kiosk::Kiosk")` for
+/// `vector<vector<@mysten/kiosk::kiosk::Kiosk>>`.
+pub(crate) fn strip_vectors(name: &str) -> (usize, &str) {
+    let mut depth = 0;
+    let mut name = name.trim();
+    while let Some(element) = name
+        .strip_prefix("vector<")
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        depth += 1;
+        name = element.trim();
+    }
+    (depth, name)
+}
+
+/// The MVR package names, without their leading `@`, referred to by the type named `name`.
+fn package_names(name: &str) -> impl Iterator<Item = &str> {
+    name.split('@').skip(1).map(|rest| {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || "_-/.".contains(c)))
+            .unwrap_or(rest.len());
+        &rest[..end]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mvr_overrides() {
+        let kiosk: Address = "0xabc".parse().unwrap();
+        let listing: StructTag = "0xdef::listing::Listing".parse().unwrap();
+        let overrides = MvrOverrides::new()
+            .with_package("@mysten/kiosk", kiosk)
+            .with_type("@mysten/listing::listing::Listing", listing.clone());
+
+        assert_eq!(overrides.package("@mysten/kiosk"), Some(kiosk));
+        assert_eq!(overrides.package("@mysten/other"), None);
+        assert_eq!(
+            overrides.type_("@mysten/listing::listing::Listing"),
+            Some(listing)
+        );
+        assert_eq!(
+            overrides.type_("@mysten/kiosk::kiosk::Item<@mysten/kiosk::kiosk::Kiosk, u64>"),
+            Some(
+                "0xabc::kiosk::Item<0xabc::kiosk::Kiosk, u64>"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            overrides.type_("@mysten/kiosk::kiosk::Item<@mysten/other::m::T>"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_strip_vectors() {
+        assert_eq!(
+            strip_vectors("@mysten/kiosk::kiosk::Kiosk"),
+            (0, "@mysten/kiosk::kiosk::Kiosk")
+        );
+        assert_eq!(
+            strip_vectors("vector<vector< @mysten/kiosk::kiosk::Item<u64> >>"),
+            (2, "@mysten/kiosk::kiosk::Item<u64>")
+        );
+        assert_eq!(strip_vectors("vector<u8>"), (1, "u8"));
+    }
+
+    #[test]
+    fn test_mvr_resolver() {
+        let kiosk: Address = "0xabc".parse().unwrap();
+        let other: Address = "0xdef".parse().unwrap();
+        let resolver = MvrResolver::new(MvrOverrides::new().with_package("@mysten/kiosk", kiosk));
+
+        // Names are cached with or without their leading `@`
+        resolver.insert_package("mysten/other", other);
+        assert_eq!(resolver.package("@mysten/other"), Some(other));
+        assert_eq!(resolver.package("mysten/kiosk"), Some(kiosk));
+
+        // Clearing the cache keeps the overrides
+        resolver.clear();
+        assert_eq!(resolver.package("@mysten/other"), None);
+        assert_eq!(resolver.package("@mysten/kiosk"), Some(kiosk));
+    }
+}
@@ -72,6 +72,8 @@ pub struct Validator {
     pub credentials: Option<ValidatorCredentials>,
     /// Validator's description.
     pub description: Option<String>,
+    /// The table of the exchange rates of the validator's staking pool at each epoch.
+    pub exchange_rates_table: Option<ExchangeRatesTable>,
     /// Number of exchange rates in the table.
     pub exchange_rates_size: Option<u64>,
     /// The reference gas price for this epoch.
@@ -115,6 +117,14 @@ pub struct Validator {
     pub voting_power: Option<i32>,
 }
 
+/// The table from epoch to the `0x3::staking_pool::PoolTokenExchangeRate` of a validator's staking
+/// pool at that epoch.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Owner")]
+pub struct ExchangeRatesTable {
+    pub address: Address,
+}
+
 /// The credentials related fields associated with a validator.
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema = "rpc", graphql_type = "ValidatorCredentials")]
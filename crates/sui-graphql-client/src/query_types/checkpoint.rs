@@ -15,6 +15,7 @@ use crate::query_types::Base64;
 use crate::query_types::BigInt;
 use crate::query_types::DateTime;
 use crate::query_types::Epoch;
+use crate::query_types::EventConnection;
 use crate::query_types::PageInfo;
 
 // ===========================================================================
@@ -62,6 +63,39 @@ pub struct CheckpointsArgs<'a> {
     pub before: Option<&'a str>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "Query",
+    variables = "CheckpointTransactionsArgs"
+)]
+pub struct CheckpointTransactionsQuery {
+    #[arguments(id: $id)]
+    pub checkpoint: Option<CheckpointTransactions>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "Query",
+    variables = "TransactionEffectsPageArgs"
+)]
+pub struct TransactionEventsQuery {
+    #[arguments(digest: $digest)]
+    pub transaction_block: Option<TransactionEventsBlock>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "Query",
+    variables = "TransactionEffectsPageArgs"
+)]
+pub struct TransactionObjectChangesQuery {
+    #[arguments(digest: $digest)]
+    pub transaction_block: Option<TransactionObjectChangesBlock>,
+}
+
 // ===========================================================================
 // Checkpoint Query Args
 // ===========================================================================
@@ -77,6 +111,19 @@ pub struct CheckpointId {
     pub digest: Option<String>,
     pub sequence_number: Option<u64>,
 }
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CheckpointTransactionsArgs<'a> {
+    pub id: CheckpointId,
+    pub after: Option<&'a str>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionEffectsPageArgs<'a> {
+    pub digest: String,
+    pub after: Option<&'a str>,
+}
+
 // ===========================================================================
 // Checkpoint Types
 // ===========================================================================
@@ -103,6 +150,105 @@ pub struct GasCostSummary {
     pub storage_rebate: Option<BigInt>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "Checkpoint",
+    variables = "CheckpointTransactionsArgs"
+)]
+pub struct CheckpointTransactions {
+    #[arguments(after: $after)]
+    pub transaction_blocks: CheckpointTransactionBlockConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "TransactionBlockConnection")]
+pub struct CheckpointTransactionBlockConnection {
+    pub nodes: Vec<CheckpointTransactionBlock>,
+    pub page_info: PageInfo,
+}
+
+/// A transaction along with the first page of its events and object changes.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "TransactionBlock")]
+pub struct CheckpointTransactionBlock {
+    pub bcs: Option<Base64>,
+    pub signatures: Option<Vec<Base64>>,
+    pub effects: Option<CheckpointTransactionEffects>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "TransactionBlockEffects")]
+pub struct CheckpointTransactionEffects {
+    pub bcs: Base64,
+    pub events: EventConnection,
+    pub object_changes: ObjectChangeConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "TransactionBlock",
+    variables = "TransactionEffectsPageArgs"
+)]
+pub struct TransactionEventsBlock {
+    pub effects: Option<TransactionEventsEffects>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "TransactionBlockEffects",
+    variables = "TransactionEffectsPageArgs"
+)]
+pub struct TransactionEventsEffects {
+    #[arguments(after: $after)]
+    pub events: EventConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "TransactionBlock",
+    variables = "TransactionEffectsPageArgs"
+)]
+pub struct TransactionObjectChangesBlock {
+    pub effects: Option<TransactionObjectChangesEffects>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema = "rpc",
+    graphql_type = "TransactionBlockEffects",
+    variables = "TransactionEffectsPageArgs"
+)]
+pub struct TransactionObjectChangesEffects {
+    #[arguments(after: $after)]
+    pub object_changes: ObjectChangeConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "ObjectChangeConnection")]
+pub struct ObjectChangeConnection {
+    pub nodes: Vec<ObjectChange>,
+    pub page_info: PageInfo,
+}
+
+/// The state of an object before and after a transaction. The input state is missing for
+/// created objects and the output state is missing for deleted or wrapped objects.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "ObjectChange")]
+pub struct ObjectChange {
+    pub input_state: Option<ObjectBcs>,
+    pub output_state: Option<ObjectBcs>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Object")]
+pub struct ObjectBcs {
+    pub bcs: Option<Base64>,
+}
+
 // TODO need bcs in GraphQL Checkpoint to avoid this conversion
 impl TryInto<CheckpointSummary> for Checkpoint {
     type Error = error::Error;
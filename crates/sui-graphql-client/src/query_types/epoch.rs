@@ -17,6 +17,13 @@ pub struct EpochSummaryQuery {
     pub epoch: Option<EpochSummary>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Query", variables = "EpochSummaryArgs")]
+pub struct EpochProtocolVersionQuery {
+    #[arguments(id: $id)]
+    pub epoch: Option<EpochProtocolVersion>,
+}
+
 // ===========================================================================
 // Epoch Summary Args
 // ===========================================================================
@@ -40,6 +47,19 @@ pub struct EpochSummary {
     pub total_transactions: Option<u64>,
 }
 
+/// The protocol version in effect during the epoch.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Epoch")]
+pub struct EpochProtocolVersion {
+    pub protocol_configs: EpochProtocolConfigs,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "ProtocolConfigs")]
+pub struct EpochProtocolConfigs {
+    pub protocol_version: u64,
+}
+
 // ===========================================================================
 // Epoch Types
 // ===========================================================================
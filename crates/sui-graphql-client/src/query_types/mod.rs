@@ -11,6 +11,7 @@ mod dynamic_fields;
 mod epoch;
 mod events;
 mod execute_tx;
+mod mvr;
 mod normalized_move;
 mod object;
 mod packages;
@@ -22,6 +23,7 @@ mod transaction;
 pub use active_validators::ActiveValidatorsArgs;
 pub use active_validators::ActiveValidatorsQuery;
 pub use active_validators::EpochValidator;
+pub use active_validators::ExchangeRatesTable;
 pub use active_validators::Validator;
 pub use active_validators::ValidatorConnection;
 pub use active_validators::ValidatorSet;
@@ -34,8 +36,15 @@ pub use checkpoint::CheckpointArgs;
 pub use checkpoint::CheckpointId;
 pub use checkpoint::CheckpointQuery;
 pub use checkpoint::CheckpointTotalTxQuery;
+pub use checkpoint::CheckpointTransactionBlock;
+pub use checkpoint::CheckpointTransactionsArgs;
+pub use checkpoint::CheckpointTransactionsQuery;
 pub use checkpoint::CheckpointsArgs;
 pub use checkpoint::CheckpointsQuery;
+pub use checkpoint::ObjectChange;
+pub use checkpoint::TransactionEffectsPageArgs;
+pub use checkpoint::TransactionEventsQuery;
+pub use checkpoint::TransactionObjectChangesQuery;
 pub use coin::CoinMetadata;
 pub use coin::CoinMetadataArgs;
 pub use coin::CoinMetadataQuery;
@@ -50,6 +59,7 @@ pub use dynamic_fields::DynamicFieldQuery;
 pub use dynamic_fields::DynamicFieldsOwnerQuery;
 pub use dynamic_fields::DynamicObjectFieldQuery;
 pub use epoch::Epoch;
+pub use epoch::EpochProtocolVersionQuery;
 pub use epoch::EpochSummaryArgs;
 pub use epoch::EpochSummaryQuery;
 pub use events::Event;
@@ -60,6 +70,10 @@ pub use events::EventsQueryArgs;
 pub use execute_tx::ExecuteTransactionArgs;
 pub use execute_tx::ExecuteTransactionQuery;
 pub use execute_tx::ExecutionResult;
+pub use mvr::MvrNameArgs;
+pub use mvr::MvrPackage;
+pub use mvr::MvrPackageQuery;
+pub use mvr::MvrTypeQuery;
 pub use normalized_move::MoveAbility;
 pub use normalized_move::MoveFunction;
 pub use normalized_move::MoveFunctionTypeParameter;
@@ -88,14 +102,17 @@ pub use packages::PackageVersionsArgs;
 pub use packages::PackageVersionsQuery;
 pub use packages::PackagesQuery;
 pub use packages::PackagesQueryArgs;
+pub use protocol_config::ProtocolConfigAttr;
 pub use protocol_config::ProtocolConfigQuery;
 pub use protocol_config::ProtocolConfigs;
 pub use protocol_config::ProtocolVersionArgs;
 pub use service_config::Feature;
 pub use service_config::ServiceConfig;
 pub use service_config::ServiceConfigQuery;
+pub use suins::AddressDefaultSuins;
 pub use suins::DefaultSuinsNameQuery;
 pub use suins::DefaultSuinsNameQueryArgs;
+pub use suins::DomainAddress;
 pub use suins::ResolveSuinsQuery;
 pub use suins::ResolveSuinsQueryArgs;
 pub use transaction::TransactionBlock;
@@ -0,0 +1,35 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// ===========================================================================
+// Move Registry Queries
+// ===========================================================================
+
+use crate::query_types::schema;
+use crate::query_types::Address as SdkAddress;
+use crate::query_types::MoveType;
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Query", variables = "MvrNameArgs")]
+pub struct MvrPackageQuery {
+    #[arguments(name: $name)]
+    pub package_by_name: Option<MvrPackage>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "Query", variables = "MvrNameArgs")]
+pub struct MvrTypeQuery {
+    #[arguments(name: $name)]
+    pub type_by_name: MoveType,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MvrNameArgs<'a> {
+    pub name: &'a str,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema = "rpc", graphql_type = "MovePackage")]
+pub struct MvrPackage {
+    pub address: SdkAddress,
+}
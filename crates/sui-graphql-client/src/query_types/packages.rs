@@ -46,7 +46,7 @@ pub struct PackageArgs {
     variables = "PackageByNameArgs"
 )]
 pub struct PackageByNameQuery {
-    #[arguments(name: "")]
+    #[arguments(name: $name)]
     pub package_by_name: Option<MovePackage>,
 }
 
@@ -49,6 +49,22 @@ pub struct ProtocolConfigs {
     pub configs: Vec<ProtocolConfigAttr>,
 }
 
+impl ProtocolConfigs {
+    /// The value of the configuration `key`, if it is set.
+    pub fn config(&self, key: &str) -> Option<&str> {
+        self.configs
+            .iter()
+            .find(|config| config.key == key)
+            .and_then(|config| config.value.as_deref())
+    }
+
+    /// The maximum number of coins which can be used for the gas payment of a transaction, to be
+    /// passed to `CoinSelector::with_max_coins` when selecting coins.
+    pub fn max_gas_payment_objects(&self) -> Option<usize> {
+        self.config("max_gas_payment_objects")?.parse().ok()
+    }
+}
+
 /// Feature flags are a form of boolean configuration that are usually used to gate features while
 /// they are in development. Once a lag has been enabled, it is rare for it to be disabled.
 #[derive(cynic::QueryFragment, Debug)]
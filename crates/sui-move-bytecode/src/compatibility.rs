@@ -0,0 +1,1213 @@
+//! Checking that a package upgrade is permitted by an upgrade policy.
+//!
+//! [`check_upgrade`] compares a package with its proposed upgrade, reporting every way in which
+//! the upgrade violates the rules enforced when an upgrade transaction is executed. This allows
+//! incompatible upgrades to be caught before an upgrade transaction is submitted.
+//!
+//! [`check_upgrade_modules`] performs the same checks on the compiled modules and dependencies of
+//! an upgrade which has not been published yet, as given to
+//! [`ProgrammableTransaction::upgrade`](sui_sdk_types::types::ProgrammableTransaction::upgrade).
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Identifier;
+use sui_sdk_types::types::MovePackage;
+use sui_sdk_types::types::ObjectId;
+use sui_sdk_types::types::Version;
+
+use crate::deserializer::decode_code;
+use crate::deserializer::RawOperand;
+use crate::file_format::CodeUnit;
+use crate::file_format::DatatypeHandleIndex;
+use crate::file_format::SignatureIndex;
+use crate::file_format::SignatureToken;
+use crate::file_format::TableIndex;
+use crate::file_format::VariantTag;
+use crate::file_format::Visibility;
+use crate::normalized::Module;
+use crate::normalized::ModuleId;
+use crate::normalized::OpenType;
+use crate::CompiledModule;
+use crate::Error;
+
+pub use sui_sdk_types::types::UpgradePolicy;
+
+/// An aspect of a struct or enum that an upgrade changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DatatypeChange {
+    Abilities,
+    TypeParameters,
+    /// The fields of a struct or the variants of an enum.
+    Layout,
+}
+
+/// An aspect of a function that an upgrade changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FunctionChange {
+    Visibility,
+    Entry,
+    /// The constraints on the type parameters were tightened or, under the additive and
+    /// dependency-only policies, changed in any way.
+    TypeParameters,
+    /// The parameter or return types.
+    Signature,
+    /// The function's locals or bytecode.
+    ///
+    /// Bodies are compared after resolving the table indices in their instructions to the
+    /// entities they refer to, so a recompilation which only reorders the tables of a module is
+    /// not reported as a change.
+    Body,
+}
+
+/// A way in which an upgrade violates an [`UpgradePolicy`] or is internally inconsistent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Violation {
+    /// A module was removed.
+    ModuleRemoved { module: Identifier },
+    /// A module was added to a package with the dependency-only policy.
+    ModuleAdded { module: Identifier },
+    /// A module's address is not the package's original id.
+    ModuleAddressChanged {
+        module: Identifier,
+        old: Address,
+        new: Address,
+    },
+    /// A module's friends were removed or, under the dependency-only policy, added.
+    FriendsChanged { module: Identifier },
+    /// A struct or enum was removed.
+    DatatypeRemoved {
+        module: Identifier,
+        name: Identifier,
+    },
+    /// A struct or enum was added to a package with the dependency-only policy.
+    DatatypeAdded {
+        module: Identifier,
+        name: Identifier,
+    },
+    /// A struct or enum changed.
+    DatatypeChanged {
+        module: Identifier,
+        name: Identifier,
+        change: DatatypeChange,
+    },
+    /// A function which must be preserved under the policy was removed.
+    FunctionRemoved {
+        module: Identifier,
+        name: Identifier,
+    },
+    /// A function was added to a package with the dependency-only policy.
+    FunctionAdded {
+        module: Identifier,
+        name: Identifier,
+    },
+    /// A function which must be preserved under the policy changed.
+    FunctionChanged {
+        module: Identifier,
+        name: Identifier,
+        change: FunctionChange,
+    },
+    /// The type origin table does not record the package a datatype was first defined in.
+    TypeOrigin {
+        module: Identifier,
+        name: Identifier,
+        expected: ObjectId,
+        actual: Option<ObjectId>,
+    },
+    /// A module depends on a package missing from the linkage table.
+    LinkageMissing { dependency: Address },
+    /// A dependency was downgraded to an earlier version.
+    LinkageDowngraded {
+        dependency: ObjectId,
+        old: Version,
+        new: Version,
+    },
+    /// The upgraded package's version does not immediately follow the original's.
+    VersionNotIncremented { old: Version, new: Version },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::ModuleRemoved { module } => write!(f, "module `{module}` was removed"),
+            Violation::ModuleAdded { module } => write!(f, "module `{module}` was added"),
+            Violation::ModuleAddressChanged { module, old, new } => {
+                write!(f, "module `{module}` changed address from {old} to {new}")
+            }
+            Violation::FriendsChanged { module } => {
+                write!(f, "friends of module `{module}` changed")
+            }
+            Violation::DatatypeRemoved { module, name } => {
+                write!(f, "datatype `{module}::{name}` was removed")
+            }
+            Violation::DatatypeAdded { module, name } => {
+                write!(f, "datatype `{module}::{name}` was added")
+            }
+            Violation::DatatypeChanged {
+                module,
+                name,
+                change,
+            } => write!(f, "{change:?} of datatype `{module}::{name}` changed"),
+            Violation::FunctionRemoved { module, name } => {
+                write!(f, "function `{module}::{name}` was removed")
+            }
+            Violation::FunctionAdded { module, name } => {
+                write!(f, "function `{module}::{name}` was added")
+            }
+            Violation::FunctionChanged {
+                module,
+                name,
+                change,
+            } => write!(f, "{change:?} of function `{module}::{name}` changed"),
+            Violation::TypeOrigin {
+                module,
+                name,
+                expected,
+                actual: Some(actual),
+            } => write!(
+                f,
+                "type origin of `{module}::{name}` is {actual}, expected {expected}"
+            ),
+            Violation::TypeOrigin {
+                module,
+                name,
+                expected,
+                actual: None,
+            } => write!(
+                f,
+                "type origin of `{module}::{name}` is missing, expected {expected}"
+            ),
+            Violation::LinkageMissing { dependency } => {
+                write!(
+                    f,
+                    "dependency {dependency} is missing from the linkage table"
+                )
+            }
+            Violation::LinkageDowngraded {
+                dependency,
+                old,
+                new,
+            } => write!(
+                f,
+                "dependency {dependency} was downgraded from version {old} to {new}"
+            ),
+            Violation::VersionNotIncremented { old, new } => write!(
+                f,
+                "upgraded package has version {new}, expected {}",
+                old + 1
+            ),
+        }
+    }
+}
+
+/// Check whether `new` is a valid upgrade of `old` under `policy`, returning every violation
+/// found.
+///
+/// Besides the rules of the policy, this checks that `new` is consistent with `old`: its version
+/// follows that of `old`, its modules keep the original package's address, its type origin table
+/// records where each datatype was first defined (with datatypes introduced by the upgrade
+/// originating from `new` itself), and its linkage table covers every dependency without
+/// downgrading any of those of `old`.
+///
+/// Returns an error if a module of either package cannot be deserialized.
+pub fn check_upgrade(
+    old: &MovePackage,
+    new: &MovePackage,
+    policy: UpgradePolicy,
+) -> Result<Vec<Violation>, Error> {
+    let old_modules = modules(old)?;
+    let new_modules = modules(new)?;
+    let mut violations = Vec::new();
+
+    if new.version != old.version + 1 {
+        violations.push(Violation::VersionNotIncremented {
+            old: old.version,
+            new: new.version,
+        });
+    }
+
+    check_modules(&old_modules, &new_modules, policy, &mut violations);
+    check_type_origins(old, new, &new_modules, &mut violations);
+    check_linkage(old, new, &new_modules, &mut violations);
+    Ok(violations)
+}
+
+/// Check whether the package made up of `modules`, linked against the packages in
+/// `dependencies`, is a valid upgrade of `old` under `policy`, returning every violation found.
+///
+/// `modules` and `dependencies` are those of an upgrade which has not been published yet, as
+/// given to [`ProgrammableTransaction::upgrade`]: modules are expected to be compiled with the
+/// address of their own package set to `0x0`, which is substituted with the original id of `old`
+/// as it is when the upgrade is executed.
+///
+/// The version, type origin table and linkage table of the upgraded package are assigned when
+/// the upgrade is executed, so unlike [`check_upgrade`], they are not checked. The only linkage
+/// violation reported is a dependency of `old` linked at an upgraded version being linked at its
+/// original version, by listing its original id in `dependencies`.
+///
+/// Returns an error if a module of `old` or `modules` cannot be deserialized.
+///
+/// [`ProgrammableTransaction::upgrade`]: sui_sdk_types::types::ProgrammableTransaction::upgrade
+pub fn check_upgrade_modules(
+    old: &MovePackage,
+    modules: &[Vec<u8>],
+    dependencies: &[ObjectId],
+    policy: UpgradePolicy,
+) -> Result<Vec<Violation>, Error> {
+    let old_modules = self::modules(old)?;
+    let original_id = old_modules.values().next().map(|info| info.module.address);
+    let new_modules = modules
+        .iter()
+        .map(|bytes| {
+            let mut compiled = CompiledModule::deserialize(bytes)?;
+            if let Some(original_id) = original_id {
+                let index = compiled.self_handle().address as usize;
+                if compiled.address_identifiers[index] == Address::ZERO {
+                    compiled.address_identifiers[index] = original_id;
+                }
+            }
+            Ok((compiled.name().clone(), ModuleInfo::new(&compiled)?))
+        })
+        .collect::<Result<BTreeMap<_, _>, Error>>()?;
+    let mut violations = Vec::new();
+
+    check_modules(&old_modules, &new_modules, policy, &mut violations);
+    for (dependency, info) in &old.linkage_table {
+        // Packages are first published at version 1, under their original id
+        if info.upgraded_id != *dependency && dependencies.contains(dependency) {
+            violations.push(Violation::LinkageDowngraded {
+                dependency: *dependency,
+                old: info.upgraded_version,
+                new: 1,
+            });
+        }
+    }
+    Ok(violations)
+}
+
+/// A normalized module along with the details needed to compare it that normalization discards.
+struct ModuleInfo {
+    module: Module,
+    /// The locals and serialized bytecode of each function, keyed by name.
+    bodies: BTreeMap<Identifier, Option<FunctionBody>>,
+    /// The addresses of the other packages this module refers to.
+    dependencies: BTreeSet<Address>,
+}
+
+/// The implementation of a non-native function.
+///
+/// The table indices within its instructions are resolved to the entities they refer to, so that
+/// the bodies of functions from modules whose tables are laid out differently can be compared.
+#[derive(Clone, PartialEq, Eq)]
+struct FunctionBody {
+    locals: Vec<OpenType>,
+    code: Vec<Instruction>,
+    jump_tables: Vec<JumpTable>,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct Instruction {
+    opcode: u8,
+    operand: Operand,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Operand {
+    None,
+    /// A branch target or jump table index.
+    Index(u64),
+    /// A local index or an integer constant.
+    Bytes(Vec<u8>),
+    Constant {
+        type_: OpenType,
+        data: Vec<u8>,
+    },
+    Function {
+        module: ModuleId,
+        name: Identifier,
+        type_arguments: Vec<OpenType>,
+    },
+    /// A struct, instantiated with any type arguments.
+    Datatype(OpenType),
+    Field {
+        owner: OpenType,
+        field: u16,
+    },
+    Variant {
+        owner: OpenType,
+        variant: VariantTag,
+    },
+    /// The element type of a vector operation, along with the element count of a vector pack or
+    /// unpack.
+    Vector {
+        element: Vec<OpenType>,
+        count: Option<u64>,
+    },
+}
+
+/// The branch targets of a `VariantSwitch` over the variants of `owner`.
+#[derive(Clone, PartialEq, Eq)]
+struct JumpTable {
+    owner: OpenType,
+    branches: Vec<TableIndex>,
+}
+
+impl FunctionBody {
+    fn new(module: &CompiledModule, code: &CodeUnit) -> Result<Self, Error> {
+        let locals = module
+            .signature_at(code.locals)
+            .0
+            .iter()
+            .map(|token| OpenType::new(module, token))
+            .collect();
+
+        // Indices within the tables of the module are checked when it is deserialized, while the
+        // operands of instructions are not and need to be checked here.
+        fn entry<'a, T>(table: &'static str, entries: &'a [T], index: u64) -> Result<&'a T, Error> {
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| entries.get(index))
+                .ok_or(Error::IndexOutOfBounds {
+                    table,
+                    index: usize::try_from(index).unwrap_or(usize::MAX),
+                    len: entries.len(),
+                })
+        }
+        let types = |index: u64| {
+            let signature = entry("signatures", &module.signatures, index)?;
+            Ok::<_, Error>(
+                signature
+                    .0
+                    .iter()
+                    .map(|token| OpenType::new(module, token))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let datatype = |handle: DatatypeHandleIndex, type_arguments: Option<SignatureIndex>| {
+            let token = match type_arguments {
+                Some(index) => SignatureToken::DatatypeInstantiation(
+                    handle,
+                    module.signature_at(index).0.clone(),
+                ),
+                None => SignatureToken::Datatype(handle),
+            };
+            OpenType::new(module, &token)
+        };
+        let struct_def = |index: u64, type_arguments| {
+            let def = entry("struct definitions", &module.struct_defs, index)?;
+            Ok::<_, Error>(datatype(def.struct_handle, type_arguments))
+        };
+        let enum_def = |index: u64, type_arguments| {
+            let def = entry("enum definitions", &module.enum_defs, index)?;
+            Ok::<_, Error>(datatype(def.enum_handle, type_arguments))
+        };
+        let function = |index: u64, type_arguments: Option<SignatureIndex>| {
+            let handle = entry("function handles", &module.function_handles, index)?;
+            let defining_module = module.module_handle_at(handle.module);
+            Ok::<_, Error>(Operand::Function {
+                module: ModuleId {
+                    address: module.address_identifier_at(defining_module.address),
+                    name: module.identifier_at(defining_module.name).clone(),
+                },
+                name: module.identifier_at(handle.name).clone(),
+                type_arguments: type_arguments
+                    .map(|index| types(index.into()))
+                    .transpose()?
+                    .unwrap_or_default(),
+            })
+        };
+        let field = |index: u64, type_arguments| {
+            let handle = entry("field handles", &module.field_handles, index)?;
+            Ok::<_, Error>(Operand::Field {
+                owner: struct_def(handle.owner.into(), type_arguments)?,
+                field: handle.field,
+            })
+        };
+
+        let (instructions, jump_tables) = decode_code(&code.code, module.version)?;
+        let code = instructions
+            .into_iter()
+            .map(|instruction| {
+                let operand = match (instruction.opcode, instruction.operand) {
+                    (_, RawOperand::None) => Operand::None,
+                    (_, RawOperand::Bytes(bytes)) => Operand::Bytes(bytes.to_vec()),
+                    (_, RawOperand::Vector(signature, count)) => Operand::Vector {
+                        element: types(signature.into())?,
+                        count: Some(count),
+                    },
+                    // LdConst
+                    (0x07, RawOperand::Index(index)) => {
+                        let constant = entry("constants", &module.constant_pool, index)?;
+                        Operand::Constant {
+                            type_: OpenType::new(module, &constant.type_),
+                            data: constant.data.clone(),
+                        }
+                    }
+                    // Call
+                    (0x11, RawOperand::Index(index)) => function(index, None)?,
+                    // CallGeneric
+                    (0x38, RawOperand::Index(index)) => {
+                        let instantiation = entry(
+                            "function instantiations",
+                            &module.function_instantiations,
+                            index,
+                        )?;
+                        function(
+                            instantiation.handle.into(),
+                            Some(instantiation.type_parameters),
+                        )?
+                    }
+                    // Pack, Unpack and global storage operations
+                    (0x12 | 0x13 | 0x29..=0x2D, RawOperand::Index(index)) => {
+                        Operand::Datatype(struct_def(index, None)?)
+                    }
+                    (0x39..=0x3F, RawOperand::Index(index)) => {
+                        let instantiation = entry(
+                            "struct definition instantiations",
+                            &module.struct_def_instantiations,
+                            index,
+                        )?;
+                        Operand::Datatype(struct_def(
+                            instantiation.def.into(),
+                            Some(instantiation.type_parameters),
+                        )?)
+                    }
+                    // Field borrows
+                    (0x0F | 0x10, RawOperand::Index(index)) => field(index, None)?,
+                    (0x36 | 0x37, RawOperand::Index(index)) => {
+                        let instantiation =
+                            entry("field instantiations", &module.field_instantiations, index)?;
+                        field(
+                            instantiation.handle.into(),
+                            Some(instantiation.type_parameters),
+                        )?
+                    }
+                    // Vector operations other than pack and unpack
+                    (0x41..=0x45 | 0x47, RawOperand::Index(index)) => Operand::Vector {
+                        element: types(index)?,
+                        count: None,
+                    },
+                    // Variant pack and unpack
+                    (0x4E | 0x50..=0x52, RawOperand::Index(index)) => {
+                        let handle = entry("variant handles", &module.variant_handles, index)?;
+                        Operand::Variant {
+                            owner: enum_def(handle.enum_def.into(), None)?,
+                            variant: handle.variant,
+                        }
+                    }
+                    (0x4F | 0x53..=0x55, RawOperand::Index(index)) => {
+                        let handle = entry(
+                            "variant instantiation handles",
+                            &module.variant_instantiation_handles,
+                            index,
+                        )?;
+                        let instantiation =
+                            &module.enum_def_instantiations[usize::from(handle.enum_def)];
+                        Operand::Variant {
+                            owner: enum_def(
+                                instantiation.def.into(),
+                                Some(instantiation.type_parameters),
+                            )?,
+                            variant: handle.variant,
+                        }
+                    }
+                    // Branches and VariantSwitch
+                    (_, RawOperand::Index(index)) => Operand::Index(index),
+                };
+                Ok(Instruction {
+                    opcode: instruction.opcode,
+                    operand,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+        let jump_tables = jump_tables
+            .into_iter()
+            .map(|table| {
+                Ok(JumpTable {
+                    owner: enum_def(table.enum_def.into(), None)?,
+                    branches: table.branches,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            locals,
+            code,
+            jump_tables,
+        })
+    }
+}
+
+impl ModuleInfo {
+    fn new(compiled: &CompiledModule) -> Result<Self, Error> {
+        let bodies = compiled
+            .function_defs
+            .iter()
+            .map(|def| {
+                let name = compiled
+                    .identifier_at(compiled.function_handle_at(def.function).name)
+                    .clone();
+                let body = def
+                    .code
+                    .as_ref()
+                    .map(|code| FunctionBody::new(compiled, code))
+                    .transpose()?;
+                Ok((name, body))
+            })
+            .collect::<Result<_, Error>>()?;
+        let dependencies = compiled
+            .module_handles
+            .iter()
+            .map(|handle| compiled.address_identifier_at(handle.address))
+            .filter(|address| *address != compiled.address())
+            .collect();
+
+        Ok(Self {
+            module: Module::new(compiled),
+            bodies,
+            dependencies,
+        })
+    }
+}
+
+fn modules(package: &MovePackage) -> Result<BTreeMap<Identifier, ModuleInfo>, Error> {
+    package
+        .modules
+        .iter()
+        .map(|(name, bytes)| {
+            let compiled = CompiledModule::deserialize(bytes)?;
+            Ok((name.clone(), ModuleInfo::new(&compiled)?))
+        })
+        .collect()
+}
+
+fn check_modules(
+    old_modules: &BTreeMap<Identifier, ModuleInfo>,
+    new_modules: &BTreeMap<Identifier, ModuleInfo>,
+    policy: UpgradePolicy,
+    violations: &mut Vec<Violation>,
+) {
+    for (name, old_module) in old_modules {
+        match new_modules.get(name) {
+            Some(new_module) => check_module(old_module, new_module, policy, violations),
+            None => violations.push(Violation::ModuleRemoved {
+                module: name.clone(),
+            }),
+        }
+    }
+    if policy == UpgradePolicy::DependencyOnly {
+        for name in new_modules.keys() {
+            if !old_modules.contains_key(name) {
+                violations.push(Violation::ModuleAdded {
+                    module: name.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn check_module(
+    old: &ModuleInfo,
+    new: &ModuleInfo,
+    policy: UpgradePolicy,
+    violations: &mut Vec<Violation>,
+) {
+    let module = &old.module.name;
+    let datatype_changed = |name: &Identifier, change| Violation::DatatypeChanged {
+        module: module.clone(),
+        name: name.clone(),
+        change,
+    };
+
+    if old.module.address != new.module.address {
+        violations.push(Violation::ModuleAddressChanged {
+            module: module.clone(),
+            old: old.module.address,
+            new: new.module.address,
+        });
+    }
+
+    if policy >= UpgradePolicy::Additive {
+        let removed = old
+            .module
+            .friends
+            .iter()
+            .any(|friend| !new.module.friends.contains(friend));
+        let added = new
+            .module
+            .friends
+            .iter()
+            .any(|friend| !old.module.friends.contains(friend));
+        if removed || (added && policy == UpgradePolicy::DependencyOnly) {
+            violations.push(Violation::FriendsChanged {
+                module: module.clone(),
+            });
+        }
+    }
+
+    for (name, old_struct) in &old.module.structs {
+        let Some(new_struct) = new.module.structs.get(name) else {
+            violations.push(Violation::DatatypeRemoved {
+                module: module.clone(),
+                name: name.clone(),
+            });
+            continue;
+        };
+        if old_struct.abilities != new_struct.abilities {
+            violations.push(datatype_changed(name, DatatypeChange::Abilities));
+        }
+        if old_struct.type_parameters != new_struct.type_parameters {
+            violations.push(datatype_changed(name, DatatypeChange::TypeParameters));
+        }
+        if old_struct.fields != new_struct.fields {
+            violations.push(datatype_changed(name, DatatypeChange::Layout));
+        }
+    }
+
+    for (name, old_enum) in &old.module.enums {
+        let Some(new_enum) = new.module.enums.get(name) else {
+            violations.push(Violation::DatatypeRemoved {
+                module: module.clone(),
+                name: name.clone(),
+            });
+            continue;
+        };
+        if old_enum.abilities != new_enum.abilities {
+            violations.push(datatype_changed(name, DatatypeChange::Abilities));
+        }
+        if old_enum.type_parameters != new_enum.type_parameters {
+            violations.push(datatype_changed(name, DatatypeChange::TypeParameters));
+        }
+        if old_enum.variants != new_enum.variants {
+            violations.push(datatype_changed(name, DatatypeChange::Layout));
+        }
+    }
+
+    for (name, old_function) in &old.module.functions {
+        // Under the compatible policy only public functions need to be preserved, while the
+        // stricter policies preserve every function
+        if policy == UpgradePolicy::Compatible && old_function.visibility != Visibility::Public {
+            continue;
+        }
+
+        let Some(new_function) = new.module.functions.get(name) else {
+            violations.push(Violation::FunctionRemoved {
+                module: module.clone(),
+                name: name.clone(),
+            });
+            continue;
+        };
+
+        let mut changes = Vec::new();
+        if old_function.visibility != new_function.visibility {
+            changes.push(FunctionChange::Visibility);
+        }
+        // Relaxing the constraints on a type parameter is compatible, as any type argument that
+        // satisfied the old constraints still satisfies the new ones, but existing code may not
+        // change at all under the stricter policies
+        let constraints_relaxed = old_function.type_parameters.len()
+            == new_function.type_parameters.len()
+            && old_function
+                .type_parameters
+                .iter()
+                .zip(&new_function.type_parameters)
+                .all(|(old, new)| new.is_subset_of(*old));
+        if !constraints_relaxed
+            || (policy >= UpgradePolicy::Additive
+                && old_function.type_parameters != new_function.type_parameters)
+        {
+            changes.push(FunctionChange::TypeParameters);
+        }
+        if old_function.parameters != new_function.parameters
+            || old_function.return_ != new_function.return_
+        {
+            changes.push(FunctionChange::Signature);
+        }
+        if policy >= UpgradePolicy::Additive {
+            if old_function.is_entry != new_function.is_entry {
+                changes.push(FunctionChange::Entry);
+            }
+            if old.bodies.get(name) != new.bodies.get(name) {
+                changes.push(FunctionChange::Body);
+            }
+        }
+        violations.extend(
+            changes
+                .into_iter()
+                .map(|change| Violation::FunctionChanged {
+                    module: module.clone(),
+                    name: name.clone(),
+                    change,
+                }),
+        );
+    }
+
+    if policy == UpgradePolicy::DependencyOnly {
+        let added_datatypes = new
+            .module
+            .structs
+            .keys()
+            .filter(|name| !old.module.structs.contains_key(*name))
+            .chain(
+                new.module
+                    .enums
+                    .keys()
+                    .filter(|name| !old.module.enums.contains_key(*name)),
+            );
+        for name in added_datatypes {
+            violations.push(Violation::DatatypeAdded {
+                module: module.clone(),
+                name: name.clone(),
+            });
+        }
+
+        for name in new.module.functions.keys() {
+            if !old.module.functions.contains_key(name) {
+                violations.push(Violation::FunctionAdded {
+                    module: module.clone(),
+                    name: name.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn check_type_origins(
+    old: &MovePackage,
+    new: &MovePackage,
+    new_modules: &BTreeMap<Identifier, ModuleInfo>,
+    violations: &mut Vec<Violation>,
+) {
+    let origins = |package: &MovePackage| {
+        package
+            .type_origin_table
+            .iter()
+            .map(|origin| {
+                (
+                    (origin.module_name.clone(), origin.struct_name.clone()),
+                    origin.package,
+                )
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+    let old_origins = origins(old);
+    let new_origins = origins(new);
+
+    for (module_name, info) in new_modules {
+        let datatypes = info.module.structs.keys().chain(info.module.enums.keys());
+        for name in datatypes {
+            let key = (module_name.clone(), name.clone());
+            let expected = old_origins.get(&key).copied().unwrap_or(new.id);
+            let actual = new_origins.get(&key).copied();
+            if actual != Some(expected) {
+                violations.push(Violation::TypeOrigin {
+                    module: module_name.clone(),
+                    name: name.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+}
+
+fn check_linkage(
+    old: &MovePackage,
+    new: &MovePackage,
+    new_modules: &BTreeMap<Identifier, ModuleInfo>,
+    violations: &mut Vec<Violation>,
+) {
+    let dependencies = new_modules
+        .values()
+        .flat_map(|info| &info.dependencies)
+        .collect::<BTreeSet<_>>();
+    for dependency in dependencies {
+        if !new.linkage_table.contains_key(&ObjectId::from(*dependency)) {
+            violations.push(Violation::LinkageMissing {
+                dependency: *dependency,
+            });
+        }
+    }
+
+    for (dependency, old_info) in &old.linkage_table {
+        if let Some(new_info) = new.linkage_table.get(dependency) {
+            if new_info.upgraded_version < old_info.upgraded_version {
+                violations.push(Violation::LinkageDowngraded {
+                    dependency: *dependency,
+                    old: old_info.upgraded_version,
+                    new: new_info.upgraded_version,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::deserializer::RawInstruction;
+    use crate::deserializer::RawJumpTable;
+    use crate::file_format::Ability;
+    use crate::file_format::AbilitySet;
+    use crate::test::genesis_packages;
+    use crate::test::uleb128;
+
+    fn sui_framework() -> MovePackage {
+        genesis_packages()
+            .into_iter()
+            .find(|package| package.id == ObjectId::from(Address::TWO))
+            .unwrap()
+    }
+
+    fn upgrade(package: &MovePackage) -> MovePackage {
+        MovePackage {
+            version: package.version + 1,
+            ..package.clone()
+        }
+    }
+
+    fn ident(name: &str) -> Identifier {
+        Identifier::new(name).unwrap()
+    }
+
+    #[test]
+    fn identical_upgrade() {
+        let old = sui_framework();
+        let new = upgrade(&old);
+        assert_eq!(
+            check_upgrade(&old, &new, UpgradePolicy::DependencyOnly).unwrap(),
+            []
+        );
+    }
+
+    #[test]
+    fn unpublished_upgrade() {
+        let old = sui_framework();
+        let dependencies = [ObjectId::from(Address::ONE)];
+
+        // Modules compiled for an upgrade have the address of their own package set to `0x0`
+        let modules = old
+            .modules
+            .values()
+            .map(|bytes| {
+                let framework = Address::TWO.into_inner();
+                let position = bytes
+                    .windows(Address::LENGTH)
+                    .position(|window| window == framework)
+                    .unwrap();
+                let mut bytes = bytes.clone();
+                bytes[position..position + Address::LENGTH].fill(0);
+                bytes
+            })
+            .collect::<Vec<_>>();
+        assert!(modules.iter().all(|bytes| {
+            CompiledModule::deserialize(bytes).unwrap().address() == Address::ZERO
+        }));
+        assert_eq!(
+            check_upgrade_modules(&old, &modules, &dependencies, UpgradePolicy::DependencyOnly)
+                .unwrap(),
+            []
+        );
+
+        let violations = check_upgrade_modules(
+            &old,
+            &modules[1..],
+            &dependencies,
+            UpgradePolicy::Compatible,
+        )
+        .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0], Violation::ModuleRemoved { .. }));
+
+        // A dependency linked at an upgraded version can't be linked at its original version
+        let mut old = old;
+        let dependency: ObjectId = "0x42".parse().unwrap();
+        old.linkage_table.insert(
+            dependency,
+            sui_sdk_types::types::UpgradeInfo {
+                upgraded_id: "0x43".parse().unwrap(),
+                upgraded_version: 2,
+            },
+        );
+        assert_eq!(
+            check_upgrade_modules(&old, &modules, &[dependency], UpgradePolicy::Compatible)
+                .unwrap(),
+            [Violation::LinkageDowngraded {
+                dependency,
+                old: 2,
+                new: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn package_level_violations() {
+        let old = sui_framework();
+        let mut new = old.clone();
+        new.modules.remove(&ident("coin"));
+        new.type_origin_table
+            .retain(|origin| origin.module_name != ident("balance"));
+        new.linkage_table.clear();
+
+        let violations = check_upgrade(&old, &new, UpgradePolicy::Compatible).unwrap();
+        assert!(violations.contains(&Violation::VersionNotIncremented {
+            old: old.version,
+            new: old.version,
+        }));
+        assert!(violations.contains(&Violation::ModuleRemoved {
+            module: ident("coin")
+        }));
+        assert!(violations.contains(&Violation::TypeOrigin {
+            module: ident("balance"),
+            name: ident("Balance"),
+            expected: old.id,
+            actual: None,
+        }));
+        assert!(violations.contains(&Violation::LinkageMissing {
+            dependency: "0x1".parse().unwrap(),
+        }));
+    }
+
+    #[test]
+    fn module_violations() {
+        let old = sui_framework();
+        let modules = modules(&old).unwrap();
+        let old_coin = &modules[&ident("coin")];
+        let new_coin = |edit: &dyn Fn(&mut Module)| {
+            let mut module = old_coin.module.clone();
+            edit(&mut module);
+            ModuleInfo {
+                module,
+                bodies: old_coin.bodies.clone(),
+                dependencies: old_coin.dependencies.clone(),
+            }
+        };
+        let check = |new: &ModuleInfo, policy| {
+            let mut violations = Vec::new();
+            check_module(old_coin, new, policy, &mut violations);
+            violations
+        };
+
+        // Changing the layout of a struct is never allowed
+        let new = new_coin(&|module| {
+            let coin = module.structs.get_mut(&ident("Coin")).unwrap();
+            coin.fields.pop();
+        });
+        assert_eq!(
+            check(&new, UpgradePolicy::Compatible),
+            [Violation::DatatypeChanged {
+                module: ident("coin"),
+                name: ident("Coin"),
+                change: DatatypeChange::Layout,
+            }]
+        );
+
+        // Public function signatures must be preserved
+        let new = new_coin(&|module| {
+            let value = module.functions.get_mut(&ident("value")).unwrap();
+            value.return_ = vec![OpenType::U128];
+        });
+        assert_eq!(
+            check(&new, UpgradePolicy::Compatible),
+            [Violation::FunctionChanged {
+                module: ident("coin"),
+                name: ident("value"),
+                change: FunctionChange::Signature,
+            }]
+        );
+
+        // The constraints on type parameters may only be relaxed, and only under the compatible
+        // policy
+        let new = new_coin(&|module| {
+            let value = module.functions.get_mut(&ident("value")).unwrap();
+            value.type_parameters = vec![AbilitySet::EMPTY.with(Ability::Drop)];
+        });
+        assert_eq!(
+            check(&new, UpgradePolicy::Compatible),
+            [Violation::FunctionChanged {
+                module: ident("coin"),
+                name: ident("value"),
+                change: FunctionChange::TypeParameters,
+            }]
+        );
+        let (relaxed, _) = old_coin
+            .module
+            .functions
+            .iter()
+            .find(|(_, function)| {
+                function.visibility == Visibility::Public
+                    && function.type_parameters.iter().any(|c| !c.is_empty())
+            })
+            .unwrap();
+        let new = new_coin(&|module| {
+            let function = module.functions.get_mut(relaxed).unwrap();
+            function.type_parameters.fill(AbilitySet::EMPTY);
+        });
+        assert_eq!(check(&new, UpgradePolicy::Compatible), []);
+        assert_eq!(
+            check(&new, UpgradePolicy::Additive),
+            [Violation::FunctionChanged {
+                module: ident("coin"),
+                name: relaxed.clone(),
+                change: FunctionChange::TypeParameters,
+            }]
+        );
+
+        // Non-public functions may only be removed under the compatible policy
+        let old_balance = &modules[&ident("balance")];
+        let (private, _) = old_balance
+            .module
+            .functions
+            .iter()
+            .find(|(_, function)| function.visibility != Visibility::Public)
+            .unwrap();
+        let mut new = ModuleInfo {
+            module: old_balance.module.clone(),
+            bodies: old_balance.bodies.clone(),
+            dependencies: old_balance.dependencies.clone(),
+        };
+        new.module.functions.remove(private);
+        for (policy, expected) in [
+            (UpgradePolicy::Compatible, vec![]),
+            (
+                UpgradePolicy::Additive,
+                vec![Violation::FunctionRemoved {
+                    module: ident("balance"),
+                    name: private.clone(),
+                }],
+            ),
+        ] {
+            let mut violations = Vec::new();
+            check_module(old_balance, &new, policy, &mut violations);
+            assert_eq!(violations, expected);
+        }
+
+        // Additions are only rejected by the dependency-only policy
+        let new = new_coin(&|module| {
+            let value = module.functions[&ident("value")].clone();
+            module.functions.insert(ident("value2"), value);
+        });
+        assert_eq!(check(&new, UpgradePolicy::Additive), []);
+        assert_eq!(
+            check(&new, UpgradePolicy::DependencyOnly),
+            [Violation::FunctionAdded {
+                module: ident("coin"),
+                name: ident("value2"),
+            }]
+        );
+    }
+
+    #[test]
+    fn changed_body() {
+        let old = sui_framework();
+        let modules = modules(&old).unwrap();
+        let old_coin = &modules[&ident("coin")];
+        let mut bodies = old_coin.bodies.clone();
+        if let Some(Some(body)) = bodies.get_mut(&ident("value")) {
+            body.code.push(Instruction {
+                opcode: 0x01,
+                operand: Operand::None,
+            });
+        }
+        let new = ModuleInfo {
+            module: old_coin.module.clone(),
+            bodies,
+            dependencies: old_coin.dependencies.clone(),
+        };
+
+        let mut violations = Vec::new();
+        check_module(old_coin, &new, UpgradePolicy::Compatible, &mut violations);
+        assert_eq!(violations, []);
+        check_module(old_coin, &new, UpgradePolicy::Additive, &mut violations);
+        assert_eq!(
+            violations,
+            [Violation::FunctionChanged {
+                module: ident("coin"),
+                name: ident("value"),
+                change: FunctionChange::Body,
+            }]
+        );
+    }
+
+    /// Serialize a decoded function body, the inverse of `decode_code`.
+    fn encode_code(
+        instructions: &[RawInstruction],
+        jump_tables: &[RawJumpTable],
+        version: u32,
+    ) -> Vec<u8> {
+        let mut code = uleb128(instructions.len() as u64);
+        for instruction in instructions {
+            code.push(instruction.opcode);
+            match instruction.operand {
+                RawOperand::None => {}
+                RawOperand::Index(index) => code.extend(uleb128(index)),
+                RawOperand::Bytes(bytes) => code.extend(bytes),
+                RawOperand::Vector(signature, count) => {
+                    code.extend(uleb128(signature.into()));
+                    code.extend(count.to_le_bytes());
+                }
+            }
+        }
+        if version < 7 {
+            return code;
+        }
+        code.extend(uleb128(jump_tables.len() as u64));
+        for table in jump_tables {
+            code.extend(uleb128(table.enum_def.into()));
+            code.push(0x1);
+            code.extend(uleb128(table.branches.len() as u64));
+            for branch in &table.branches {
+                code.extend(uleb128((*branch).into()));
+            }
+        }
+        code
+    }
+
+    #[test]
+    fn reordered_tables() {
+        let old = sui_framework();
+        let old_coin = CompiledModule::deserialize(&old.modules[&ident("coin")]).unwrap();
+
+        // Reverse the function handles of the module, as a recompilation might reorder them,
+        // updating every reference to them
+        let mut new_coin = old_coin.clone();
+        let last = (new_coin.function_handles.len() - 1) as TableIndex;
+        new_coin.function_handles.reverse();
+        for instantiation in &mut new_coin.function_instantiations {
+            instantiation.handle = last - instantiation.handle;
+        }
+        for def in &mut new_coin.function_defs {
+            def.function = last - def.function;
+            if let Some(code) = &mut def.code {
+                let (mut instructions, jump_tables) =
+                    decode_code(&code.code, new_coin.version).unwrap();
+                for instruction in &mut instructions {
+                    if let (0x11, RawOperand::Index(index)) =
+                        (instruction.opcode, &mut instruction.operand)
+                    {
+                        *index = u64::from(last) - *index;
+                    }
+                }
+                code.code = encode_code(&instructions, &jump_tables, new_coin.version);
+            }
+        }
+        assert!(old_coin
+            .function_defs
+            .iter()
+            .zip(&new_coin.function_defs)
+            .any(|(old, new)| old.code != new.code));
+
+        let old_info = ModuleInfo::new(&old_coin).unwrap();
+        let new_info = ModuleInfo::new(&new_coin).unwrap();
+        let mut violations = Vec::new();
+        check_module(
+            &old_info,
+            &new_info,
+            UpgradePolicy::DependencyOnly,
+            &mut violations,
+        );
+        assert_eq!(violations, []);
+    }
+}
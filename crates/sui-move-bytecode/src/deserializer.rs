@@ -0,0 +1,797 @@
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Identifier;
+
+use crate::file_format::*;
+use crate::Error;
+
+/// The magic number which starts every compiled Move module.
+const MAGIC: [u8; 4] = [0xA1, 0x1C, 0xEB, 0x0B];
+
+/// The flavor, stored in the most significant byte of the version, of modules compiled for Sui.
+const SUI_FLAVOR: u8 = 0x05;
+
+/// The oldest binary format version which can be deserialized.
+pub const MIN_VERSION: u32 = 5;
+
+/// The newest binary format version which can be deserialized.
+pub const MAX_VERSION: u32 = 7;
+
+/// The maximum nesting depth of a type.
+const MAX_TYPE_DEPTH: usize = 256;
+
+mod table_kind {
+    pub const MODULE_HANDLES: u8 = 0x1;
+    pub const DATATYPE_HANDLES: u8 = 0x2;
+    pub const FUNCTION_HANDLES: u8 = 0x3;
+    pub const FUNCTION_INST: u8 = 0x4;
+    pub const SIGNATURES: u8 = 0x5;
+    pub const CONSTANT_POOL: u8 = 0x6;
+    pub const IDENTIFIERS: u8 = 0x7;
+    pub const ADDRESS_IDENTIFIERS: u8 = 0x8;
+    pub const STRUCT_DEFS: u8 = 0xA;
+    pub const STRUCT_DEF_INST: u8 = 0xB;
+    pub const FUNCTION_DEFS: u8 = 0xC;
+    pub const FIELD_HANDLES: u8 = 0xD;
+    pub const FIELD_INST: u8 = 0xE;
+    pub const FRIEND_DECLS: u8 = 0xF;
+    pub const METADATA: u8 = 0x10;
+    pub const ENUM_DEFS: u8 = 0x11;
+    pub const ENUM_DEF_INST: u8 = 0x12;
+    pub const VARIANT_HANDLES: u8 = 0x13;
+    pub const VARIANT_INST_HANDLES: u8 = 0x14;
+}
+
+mod token {
+    pub const BOOL: u8 = 0x1;
+    pub const U8: u8 = 0x2;
+    pub const U64: u8 = 0x3;
+    pub const U128: u8 = 0x4;
+    pub const ADDRESS: u8 = 0x5;
+    pub const REFERENCE: u8 = 0x6;
+    pub const MUTABLE_REFERENCE: u8 = 0x7;
+    pub const DATATYPE: u8 = 0x8;
+    pub const TYPE_PARAMETER: u8 = 0x9;
+    pub const VECTOR: u8 = 0xA;
+    pub const DATATYPE_INST: u8 = 0xB;
+    pub const SIGNER: u8 = 0xC;
+    pub const U16: u8 = 0xD;
+    pub const U32: u8 = 0xE;
+    pub const U256: u8 = 0xF;
+}
+
+const NATIVE_STRUCT: u8 = 0x1;
+const DECLARED_STRUCT: u8 = 0x2;
+const DECLARED_ENUM: u8 = 0x2;
+
+const FUNCTION_NATIVE: u8 = 0x2;
+const FUNCTION_ENTRY: u8 = 0x4;
+
+const JUMP_TABLE_FULL: u8 = 0x1;
+
+impl CompiledModule {
+    /// Deserialize a module from the Move binary format.
+    ///
+    /// Along with checking that the binary is well-formed, this checks that every index within
+    /// the module refers to an entry in the appropriate table, but performs none of the further
+    /// checks done by the Move bytecode verifier.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        let mut cursor = Cursor::new(bytes);
+
+        if cursor.read_bytes(MAGIC.len())? != MAGIC {
+            return Err(Error::BadMagic);
+        }
+
+        let version = u32::from_le_bytes(cursor.read_array()?);
+        let flavor = (version >> 24) as u8;
+        let version = version & 0x00FF_FFFF;
+        if !(MIN_VERSION..=MAX_VERSION).contains(&version) {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        if flavor != 0 && flavor != SUI_FLAVOR {
+            return Err(malformed(format!("unknown binary flavor {flavor}")));
+        }
+
+        let table_count = cursor.read_uleb128(u8::MAX.into())?;
+        let mut tables = Vec::new();
+        for _ in 0..table_count {
+            let kind = cursor.read_u8()?;
+            let offset = cursor.read_uleb128(u32::MAX.into())? as usize;
+            let length = cursor.read_uleb128(u32::MAX.into())? as usize;
+            if tables.iter().any(|(k, _, _)| *k == kind) {
+                return Err(malformed(format!("duplicate table {kind:#x}")));
+            }
+            tables.push((kind, offset, length));
+        }
+
+        // Tables must be laid out back to back, without gaps, following the table headers
+        tables.sort_by_key(|(_, offset, _)| *offset);
+        let mut data_length = 0;
+        for (kind, offset, length) in &tables {
+            if *offset != data_length {
+                return Err(malformed(format!("table {kind:#x} is not contiguous")));
+            }
+            data_length = data_length
+                .checked_add(*length)
+                .ok_or_else(|| malformed("table lengths overflow"))?;
+        }
+        let data = cursor.read_bytes(data_length)?;
+
+        let mut module = CompiledModule {
+            version,
+            self_module_handle_idx: cursor.read_index()?,
+            ..Default::default()
+        };
+        if !cursor.is_empty() {
+            return Err(malformed("trailing bytes after module"));
+        }
+
+        for (kind, offset, length) in tables {
+            let mut table = Cursor::new(&data[offset..offset + length]);
+            let table = &mut table;
+            match kind {
+                table_kind::MODULE_HANDLES => {
+                    module.module_handles = read_entries(table, read_module_handle)?
+                }
+                table_kind::DATATYPE_HANDLES => {
+                    module.datatype_handles = read_entries(table, read_datatype_handle)?
+                }
+                table_kind::FUNCTION_HANDLES => {
+                    module.function_handles = read_entries(table, read_function_handle)?
+                }
+                table_kind::FUNCTION_INST => {
+                    module.function_instantiations = read_entries(table, |cursor| {
+                        Ok(FunctionInstantiation {
+                            handle: cursor.read_index()?,
+                            type_parameters: cursor.read_index()?,
+                        })
+                    })?
+                }
+                table_kind::SIGNATURES => {
+                    module.signatures = read_entries(table, read_signature)?;
+                }
+                table_kind::CONSTANT_POOL => {
+                    module.constant_pool = read_entries(table, |cursor| {
+                        Ok(Constant {
+                            type_: read_signature_token(cursor, 0)?,
+                            data: cursor.read_byte_blob()?,
+                        })
+                    })?
+                }
+                table_kind::IDENTIFIERS => {
+                    module.identifiers = read_entries(table, read_identifier)?;
+                }
+                table_kind::ADDRESS_IDENTIFIERS => {
+                    module.address_identifiers =
+                        read_entries(table, |cursor| cursor.read_array().map(Address::new))?
+                }
+                table_kind::STRUCT_DEFS => {
+                    module.struct_defs = read_entries(table, read_struct_definition)?;
+                }
+                table_kind::STRUCT_DEF_INST => {
+                    module.struct_def_instantiations = read_entries(table, |cursor| {
+                        Ok(StructDefInstantiation {
+                            def: cursor.read_index()?,
+                            type_parameters: cursor.read_index()?,
+                        })
+                    })?
+                }
+                table_kind::FUNCTION_DEFS => {
+                    module.function_defs =
+                        read_entries(table, |cursor| read_function_definition(cursor, version))?
+                }
+                table_kind::FIELD_HANDLES => {
+                    module.field_handles = read_entries(table, |cursor| {
+                        Ok(FieldHandle {
+                            owner: cursor.read_index()?,
+                            field: cursor.read_index()?,
+                        })
+                    })?
+                }
+                table_kind::FIELD_INST => {
+                    module.field_instantiations = read_entries(table, |cursor| {
+                        Ok(FieldInstantiation {
+                            handle: cursor.read_index()?,
+                            type_parameters: cursor.read_index()?,
+                        })
+                    })?
+                }
+                table_kind::FRIEND_DECLS => {
+                    module.friend_decls = read_entries(table, read_module_handle)?;
+                }
+                table_kind::METADATA => {
+                    module.metadata = read_entries(table, |cursor| {
+                        Ok(Metadata {
+                            key: cursor.read_byte_blob()?,
+                            value: cursor.read_byte_blob()?,
+                        })
+                    })?
+                }
+                table_kind::ENUM_DEFS if version >= 7 => {
+                    module.enum_defs = read_entries(table, read_enum_definition)?;
+                }
+                table_kind::ENUM_DEF_INST if version >= 7 => {
+                    module.enum_def_instantiations = read_entries(table, |cursor| {
+                        Ok(EnumDefInstantiation {
+                            def: cursor.read_index()?,
+                            type_parameters: cursor.read_index()?,
+                        })
+                    })?
+                }
+                table_kind::VARIANT_HANDLES if version >= 7 => {
+                    module.variant_handles = read_entries(table, |cursor| {
+                        Ok(VariantHandle {
+                            enum_def: cursor.read_index()?,
+                            variant: cursor.read_index()?,
+                        })
+                    })?
+                }
+                table_kind::VARIANT_INST_HANDLES if version >= 7 => {
+                    module.variant_instantiation_handles = read_entries(table, |cursor| {
+                        Ok(VariantInstantiationHandle {
+                            enum_def: cursor.read_index()?,
+                            variant: cursor.read_index()?,
+                        })
+                    })?
+                }
+                kind => return Err(malformed(format!("unknown table {kind:#x}"))),
+            }
+        }
+
+        check_bounds(&module)?;
+        Ok(module)
+    }
+}
+
+fn malformed<T: Into<String>>(message: T) -> Error {
+    Error::Malformed(message.into())
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .bytes
+            .get(self.position..)
+            .and_then(|remaining| remaining.get(..length))
+            .ok_or(Error::UnexpectedEof)?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        self.read_array::<1>().map(|[byte]| byte)
+    }
+
+    /// Read a canonically encoded ULEB128 value which is at most `max`.
+    fn read_uleb128(&mut self, max: u64) -> Result<u64, Error> {
+        let mut value: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            let digit = u64::from(byte & 0x7f);
+            if shift > 63 || (shift == 63 && digit > 1) {
+                return Err(malformed("ULEB128 value overflows u64"));
+            }
+            value |= digit << shift;
+
+            if byte & 0x80 == 0 {
+                if shift > 0 && digit == 0 {
+                    return Err(malformed("ULEB128 value is not canonically encoded"));
+                }
+                break;
+            }
+            shift += 7;
+        }
+
+        if value > max {
+            return Err(malformed(format!("value {value} exceeds maximum of {max}")));
+        }
+        Ok(value)
+    }
+
+    fn read_index(&mut self) -> Result<TableIndex, Error> {
+        self.read_uleb128(TableIndex::MAX.into())
+            .map(|index| index as TableIndex)
+    }
+
+    fn read_count(&mut self) -> Result<usize, Error> {
+        self.read_uleb128(u32::MAX.into())
+            .map(|count| count as usize)
+    }
+
+    fn read_byte_blob(&mut self) -> Result<Vec<u8>, Error> {
+        let length = self.read_count()?;
+        self.read_bytes(length).map(<[u8]>::to_vec)
+    }
+
+    fn read_ability_set(&mut self) -> Result<AbilitySet, Error> {
+        let byte = self.read_uleb128(AbilitySet::ALL.into_u8().into())?;
+        Ok(AbilitySet::from_u8(byte as u8).unwrap())
+    }
+}
+
+fn read_entries<'a, T, F>(cursor: &mut Cursor<'a>, mut read: F) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&mut Cursor<'a>) -> Result<T, Error>,
+{
+    let mut entries = Vec::new();
+    while !cursor.is_empty() {
+        entries.push(read(cursor)?);
+    }
+    Ok(entries)
+}
+
+fn read_identifier(cursor: &mut Cursor) -> Result<Identifier, Error> {
+    let bytes = cursor.read_byte_blob()?;
+    let identifier =
+        String::from_utf8(bytes).map_err(|_| malformed("identifier is not valid UTF-8"))?;
+    Identifier::new(&identifier).map_err(|_| malformed(format!("invalid identifier {identifier}")))
+}
+
+fn read_module_handle(cursor: &mut Cursor) -> Result<ModuleHandle, Error> {
+    Ok(ModuleHandle {
+        address: cursor.read_index()?,
+        name: cursor.read_index()?,
+    })
+}
+
+fn read_datatype_handle(cursor: &mut Cursor) -> Result<DatatypeHandle, Error> {
+    let module = cursor.read_index()?;
+    let name = cursor.read_index()?;
+    let abilities = cursor.read_ability_set()?;
+    let count = cursor.read_count()?;
+    let mut type_parameters = Vec::new();
+    for _ in 0..count {
+        let constraints = cursor.read_ability_set()?;
+        let is_phantom = match cursor.read_u8()? {
+            0 => false,
+            1 => true,
+            byte => return Err(malformed(format!("invalid phantom flag {byte}"))),
+        };
+        type_parameters.push(DatatypeTypeParameter {
+            constraints,
+            is_phantom,
+        });
+    }
+
+    Ok(DatatypeHandle {
+        module,
+        name,
+        abilities,
+        type_parameters,
+    })
+}
+
+fn read_function_handle(cursor: &mut Cursor) -> Result<FunctionHandle, Error> {
+    let module = cursor.read_index()?;
+    let name = cursor.read_index()?;
+    let parameters = cursor.read_index()?;
+    let return_ = cursor.read_index()?;
+    let count = cursor.read_count()?;
+    let type_parameters = (0..count)
+        .map(|_| cursor.read_ability_set())
+        .collect::<Result<_, _>>()?;
+
+    Ok(FunctionHandle {
+        module,
+        name,
+        parameters,
+        return_,
+        type_parameters,
+    })
+}
+
+fn read_signature(cursor: &mut Cursor) -> Result<Signature, Error> {
+    let count = cursor.read_count()?;
+    (0..count)
+        .map(|_| read_signature_token(cursor, 0))
+        .collect::<Result<_, _>>()
+        .map(Signature)
+}
+
+fn read_signature_token(cursor: &mut Cursor, depth: usize) -> Result<SignatureToken, Error> {
+    if depth > MAX_TYPE_DEPTH {
+        return Err(malformed("type exceeds maximum depth"));
+    }
+
+    let token = match cursor.read_u8()? {
+        token::BOOL => SignatureToken::Bool,
+        token::U8 => SignatureToken::U8,
+        token::U16 => SignatureToken::U16,
+        token::U32 => SignatureToken::U32,
+        token::U64 => SignatureToken::U64,
+        token::U128 => SignatureToken::U128,
+        token::U256 => SignatureToken::U256,
+        token::ADDRESS => SignatureToken::Address,
+        token::SIGNER => SignatureToken::Signer,
+        token::VECTOR => SignatureToken::Vector(Box::new(read_signature_token(cursor, depth + 1)?)),
+        token::REFERENCE => {
+            SignatureToken::Reference(Box::new(read_signature_token(cursor, depth + 1)?))
+        }
+        token::MUTABLE_REFERENCE => {
+            SignatureToken::MutableReference(Box::new(read_signature_token(cursor, depth + 1)?))
+        }
+        token::DATATYPE => SignatureToken::Datatype(cursor.read_index()?),
+        token::DATATYPE_INST => {
+            let handle = cursor.read_index()?;
+            let arity = cursor.read_count()?;
+            if arity == 0 {
+                return Err(malformed("datatype instantiation without type arguments"));
+            }
+            let type_arguments = (0..arity)
+                .map(|_| read_signature_token(cursor, depth + 1))
+                .collect::<Result<_, _>>()?;
+            SignatureToken::DatatypeInstantiation(handle, type_arguments)
+        }
+        token::TYPE_PARAMETER => SignatureToken::TypeParameter(cursor.read_index()?),
+        token => return Err(malformed(format!("unknown type {token:#x}"))),
+    };
+    Ok(token)
+}
+
+fn read_fields(cursor: &mut Cursor) -> Result<Vec<FieldDefinition>, Error> {
+    let count = cursor.read_count()?;
+    (0..count)
+        .map(|_| {
+            Ok(FieldDefinition {
+                name: cursor.read_index()?,
+                signature: read_signature_token(cursor, 0)?,
+            })
+        })
+        .collect()
+}
+
+fn read_struct_definition(cursor: &mut Cursor) -> Result<StructDefinition, Error> {
+    let struct_handle = cursor.read_index()?;
+    let fields = match cursor.read_u8()? {
+        NATIVE_STRUCT => None,
+        DECLARED_STRUCT => Some(read_fields(cursor)?),
+        flag => return Err(malformed(format!("unknown struct flag {flag:#x}"))),
+    };
+
+    Ok(StructDefinition {
+        struct_handle,
+        fields,
+    })
+}
+
+fn read_enum_definition(cursor: &mut Cursor) -> Result<EnumDefinition, Error> {
+    let enum_handle = cursor.read_index()?;
+    match cursor.read_u8()? {
+        DECLARED_ENUM => {}
+        flag => return Err(malformed(format!("unknown enum flag {flag:#x}"))),
+    }
+    let count = cursor.read_count()?;
+    let variants = (0..count)
+        .map(|_| {
+            Ok(VariantDefinition {
+                variant_name: cursor.read_index()?,
+                fields: read_fields(cursor)?,
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(EnumDefinition {
+        enum_handle,
+        variants,
+    })
+}
+
+fn read_function_definition(
+    cursor: &mut Cursor,
+    version: u32,
+) -> Result<FunctionDefinition, Error> {
+    let function = cursor.read_index()?;
+    let visibility = match cursor.read_u8()? {
+        0x0 => Visibility::Private,
+        0x1 => Visibility::Public,
+        0x3 => Visibility::Friend,
+        visibility => return Err(malformed(format!("unknown visibility {visibility:#x}"))),
+    };
+    let flags = cursor.read_u8()?;
+    if flags & !(FUNCTION_NATIVE | FUNCTION_ENTRY) != 0 {
+        return Err(malformed(format!("unknown function flags {flags:#x}")));
+    }
+    let count = cursor.read_count()?;
+    let acquires_global_resources = (0..count)
+        .map(|_| cursor.read_index())
+        .collect::<Result<_, _>>()?;
+
+    let code = if flags & FUNCTION_NATIVE == 0 {
+        let locals = cursor.read_index()?;
+        let start = cursor.position;
+        skip_code(cursor, version)?;
+        Some(CodeUnit {
+            locals,
+            code: cursor.bytes[start..cursor.position].to_vec(),
+        })
+    } else {
+        None
+    };
+
+    Ok(FunctionDefinition {
+        function,
+        visibility,
+        is_entry: flags & FUNCTION_ENTRY != 0,
+        acquires_global_resources,
+        code,
+    })
+}
+
+/// An instruction within a function body whose operand has not been resolved against the tables
+/// of its module.
+pub(crate) struct RawInstruction<'a> {
+    pub opcode: u8,
+    pub operand: RawOperand<'a>,
+}
+
+pub(crate) enum RawOperand<'a> {
+    None,
+    /// A branch target, jump table index or table index.
+    Index(u64),
+    /// A local index or an integer constant, in little-endian order.
+    Bytes(&'a [u8]),
+    /// The signature index and element count of a vector pack or unpack.
+    Vector(TableIndex, u64),
+}
+
+/// The branch targets of a `VariantSwitch` over the variants of `enum_def`.
+pub(crate) struct RawJumpTable {
+    pub enum_def: EnumDefinitionIndex,
+    pub branches: Vec<TableIndex>,
+}
+
+/// Decode the instructions, and any jump tables, of a function body serialized with binary format
+/// `version`.
+///
+/// Only the structure of the body is checked, operands referring to the tables of the module are
+/// not checked to be in bounds.
+pub(crate) fn decode_code(
+    code: &[u8],
+    version: u32,
+) -> Result<(Vec<RawInstruction<'_>>, Vec<RawJumpTable>), Error> {
+    let mut cursor = Cursor::new(code);
+    let decoded = read_code(&mut cursor, version)?;
+    if !cursor.is_empty() {
+        return Err(malformed("trailing bytes after function body"));
+    }
+    Ok(decoded)
+}
+
+/// Advance past the instructions, and any jump tables, of a function body.
+fn skip_code(cursor: &mut Cursor, version: u32) -> Result<(), Error> {
+    read_code(cursor, version).map(|_| ())
+}
+
+fn read_code<'a>(
+    cursor: &mut Cursor<'a>,
+    version: u32,
+) -> Result<(Vec<RawInstruction<'a>>, Vec<RawJumpTable>), Error> {
+    let count = cursor.read_count()?;
+    let mut instructions = Vec::new();
+    for _ in 0..count {
+        let opcode = cursor.read_u8()?;
+        let operand = match opcode {
+            // Instructions without operands
+            0x01
+            | 0x02
+            | 0x08
+            | 0x09
+            | 0x14..=0x28
+            | 0x2E
+            | 0x2F
+            | 0x30
+            | 0x33..=0x35
+            | 0x4B..=0x4D => RawOperand::None,
+            // Instructions with a single ULEB128 operand, e.g. a branch target or table index
+            0x03..=0x05
+            | 0x07
+            | 0x0F..=0x13
+            | 0x29..=0x2D
+            | 0x36..=0x3F
+            | 0x41..=0x45
+            | 0x47
+            | 0x4E..=0x56 => RawOperand::Index(cursor.read_uleb128(u64::MAX)?),
+            // Instructions with a local index or u8 constant
+            0x0A..=0x0E | 0x31 => RawOperand::Bytes(cursor.read_bytes(1)?),
+            // Instructions with an integer constant
+            0x48 => RawOperand::Bytes(cursor.read_bytes(2)?),
+            0x49 => RawOperand::Bytes(cursor.read_bytes(4)?),
+            0x06 => RawOperand::Bytes(cursor.read_bytes(8)?),
+            0x32 => RawOperand::Bytes(cursor.read_bytes(16)?),
+            0x4A => RawOperand::Bytes(cursor.read_bytes(32)?),
+            // Vector pack and unpack, with a signature index and element count
+            0x40 | 0x46 => {
+                let signature = cursor.read_index()?;
+                let count = u64::from_le_bytes(cursor.read_array()?);
+                RawOperand::Vector(signature, count)
+            }
+            opcode => return Err(malformed(format!("unknown opcode {opcode:#x}"))),
+        };
+        instructions.push(RawInstruction { opcode, operand });
+    }
+
+    let mut jump_tables = Vec::new();
+    if version >= 7 {
+        let count = cursor.read_count()?;
+        for _ in 0..count {
+            let enum_def = cursor.read_index()?;
+            match cursor.read_u8()? {
+                JUMP_TABLE_FULL => {
+                    let branches = cursor.read_count()?;
+                    let branches = (0..branches)
+                        .map(|_| cursor.read_index())
+                        .collect::<Result<_, _>>()?;
+                    jump_tables.push(RawJumpTable { enum_def, branches });
+                }
+                flag => return Err(malformed(format!("unknown jump table flag {flag:#x}"))),
+            }
+        }
+    }
+
+    Ok((instructions, jump_tables))
+}
+
+fn check_index(table: &'static str, index: TableIndex, len: usize) -> Result<(), Error> {
+    if usize::from(index) < len {
+        Ok(())
+    } else {
+        Err(Error::IndexOutOfBounds {
+            table,
+            index: index.into(),
+            len,
+        })
+    }
+}
+
+/// Check that every index within the module refers to an entry within the appropriate table.
+fn check_bounds(module: &CompiledModule) -> Result<(), Error> {
+    let identifier = |index| check_index("identifiers", index, module.identifiers.len());
+    let address = |index| {
+        check_index(
+            "address identifiers",
+            index,
+            module.address_identifiers.len(),
+        )
+    };
+    let module_handle = |index| check_index("module handles", index, module.module_handles.len());
+    let datatype_handle =
+        |index| check_index("datatype handles", index, module.datatype_handles.len());
+    let function_handle =
+        |index| check_index("function handles", index, module.function_handles.len());
+    let signature = |index| check_index("signatures", index, module.signatures.len());
+    let struct_def = |index| check_index("struct definitions", index, module.struct_defs.len());
+    let enum_def = |index| check_index("enum definitions", index, module.enum_defs.len());
+
+    fn check_token(
+        token: &SignatureToken,
+        datatype_handle: &impl Fn(TableIndex) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        match token {
+            SignatureToken::Vector(inner)
+            | SignatureToken::Reference(inner)
+            | SignatureToken::MutableReference(inner) => check_token(inner, datatype_handle),
+            SignatureToken::Datatype(index) => datatype_handle(*index),
+            SignatureToken::DatatypeInstantiation(index, type_arguments) => {
+                datatype_handle(*index)?;
+                type_arguments
+                    .iter()
+                    .try_for_each(|argument| check_token(argument, datatype_handle))
+            }
+            _ => Ok(()),
+        }
+    }
+    let fields = |fields: &[FieldDefinition]| {
+        fields.iter().try_for_each(|field| {
+            identifier(field.name)?;
+            check_token(&field.signature, &datatype_handle)
+        })
+    };
+
+    module_handle(module.self_module_handle_idx)?;
+    for handle in module.module_handles.iter().chain(&module.friend_decls) {
+        address(handle.address)?;
+        identifier(handle.name)?;
+    }
+    for handle in &module.datatype_handles {
+        module_handle(handle.module)?;
+        identifier(handle.name)?;
+    }
+    for handle in &module.function_handles {
+        module_handle(handle.module)?;
+        identifier(handle.name)?;
+        signature(handle.parameters)?;
+        signature(handle.return_)?;
+    }
+    for handle in &module.field_handles {
+        struct_def(handle.owner)?;
+        let field_count = module.struct_defs[handle.owner as usize]
+            .fields
+            .as_ref()
+            .map_or(0, Vec::len);
+        check_index("fields", handle.field, field_count)?;
+    }
+    for instantiation in &module.struct_def_instantiations {
+        struct_def(instantiation.def)?;
+        signature(instantiation.type_parameters)?;
+    }
+    for instantiation in &module.function_instantiations {
+        function_handle(instantiation.handle)?;
+        signature(instantiation.type_parameters)?;
+    }
+    for instantiation in &module.field_instantiations {
+        check_index(
+            "field handles",
+            instantiation.handle,
+            module.field_handles.len(),
+        )?;
+        signature(instantiation.type_parameters)?;
+    }
+    for instantiation in &module.enum_def_instantiations {
+        enum_def(instantiation.def)?;
+        signature(instantiation.type_parameters)?;
+    }
+    for handle in &module.variant_handles {
+        enum_def(handle.enum_def)?;
+        let variant_count = module.enum_defs[handle.enum_def as usize].variants.len();
+        check_index("variants", handle.variant, variant_count)?;
+    }
+    for handle in &module.variant_instantiation_handles {
+        check_index(
+            "enum definition instantiations",
+            handle.enum_def,
+            module.enum_def_instantiations.len(),
+        )?;
+        let def = module.enum_def_instantiations[handle.enum_def as usize].def;
+        let variant_count = module.enum_defs[def as usize].variants.len();
+        check_index("variants", handle.variant, variant_count)?;
+    }
+    for Signature(tokens) in &module.signatures {
+        tokens
+            .iter()
+            .try_for_each(|t| check_token(t, &datatype_handle))?;
+    }
+    for constant in &module.constant_pool {
+        check_token(&constant.type_, &datatype_handle)?;
+    }
+    for def in &module.struct_defs {
+        datatype_handle(def.struct_handle)?;
+        if let Some(struct_fields) = &def.fields {
+            fields(struct_fields)?;
+        }
+    }
+    for def in &module.enum_defs {
+        datatype_handle(def.enum_handle)?;
+        for variant in &def.variants {
+            identifier(variant.variant_name)?;
+            fields(&variant.fields)?;
+        }
+    }
+    for def in &module.function_defs {
+        function_handle(def.function)?;
+        def.acquires_global_resources
+            .iter()
+            .try_for_each(|index| struct_def(*index))?;
+        if let Some(code) = &def.code {
+            signature(code.locals)?;
+        }
+    }
+
+    Ok(())
+}
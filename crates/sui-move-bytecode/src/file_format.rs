@@ -0,0 +1,382 @@
+//! The in-memory representation of a compiled Move module.
+//!
+//! The types in this module mirror the tables of the Move binary format. Entities reference one
+//! another by their index into the table holding them, e.g. a [`DatatypeHandle`] refers to the
+//! module declaring it by a [`ModuleHandleIndex`] into [`CompiledModule::module_handles`].
+//! [`CompiledModule::deserialize`] checks that every index is in bounds, so indexing the tables
+//! of a deserialized module directly will not panic.
+
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Identifier;
+
+/// An index into one of the tables of a [`CompiledModule`].
+pub type TableIndex = u16;
+
+pub type ModuleHandleIndex = TableIndex;
+pub type DatatypeHandleIndex = TableIndex;
+pub type FunctionHandleIndex = TableIndex;
+pub type FieldHandleIndex = TableIndex;
+pub type StructDefinitionIndex = TableIndex;
+pub type StructDefInstantiationIndex = TableIndex;
+pub type EnumDefinitionIndex = TableIndex;
+pub type EnumDefInstantiationIndex = TableIndex;
+pub type FunctionInstantiationIndex = TableIndex;
+pub type FieldInstantiationIndex = TableIndex;
+pub type SignatureIndex = TableIndex;
+pub type IdentifierIndex = TableIndex;
+pub type AddressIdentifierIndex = TableIndex;
+pub type ConstantPoolIndex = TableIndex;
+
+/// The index of a type parameter within the type parameters of a datatype or function.
+pub type TypeParameterIndex = u16;
+
+/// The position of a variant within an enum.
+pub type VariantTag = u16;
+
+/// A compiled Move module.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompiledModule {
+    /// The version of the binary format the module was serialized with.
+    pub version: u32,
+    /// The handle of this module within `module_handles`.
+    pub self_module_handle_idx: ModuleHandleIndex,
+    /// Handles to this module and all of the modules it depends on.
+    pub module_handles: Vec<ModuleHandle>,
+    /// Handles to the structs and enums declared by this module and its dependencies.
+    pub datatype_handles: Vec<DatatypeHandle>,
+    /// Handles to the functions declared by this module and its dependencies.
+    pub function_handles: Vec<FunctionHandle>,
+    /// Handles to the fields of the structs declared by this module.
+    pub field_handles: Vec<FieldHandle>,
+    /// The modules which are friends of this module.
+    pub friend_decls: Vec<ModuleHandle>,
+    pub struct_def_instantiations: Vec<StructDefInstantiation>,
+    pub function_instantiations: Vec<FunctionInstantiation>,
+    pub field_instantiations: Vec<FieldInstantiation>,
+    /// Lists of types, used for function parameters and return values, type arguments and locals.
+    pub signatures: Vec<Signature>,
+    pub identifiers: Vec<Identifier>,
+    pub address_identifiers: Vec<Address>,
+    pub constant_pool: Vec<Constant>,
+    pub metadata: Vec<Metadata>,
+    /// The structs declared by this module.
+    pub struct_defs: Vec<StructDefinition>,
+    /// The functions declared by this module.
+    pub function_defs: Vec<FunctionDefinition>,
+    /// The enums declared by this module.
+    pub enum_defs: Vec<EnumDefinition>,
+    pub enum_def_instantiations: Vec<EnumDefInstantiation>,
+    pub variant_handles: Vec<VariantHandle>,
+    pub variant_instantiation_handles: Vec<VariantInstantiationHandle>,
+}
+
+impl CompiledModule {
+    /// The handle of this module.
+    pub fn self_handle(&self) -> &ModuleHandle {
+        &self.module_handles[self.self_module_handle_idx as usize]
+    }
+
+    /// The address this module is published at.
+    pub fn address(&self) -> Address {
+        self.address_identifier_at(self.self_handle().address)
+    }
+
+    /// The name of this module.
+    pub fn name(&self) -> &Identifier {
+        self.identifier_at(self.self_handle().name)
+    }
+
+    pub fn identifier_at(&self, idx: IdentifierIndex) -> &Identifier {
+        &self.identifiers[idx as usize]
+    }
+
+    pub fn address_identifier_at(&self, idx: AddressIdentifierIndex) -> Address {
+        self.address_identifiers[idx as usize]
+    }
+
+    pub fn signature_at(&self, idx: SignatureIndex) -> &Signature {
+        &self.signatures[idx as usize]
+    }
+
+    pub fn module_handle_at(&self, idx: ModuleHandleIndex) -> &ModuleHandle {
+        &self.module_handles[idx as usize]
+    }
+
+    pub fn datatype_handle_at(&self, idx: DatatypeHandleIndex) -> &DatatypeHandle {
+        &self.datatype_handles[idx as usize]
+    }
+
+    pub fn function_handle_at(&self, idx: FunctionHandleIndex) -> &FunctionHandle {
+        &self.function_handles[idx as usize]
+    }
+}
+
+/// A reference to a module, by address and name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleHandle {
+    pub address: AddressIdentifierIndex,
+    pub name: IdentifierIndex,
+}
+
+/// A reference to a struct or enum declared by a module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatatypeHandle {
+    pub module: ModuleHandleIndex,
+    pub name: IdentifierIndex,
+    pub abilities: AbilitySet,
+    pub type_parameters: Vec<DatatypeTypeParameter>,
+}
+
+/// A type parameter of a struct or enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DatatypeTypeParameter {
+    /// The abilities that any type argument must have.
+    pub constraints: AbilitySet,
+    /// Whether the type parameter is only used in phantom positions.
+    pub is_phantom: bool,
+}
+
+/// A reference to a function declared by a module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionHandle {
+    pub module: ModuleHandleIndex,
+    pub name: IdentifierIndex,
+    pub parameters: SignatureIndex,
+    pub return_: SignatureIndex,
+    /// The constraints on each of the function's type parameters.
+    pub type_parameters: Vec<AbilitySet>,
+}
+
+/// A reference to a field of a struct declared by this module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldHandle {
+    pub owner: StructDefinitionIndex,
+    pub field: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructDefInstantiation {
+    pub def: StructDefinitionIndex,
+    pub type_parameters: SignatureIndex,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionInstantiation {
+    pub handle: FunctionHandleIndex,
+    pub type_parameters: SignatureIndex,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInstantiation {
+    pub handle: FieldHandleIndex,
+    pub type_parameters: SignatureIndex,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumDefInstantiation {
+    pub def: EnumDefinitionIndex,
+    pub type_parameters: SignatureIndex,
+}
+
+/// A reference to a variant of an enum declared by this module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantHandle {
+    pub enum_def: EnumDefinitionIndex,
+    pub variant: VariantTag,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantInstantiationHandle {
+    pub enum_def: EnumDefInstantiationIndex,
+    pub variant: VariantTag,
+}
+
+/// A list of types.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Signature(pub Vec<SignatureToken>);
+
+/// A type as it appears in a compiled module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureToken {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<SignatureToken>),
+    Datatype(DatatypeHandleIndex),
+    DatatypeInstantiation(DatatypeHandleIndex, Vec<SignatureToken>),
+    Reference(Box<SignatureToken>),
+    MutableReference(Box<SignatureToken>),
+    TypeParameter(TypeParameterIndex),
+}
+
+/// A constant value, serialized using BCS.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constant {
+    pub type_: SignatureToken,
+    pub data: Vec<u8>,
+}
+
+/// An arbitrary key-value pair attached to a module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+/// A struct declared by this module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructDefinition {
+    pub struct_handle: DatatypeHandleIndex,
+    /// The struct's fields, or `None` for a native struct.
+    pub fields: Option<Vec<FieldDefinition>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDefinition {
+    pub name: IdentifierIndex,
+    pub signature: SignatureToken,
+}
+
+/// An enum declared by this module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumDefinition {
+    pub enum_handle: DatatypeHandleIndex,
+    pub variants: Vec<VariantDefinition>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantDefinition {
+    pub variant_name: IdentifierIndex,
+    pub fields: Vec<FieldDefinition>,
+}
+
+/// A function declared by this module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionDefinition {
+    pub function: FunctionHandleIndex,
+    pub visibility: Visibility,
+    pub is_entry: bool,
+    pub acquires_global_resources: Vec<StructDefinitionIndex>,
+    /// The function's body, or `None` for a native function.
+    pub code: Option<CodeUnit>,
+}
+
+/// The body of a function.
+///
+/// Instructions are validated while deserializing but are not decoded, `code` holds the
+/// function's serialized instructions along with, for binary format version 7 and above, its
+/// serialized jump tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeUnit {
+    pub locals: SignatureIndex,
+    pub code: Vec<u8>,
+}
+
+/// The visibility of a function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Visibility {
+    /// Callable only from within its own module.
+    Private,
+    /// Callable from any module.
+    Public,
+    /// Callable from its own module and that module's friends.
+    Friend,
+}
+
+/// An ability of a Move type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ability {
+    Copy,
+    Drop,
+    Store,
+    Key,
+}
+
+impl Ability {
+    const ALL: [Ability; 4] = [Ability::Copy, Ability::Drop, Ability::Store, Ability::Key];
+
+    fn bit(self) -> u8 {
+        match self {
+            Ability::Copy => 0x1,
+            Ability::Drop => 0x2,
+            Ability::Store => 0x4,
+            Ability::Key => 0x8,
+        }
+    }
+}
+
+impl std::fmt::Display for Ability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ability = match self {
+            Ability::Copy => "copy",
+            Ability::Drop => "drop",
+            Ability::Store => "store",
+            Ability::Key => "key",
+        };
+        f.write_str(ability)
+    }
+}
+
+/// A set of [`Ability`]s.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AbilitySet(u8);
+
+impl AbilitySet {
+    /// The empty set of abilities.
+    pub const EMPTY: Self = Self(0);
+
+    /// The set of all abilities.
+    pub const ALL: Self = Self(0xf);
+
+    /// Construct an `AbilitySet` from its serialized representation, returning `None` if any
+    /// unknown bits are set.
+    pub fn from_u8(byte: u8) -> Option<Self> {
+        (byte & !Self::ALL.0 == 0).then_some(Self(byte))
+    }
+
+    pub fn into_u8(self) -> u8 {
+        self.0
+    }
+
+    pub fn has(self, ability: Ability) -> bool {
+        self.0 & ability.bit() != 0
+    }
+
+    pub fn with(self, ability: Ability) -> Self {
+        Self(self.0 | ability.bit())
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if every ability in `self` is also in `other`.
+    pub fn is_subset_of(self, other: Self) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Ability> {
+        Ability::ALL
+            .into_iter()
+            .filter(move |ability| self.has(*ability))
+    }
+}
+
+impl FromIterator<Ability> for AbilitySet {
+    fn from_iter<T: IntoIterator<Item = Ability>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(Self::EMPTY, |set, ability| set.with(ability))
+    }
+}
+
+impl std::fmt::Debug for AbilitySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,196 @@
+//! Offline parsing and inspection of compiled Move modules.
+//!
+//! The modules of a [`MovePackage`](sui_sdk_types::types::MovePackage) are stored in the Move
+//! binary format. [`CompiledModule::deserialize`] parses a module into its constituent tables of
+//! handles, signatures, constants and struct, enum and function definitions, and
+//! [`normalized::Module`] provides a self-contained view of the declarations in a module,
+//! equivalent to the normalized Move types exposed by the GraphQL RPC, allowing a package's
+//! interface to be inspected without any network access. Building on these,
+//! [`compatibility::check_upgrade`] checks that a package upgrade satisfies an upgrade policy.
+
+pub mod compatibility;
+mod deserializer;
+pub mod file_format;
+pub mod normalized;
+
+pub use deserializer::MAX_VERSION;
+pub use deserializer::MIN_VERSION;
+pub use file_format::CompiledModule;
+
+/// An error encountered while deserializing a compiled module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The binary ended before the module was completely read.
+    UnexpectedEof,
+    /// The binary does not start with the Move magic number.
+    BadMagic,
+    /// The binary format version is outside of the supported range, see [`MIN_VERSION`] and
+    /// [`MAX_VERSION`].
+    UnsupportedVersion(u32),
+    /// The binary is not a well-formed module.
+    Malformed(String),
+    /// An index within the module refers to an entry past the end of a table.
+    IndexOutOfBounds {
+        table: &'static str,
+        index: usize,
+        len: usize,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnexpectedEof => f.write_str("unexpected end of module binary"),
+            Error::BadMagic => f.write_str("module binary does not start with the Move magic"),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported binary format version {version}")
+            }
+            Error::Malformed(message) => write!(f, "malformed module binary: {message}"),
+            Error::IndexOutOfBounds { table, index, len } => {
+                write!(f, "index {index} out of bounds for {table} of length {len}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::file_format::Ability;
+    use crate::file_format::AbilitySet;
+    use crate::file_format::Visibility;
+    use crate::normalized::normalize_package;
+    use std::collections::BTreeMap;
+    use sui_sdk_types::types::Address;
+    use sui_sdk_types::types::GenesisTransaction;
+    use sui_sdk_types::types::Identifier;
+    use sui_sdk_types::types::MovePackage;
+    use sui_sdk_types::types::ObjectData;
+    use sui_sdk_types::types::Transaction;
+    use sui_sdk_types::types::TransactionKind;
+
+    include!("../../sui-sdk-types/src/types/transaction/fixtures/genesis.rs");
+
+    /// The framework packages published in the genesis transaction.
+    pub(crate) fn genesis_packages() -> Vec<MovePackage> {
+        genesis_transaction_fixture()
+            .objects
+            .iter()
+            .filter_map(|object| match object.data() {
+                ObjectData::Package(package) => Some(package.clone()),
+                ObjectData::Struct(_) => None,
+            })
+            .collect()
+    }
+
+    fn framework() -> BTreeMap<Identifier, normalized::Module> {
+        let package = genesis_packages()
+            .into_iter()
+            .find(|package| package.id == "0x2".parse().unwrap())
+            .unwrap();
+        normalize_package(&package).unwrap()
+    }
+
+    #[test]
+    fn genesis_packages_deserialize() {
+        let packages = genesis_packages();
+        assert!(!packages.is_empty());
+
+        for package in packages {
+            for (name, bytes) in &package.modules {
+                let module = CompiledModule::deserialize(bytes).unwrap();
+                assert_eq!(module.name(), name);
+                assert_eq!(Address::from(package.id), module.address());
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_coin() {
+        let framework = framework();
+        let coin = &framework[&Identifier::new("coin").unwrap()];
+        assert_eq!(coin.address.to_string(), format!("0x{:064x}", 2));
+
+        let coin_struct = &coin.structs[&Identifier::new("Coin").unwrap()];
+        assert_eq!(
+            coin_struct.abilities,
+            [Ability::Key, Ability::Store]
+                .into_iter()
+                .collect::<AbilitySet>()
+        );
+        assert_eq!(coin_struct.type_parameters.len(), 1);
+        assert!(coin_struct.type_parameters[0].is_phantom);
+        let fields = coin_struct
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name, field.type_))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                format!("id: 0x{:064x}::object::UID", 2),
+                format!("balance: 0x{:064x}::balance::Balance<$0>", 2),
+            ]
+        );
+
+        let value = &coin.functions[&Identifier::new("value").unwrap()];
+        assert_eq!(value.visibility, Visibility::Public);
+        assert!(!value.is_entry);
+        assert_eq!(value.type_parameters, [AbilitySet::EMPTY]);
+        assert_eq!(
+            value.parameters[0].to_string(),
+            format!("&0x{:064x}::coin::Coin<$0>", 2)
+        );
+        assert_eq!(value.return_[0].to_string(), "u64");
+    }
+
+    #[test]
+    fn malformed_modules() {
+        let package = genesis_packages().remove(0);
+        let bytes = package.modules.values().next().unwrap();
+
+        assert_eq!(CompiledModule::deserialize(&[]), Err(Error::UnexpectedEof));
+        assert_eq!(
+            CompiledModule::deserialize(&[0, 1, 2, 3, 6, 0, 0, 0]),
+            Err(Error::BadMagic)
+        );
+
+        let mut unsupported = bytes.clone();
+        unsupported[4..8].copy_from_slice(&99u32.to_le_bytes());
+        assert_eq!(
+            CompiledModule::deserialize(&unsupported),
+            Err(Error::UnsupportedVersion(99))
+        );
+
+        for length in [8, bytes.len() / 2, bytes.len() - 1] {
+            CompiledModule::deserialize(&bytes[..length]).unwrap_err();
+        }
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        CompiledModule::deserialize(&trailing).unwrap_err();
+
+        // Two back to back tables of u32::MAX bytes, whose total length overflows a 32-bit usize
+        let mut oversized = bytes[..8].to_vec();
+        oversized.push(2);
+        for (kind, offset) in [(1, 0), (2, u32::MAX)] {
+            oversized.push(kind);
+            oversized.extend(uleb128(offset.into()));
+            oversized.extend(uleb128(u32::MAX.into()));
+        }
+        CompiledModule::deserialize(&oversized).unwrap_err();
+    }
+
+    pub(crate) fn uleb128(mut value: u64) -> Vec<u8> {
+        let mut bytes = vec![];
+        while value >= 0x80 {
+            bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+        bytes
+    }
+}
@@ -0,0 +1,308 @@
+//! Normalized views of the declarations within a compiled Move module.
+//!
+//! Unlike a [`CompiledModule`], where entities refer to one another by table index, normalized
+//! types are self-contained: datatypes are referred to by their fully qualified name and
+//! declarations are keyed by name. These are equivalent to the normalized Move types exposed by
+//! the GraphQL RPC, e.g. the [`Display`](std::fmt::Display) implementation of [`OpenType`]
+//! matches the `repr` of a GraphQL `OpenMoveType`.
+
+use std::collections::BTreeMap;
+
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Identifier;
+use sui_sdk_types::types::MovePackage;
+
+use crate::file_format::AbilitySet;
+use crate::file_format::CompiledModule;
+use crate::file_format::DatatypeHandleIndex;
+use crate::file_format::DatatypeTypeParameter;
+use crate::file_format::FieldDefinition;
+use crate::file_format::ModuleHandle;
+use crate::file_format::SignatureIndex;
+use crate::file_format::SignatureToken;
+use crate::file_format::TypeParameterIndex;
+use crate::file_format::Visibility;
+use crate::Error;
+
+/// The fully qualified name of a module.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModuleId {
+    pub address: Address,
+    pub name: Identifier,
+}
+
+impl std::fmt::Display for ModuleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}", self.address, self.name)
+    }
+}
+
+/// A normalized Move module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Module {
+    pub address: Address,
+    pub name: Identifier,
+    pub file_format_version: u32,
+    pub friends: Vec<ModuleId>,
+    pub structs: BTreeMap<Identifier, Struct>,
+    pub enums: BTreeMap<Identifier, Enum>,
+    pub functions: BTreeMap<Identifier, Function>,
+}
+
+/// A normalized Move struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Struct {
+    pub abilities: AbilitySet,
+    pub type_parameters: Vec<DatatypeTypeParameter>,
+    pub fields: Vec<Field>,
+}
+
+/// A normalized Move enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Enum {
+    pub abilities: AbilitySet,
+    pub type_parameters: Vec<DatatypeTypeParameter>,
+    /// The enum's variants, in declaration order.
+    pub variants: Vec<Variant>,
+}
+
+/// A variant of a normalized Move enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Variant {
+    pub name: Identifier,
+    pub fields: Vec<Field>,
+}
+
+/// A field of a normalized Move struct or enum variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    pub name: Identifier,
+    pub type_: OpenType,
+}
+
+/// A normalized Move function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+    pub visibility: Visibility,
+    pub is_entry: bool,
+    /// The constraints on each of the function's type parameters.
+    pub type_parameters: Vec<AbilitySet>,
+    pub parameters: Vec<OpenType>,
+    pub return_: Vec<OpenType>,
+}
+
+/// A type which may refer to the type parameters of the declaration it appears in.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OpenType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<OpenType>),
+    Datatype {
+        address: Address,
+        module: Identifier,
+        name: Identifier,
+        type_arguments: Vec<OpenType>,
+    },
+    Reference(Box<OpenType>),
+    MutableReference(Box<OpenType>),
+    TypeParameter(TypeParameterIndex),
+}
+
+impl std::fmt::Display for OpenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenType::Bool => f.write_str("bool"),
+            OpenType::U8 => f.write_str("u8"),
+            OpenType::U16 => f.write_str("u16"),
+            OpenType::U32 => f.write_str("u32"),
+            OpenType::U64 => f.write_str("u64"),
+            OpenType::U128 => f.write_str("u128"),
+            OpenType::U256 => f.write_str("u256"),
+            OpenType::Address => f.write_str("address"),
+            OpenType::Signer => f.write_str("signer"),
+            OpenType::Vector(element) => write!(f, "vector<{element}>"),
+            OpenType::Datatype {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                write!(f, "{address}::{module}::{name}")?;
+                if let Some((first, rest)) = type_arguments.split_first() {
+                    write!(f, "<{first}")?;
+                    for argument in rest {
+                        write!(f, ", {argument}")?;
+                    }
+                    f.write_str(">")?;
+                }
+                Ok(())
+            }
+            OpenType::Reference(inner) => write!(f, "&{inner}"),
+            OpenType::MutableReference(inner) => write!(f, "&mut {inner}"),
+            OpenType::TypeParameter(index) => write!(f, "${index}"),
+        }
+    }
+}
+
+impl Module {
+    /// Normalize a deserialized module.
+    pub fn new(module: &CompiledModule) -> Self {
+        let module_id = |handle: &ModuleHandle| ModuleId {
+            address: module.address_identifier_at(handle.address),
+            name: module.identifier_at(handle.name).clone(),
+        };
+        let fields = |fields: &[FieldDefinition]| {
+            fields
+                .iter()
+                .map(|field| Field {
+                    name: module.identifier_at(field.name).clone(),
+                    type_: OpenType::new(module, &field.signature),
+                })
+                .collect()
+        };
+        let signature = |index: SignatureIndex| {
+            module
+                .signature_at(index)
+                .0
+                .iter()
+                .map(|token| OpenType::new(module, token))
+                .collect()
+        };
+
+        let structs = module
+            .struct_defs
+            .iter()
+            .map(|def| {
+                let handle = module.datatype_handle_at(def.struct_handle);
+                let struct_ = Struct {
+                    abilities: handle.abilities,
+                    type_parameters: handle.type_parameters.clone(),
+                    fields: def.fields.as_deref().map(fields).unwrap_or_default(),
+                };
+                (module.identifier_at(handle.name).clone(), struct_)
+            })
+            .collect();
+
+        let enums = module
+            .enum_defs
+            .iter()
+            .map(|def| {
+                let handle = module.datatype_handle_at(def.enum_handle);
+                let enum_ = Enum {
+                    abilities: handle.abilities,
+                    type_parameters: handle.type_parameters.clone(),
+                    variants: def
+                        .variants
+                        .iter()
+                        .map(|variant| Variant {
+                            name: module.identifier_at(variant.variant_name).clone(),
+                            fields: fields(&variant.fields),
+                        })
+                        .collect(),
+                };
+                (module.identifier_at(handle.name).clone(), enum_)
+            })
+            .collect();
+
+        let functions = module
+            .function_defs
+            .iter()
+            .map(|def| {
+                let handle = module.function_handle_at(def.function);
+                let function = Function {
+                    visibility: def.visibility,
+                    is_entry: def.is_entry,
+                    type_parameters: handle.type_parameters.clone(),
+                    parameters: signature(handle.parameters),
+                    return_: signature(handle.return_),
+                };
+                (module.identifier_at(handle.name).clone(), function)
+            })
+            .collect();
+
+        Self {
+            address: module.address(),
+            name: module.name().clone(),
+            file_format_version: module.version,
+            friends: module.friend_decls.iter().map(module_id).collect(),
+            structs,
+            enums,
+            functions,
+        }
+    }
+
+    /// Deserialize and normalize a module from the Move binary format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        CompiledModule::deserialize(bytes).map(|module| Self::new(&module))
+    }
+
+    /// The fully qualified name of this module.
+    pub fn module_id(&self) -> ModuleId {
+        ModuleId {
+            address: self.address,
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl OpenType {
+    /// Normalize a type appearing in `module`.
+    pub fn new(module: &CompiledModule, token: &SignatureToken) -> Self {
+        let datatype = |index: DatatypeHandleIndex, type_arguments| {
+            let handle = module.datatype_handle_at(index);
+            let defining_module = module.module_handle_at(handle.module);
+            OpenType::Datatype {
+                address: module.address_identifier_at(defining_module.address),
+                module: module.identifier_at(defining_module.name).clone(),
+                name: module.identifier_at(handle.name).clone(),
+                type_arguments,
+            }
+        };
+
+        match token {
+            SignatureToken::Bool => OpenType::Bool,
+            SignatureToken::U8 => OpenType::U8,
+            SignatureToken::U16 => OpenType::U16,
+            SignatureToken::U32 => OpenType::U32,
+            SignatureToken::U64 => OpenType::U64,
+            SignatureToken::U128 => OpenType::U128,
+            SignatureToken::U256 => OpenType::U256,
+            SignatureToken::Address => OpenType::Address,
+            SignatureToken::Signer => OpenType::Signer,
+            SignatureToken::Vector(element) => {
+                OpenType::Vector(Box::new(OpenType::new(module, element)))
+            }
+            SignatureToken::Datatype(index) => datatype(*index, vec![]),
+            SignatureToken::DatatypeInstantiation(index, type_arguments) => datatype(
+                *index,
+                type_arguments
+                    .iter()
+                    .map(|argument| OpenType::new(module, argument))
+                    .collect(),
+            ),
+            SignatureToken::Reference(inner) => {
+                OpenType::Reference(Box::new(OpenType::new(module, inner)))
+            }
+            SignatureToken::MutableReference(inner) => {
+                OpenType::MutableReference(Box::new(OpenType::new(module, inner)))
+            }
+            SignatureToken::TypeParameter(index) => OpenType::TypeParameter(*index),
+        }
+    }
+}
+
+/// Deserialize and normalize every module in a package, keyed by module name.
+pub fn normalize_package(package: &MovePackage) -> Result<BTreeMap<Identifier, Module>, Error> {
+    package
+        .modules
+        .iter()
+        .map(|(name, bytes)| Ok((name.clone(), Module::from_bytes(bytes)?)))
+        .collect()
+}
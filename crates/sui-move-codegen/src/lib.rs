@@ -0,0 +1,223 @@
+//! Generate Rust bindings for the types and functions of Move packages.
+//!
+//! Given the normalized modules of one or more Move packages, either deserialized offline from a
+//! [`MovePackage`] or built from data fetched from an RPC, a [`Generator`] produces Rust source
+//! containing:
+//!
+//! * a type for every Move struct and enum, with a serde implementation matching the BCS layout
+//!   of the Move value and a `struct_tag` constructor for its `StructTag`;
+//! * a function for every public or entry Move function, building the [`MoveCall`] command
+//!   invoking it in a programmable transaction.
+//!
+//! The generated source is intended to be written from a `build.rs` file and included into a
+//! crate, which must depend on `serde` (with its `derive` feature) and on `sui-sdk-types` (with its
+//! `serde` feature):
+//!
+//! ```rust,ignore
+//! // build.rs
+//! fn main() {
+//!     let package: sui_sdk_types::types::MovePackage = bcs::from_bytes(
+//!         &std::fs::read("my_package.bcs").unwrap(),
+//!     ).unwrap();
+//!
+//!     let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+//!     sui_move_codegen::Generator::new()
+//!         .package("my_package", &package)
+//!         .unwrap()
+//!         .write_to_file(out_dir.join("bindings.rs"))
+//!         .unwrap();
+//! }
+//!
+//! // lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+//! ```
+//!
+//! [`MoveCall`]: sui_sdk_types::types::MoveCall
+
+mod render;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use sui_move_bytecode::normalized::normalize_package;
+use sui_move_bytecode::normalized::Module;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Identifier;
+use sui_sdk_types::types::MovePackage;
+use sui_sdk_types::types::ObjectId;
+use sui_sdk_types::types::StructTag;
+
+/// The fully qualified name of a Move datatype.
+type DatatypeKey = (Address, Identifier, Identifier);
+
+/// Generates Rust bindings for a set of Move packages.
+///
+/// Each package is emitted as a Rust module with the name it was added under, containing a
+/// submodule per Move module. A datatype referenced by a package must either be defined by one of
+/// the packages being generated or be mapped to an existing Rust type with
+/// [`Generator::map_type`].
+///
+/// A handful of framework types whose BCS layout matches that of a standard Rust type are mapped
+/// by default:
+///
+/// | Move type                 | Rust type                            |
+/// |---------------------------|--------------------------------------|
+/// | `0x1::string::String`     | `String`                             |
+/// | `0x1::ascii::String`      | `String`                             |
+/// | `0x1::option::Option<T>`  | `Option<T>`                          |
+/// | `0x2::object::UID`        | `sui_sdk_types::types::ObjectId`     |
+/// | `0x2::object::ID`         | `sui_sdk_types::types::ObjectId`     |
+#[derive(Clone, Debug)]
+pub struct Generator {
+    packages: Vec<Package>,
+    types: BTreeMap<DatatypeKey, String>,
+}
+
+#[derive(Clone, Debug)]
+struct Package {
+    name: String,
+    id: ObjectId,
+    modules: BTreeMap<Identifier, Module>,
+    type_origins: BTreeMap<(Identifier, Identifier), Address>,
+}
+
+impl Generator {
+    pub fn new() -> Self {
+        const BUILTIN_TYPES: &[(&str, &str)] = &[
+            ("0x1::string::String", "::std::string::String"),
+            ("0x1::ascii::String", "::std::string::String"),
+            ("0x1::option::Option", "::std::option::Option"),
+            ("0x2::object::UID", "::sui_sdk_types::types::ObjectId"),
+            ("0x2::object::ID", "::sui_sdk_types::types::ObjectId"),
+        ];
+
+        let types = BUILTIN_TYPES
+            .iter()
+            .map(|(move_type, rust_type)| {
+                let tag: StructTag = move_type.parse().unwrap();
+                ((tag.address, tag.module, tag.name), (*rust_type).to_owned())
+            })
+            .collect();
+
+        Self {
+            packages: Vec::new(),
+            types,
+        }
+    }
+
+    /// Add a package to generate bindings for, emitted as a Rust module named `name`.
+    ///
+    /// The package's modules are deserialized and normalized, and its type origin table is used
+    /// to determine the address of the `StructTag` of each of its datatypes.
+    pub fn package(&mut self, name: &str, package: &MovePackage) -> Result<&mut Self, Error> {
+        let modules = normalize_package(package).map_err(Error::Bytecode)?;
+        let type_origins = package
+            .type_origin_table
+            .iter()
+            .map(|origin| {
+                (
+                    (origin.module_name.clone(), origin.struct_name.clone()),
+                    origin.package.into(),
+                )
+            })
+            .collect();
+
+        self.packages.push(Package {
+            name: name.to_owned(),
+            id: package.id,
+            modules,
+            type_origins,
+        });
+        Ok(self)
+    }
+
+    /// Add already normalized modules of the package with id `package_id` to generate bindings
+    /// for, emitted as a Rust module named `name`.
+    ///
+    /// The `StructTag`s of the package's datatypes use the address of the module defining them,
+    /// which is only correct for packages that have not been upgraded; prefer
+    /// [`Generator::package`] for upgraded packages.
+    pub fn modules<I>(&mut self, name: &str, package_id: ObjectId, modules: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Module>,
+    {
+        self.packages.push(Package {
+            name: name.to_owned(),
+            id: package_id,
+            modules: modules.into_iter().map(|m| (m.name.clone(), m)).collect(),
+            type_origins: BTreeMap::new(),
+        });
+        self
+    }
+
+    /// Map the Move datatype `type_` to the Rust type at `path` instead of requiring bindings to
+    /// be generated for it.
+    ///
+    /// `path` should be an absolute path, e.g. `::my_crate::Balance`. Any type arguments of a
+    /// reference to `type_` are appended to `path`, while the type parameters of `type_` itself
+    /// are ignored.
+    pub fn map_type(&mut self, type_: StructTag, path: impl Into<String>) -> &mut Self {
+        self.types
+            .insert((type_.address, type_.module, type_.name), path.into());
+        self
+    }
+
+    /// Generate the Rust source of the bindings.
+    pub fn generate(&self) -> Result<String, Error> {
+        render::render(self)
+    }
+
+    /// Generate the Rust source of the bindings and write it to the file at `path`.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let source = self.generate()?;
+        std::fs::write(path, source).map_err(Error::Io)
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An error encountered while generating bindings.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A module of a package could not be deserialized.
+    Bytecode(sui_move_bytecode::Error),
+    /// A type has no Rust equivalent, either because it is a datatype that is neither generated
+    /// nor mapped to a Rust type, or because it cannot appear in a value.
+    UnsupportedType {
+        /// The unsupported type.
+        type_: String,
+        /// The declaration referring to the type.
+        context: String,
+    },
+    /// The bindings could not be written.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Bytecode(e) => write!(f, "unable to deserialize module: {e}"),
+            Error::UnsupportedType { type_, context } => write!(
+                f,
+                "no Rust type for `{type_}` referenced by `{context}`; \
+                generate bindings for its package or map it to an existing Rust type"
+            ),
+            Error::Io(e) => write!(f, "unable to write bindings: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Bytecode(e) => Some(e),
+            Error::UnsupportedType { .. } => None,
+            Error::Io(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,573 @@
+//! Rendering of normalized Move declarations as Rust source.
+
+use std::collections::BTreeSet;
+
+use sui_move_bytecode::file_format::AbilitySet;
+use sui_move_bytecode::file_format::Visibility;
+use sui_move_bytecode::normalized::Enum;
+use sui_move_bytecode::normalized::Field;
+use sui_move_bytecode::normalized::Function;
+use sui_move_bytecode::normalized::Module;
+use sui_move_bytecode::normalized::OpenType;
+use sui_move_bytecode::normalized::Struct;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Identifier;
+
+use crate::Error;
+use crate::Generator;
+use crate::Package;
+
+const TYPES: &str = "::sui_sdk_types::types";
+
+/// Words which cannot be used as plain Rust identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+pub(crate) fn render(generator: &Generator) -> Result<String, Error> {
+    let mut w = Writer::default();
+    w.line("// @generated by sui-move-codegen, do not edit.");
+    for package in &generator.packages {
+        generator.render_package(&mut w, package)?;
+    }
+    Ok(w.out)
+}
+
+#[derive(Default)]
+struct Writer {
+    out: String,
+    indent: usize,
+}
+
+impl Writer {
+    fn line(&mut self, line: impl AsRef<str>) {
+        let line = line.as_ref();
+        if !line.is_empty() {
+            for _ in 0..self.indent {
+                self.out.push_str("    ");
+            }
+            self.out.push_str(line);
+        }
+        self.out.push('\n');
+    }
+
+    fn open(&mut self, line: impl AsRef<str>) {
+        self.line(line);
+        self.indent += 1;
+    }
+
+    fn close(&mut self, line: impl AsRef<str>) {
+        self.indent -= 1;
+        self.line(line);
+    }
+}
+
+/// The Rust spelling of a Move identifier.
+fn ident(name: &str) -> String {
+    match name {
+        "crate" | "self" | "Self" | "super" => format!("{name}_"),
+        name if KEYWORDS.contains(&name) => format!("r#{name}"),
+        name => name.to_owned(),
+    }
+}
+
+fn bytes_literal(bytes: &[u8; 32]) -> String {
+    let bytes = bytes
+        .iter()
+        .map(|byte| format!("{byte:#04x}"))
+        .collect::<Vec<_>>();
+    format!("[{}]", bytes.join(", "))
+}
+
+/// The generic parameters of a datatype or the type arguments of a struct tag.
+fn generics(count: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    let parameters = (0..count).map(|i| format!("T{i}")).collect::<Vec<_>>();
+    format!("<{}>", parameters.join(", "))
+}
+
+fn type_parameters_of(type_: &OpenType, parameters: &mut BTreeSet<u16>) {
+    match type_ {
+        OpenType::Vector(inner)
+        | OpenType::Reference(inner)
+        | OpenType::MutableReference(inner) => type_parameters_of(inner, parameters),
+        OpenType::Datatype { type_arguments, .. } => type_arguments
+            .iter()
+            .for_each(|argument| type_parameters_of(argument, parameters)),
+        OpenType::TypeParameter(index) => {
+            parameters.insert(*index);
+        }
+        _ => {}
+    }
+}
+
+/// The type parameters, out of `count`, which are not referred to by any of `fields` and so need
+/// to be captured by a `PhantomData`.
+fn unused_type_parameters<'a>(
+    count: usize,
+    fields: impl IntoIterator<Item = &'a Field>,
+) -> Option<String> {
+    let mut used = BTreeSet::new();
+    for field in fields {
+        type_parameters_of(&field.type_, &mut used);
+    }
+    let unused = (0..count as u16)
+        .filter(|index| !used.contains(index))
+        .map(|index| format!("T{index}"))
+        .collect::<Vec<_>>();
+
+    match unused.as_slice() {
+        [] => None,
+        [parameter] => Some(format!("::std::marker::PhantomData<{parameter}>")),
+        parameters => Some(format!(
+            "::std::marker::PhantomData<({})>",
+            parameters.join(", ")
+        )),
+    }
+}
+
+fn is_tx_context(type_: &OpenType) -> bool {
+    let (OpenType::Reference(inner) | OpenType::MutableReference(inner)) = type_ else {
+        return false;
+    };
+    matches!(
+        inner.as_ref(),
+        OpenType::Datatype { address, module, name, .. }
+            if *address == Address::TWO && module == "tx_context" && name == "TxContext"
+    )
+}
+
+/// The Move declaration of `function`, for documentation.
+fn function_signature(name: &Identifier, function: &Function) -> String {
+    let mut signature = String::new();
+    match function.visibility {
+        Visibility::Public => signature.push_str("public "),
+        Visibility::Friend => signature.push_str("public(package) "),
+        Visibility::Private => {}
+    }
+    if function.is_entry {
+        signature.push_str("entry ");
+    }
+    signature.push_str(&format!("fun {name}"));
+
+    if !function.type_parameters.is_empty() {
+        let parameters = function
+            .type_parameters
+            .iter()
+            .enumerate()
+            .map(|(i, constraints)| {
+                if constraints.is_empty() {
+                    format!("${i}")
+                } else {
+                    format!("${i}: {}", abilities(*constraints))
+                }
+            })
+            .collect::<Vec<_>>();
+        signature.push_str(&format!("<{}>", parameters.join(", ")));
+    }
+
+    let parameters = function
+        .parameters
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    signature.push_str(&format!("({})", parameters.join(", ")));
+
+    match function.return_.as_slice() {
+        [] => {}
+        [type_] => signature.push_str(&format!(": {type_}")),
+        types => {
+            let types = types.iter().map(ToString::to_string).collect::<Vec<_>>();
+            signature.push_str(&format!(": ({})", types.join(", ")));
+        }
+    }
+    signature
+}
+
+fn abilities(abilities: AbilitySet) -> String {
+    abilities
+        .iter()
+        .map(|ability| ability.to_string())
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+impl Generator {
+    fn render_package(&self, w: &mut Writer, package: &Package) -> Result<(), Error> {
+        w.line("");
+        w.line(format!(
+            "/// Bindings for the Move package `{}`.",
+            package.id
+        ));
+        w.line("#[allow(clippy::all, dead_code, non_camel_case_types, non_snake_case)]");
+        w.open(format!("pub mod {} {{", ident(&package.name)));
+        w.line("/// The id of the package, which calls to its functions are made against.");
+        w.line(format!(
+            "pub const PACKAGE_ID: {TYPES}::ObjectId = {TYPES}::ObjectId::new({});",
+            bytes_literal(package.id.inner())
+        ));
+
+        for module in package.modules.values() {
+            self.render_module(w, package, module)?;
+        }
+
+        w.close("}");
+        Ok(())
+    }
+
+    fn render_module(
+        &self,
+        w: &mut Writer,
+        package: &Package,
+        module: &Module,
+    ) -> Result<(), Error> {
+        w.line("");
+        w.line(format!(
+            "/// Bindings for the Move module `{}`.",
+            module.module_id()
+        ));
+        w.open(format!("pub mod {} {{", ident(module.name.as_str())));
+        w.line(format!(
+            "pub const MODULE_NAME: &str = \"{}\";",
+            module.name
+        ));
+
+        for (name, struct_) in &module.structs {
+            self.render_struct(w, package, module, name, struct_)?;
+        }
+        for (name, enum_) in &module.enums {
+            self.render_enum(w, package, module, name, enum_)?;
+        }
+        for (name, function) in &module.functions {
+            if function.visibility == Visibility::Public || function.is_entry {
+                render_function(w, module, name, function);
+            }
+        }
+
+        w.close("}");
+        Ok(())
+    }
+
+    fn render_struct(
+        &self,
+        w: &mut Writer,
+        package: &Package,
+        module: &Module,
+        name: &Identifier,
+        struct_: &Struct,
+    ) -> Result<(), Error> {
+        let context = format!("{}::{name}", module.module_id());
+        let fields = self.fields(&struct_.fields, &context)?;
+        let count = struct_.type_parameters.len();
+        let generics = generics(count);
+
+        w.line("");
+        w.line(format!("/// The Move struct `{context}`."));
+        render_derives(w, count, &fields);
+        w.open(format!("pub struct {}{generics} {{", ident(name.as_str())));
+        for (field, type_, _) in &fields {
+            w.line(format!("pub {field}: {type_},"));
+        }
+        if let Some(phantom) = unused_type_parameters(count, &struct_.fields) {
+            w.line("#[serde(skip)]");
+            w.line(format!("pub _phantom: {phantom},"));
+        }
+        w.close("}");
+
+        render_datatype_impl(w, package, module, name, count);
+        Ok(())
+    }
+
+    fn render_enum(
+        &self,
+        w: &mut Writer,
+        package: &Package,
+        module: &Module,
+        name: &Identifier,
+        enum_: &Enum,
+    ) -> Result<(), Error> {
+        let context = format!("{}::{name}", module.module_id());
+        let variants = enum_
+            .variants
+            .iter()
+            .map(|variant| {
+                Ok((
+                    ident(variant.name.as_str()),
+                    self.fields(&variant.fields, &context)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let count = enum_.type_parameters.len();
+        let generics = generics(count);
+
+        w.line("");
+        w.line(format!("/// The Move enum `{context}`."));
+        render_derives(
+            w,
+            count,
+            &variants
+                .iter()
+                .flat_map(|(_, fields)| fields.clone())
+                .collect::<Vec<_>>(),
+        );
+        w.open(format!("pub enum {}{generics} {{", ident(name.as_str())));
+        for (variant, fields) in &variants {
+            if fields.is_empty() {
+                w.line(format!("{variant},"));
+                continue;
+            }
+            w.open(format!("{variant} {{"));
+            for (field, type_, _) in fields {
+                w.line(format!("{field}: {type_},"));
+            }
+            w.close("},");
+        }
+        let all_fields = enum_.variants.iter().flat_map(|variant| &variant.fields);
+        if let Some(phantom) = unused_type_parameters(count, all_fields) {
+            w.line("#[doc(hidden)]");
+            w.line("#[serde(skip)]");
+            w.line(format!("__Phantom({phantom}, ::std::convert::Infallible),"));
+        }
+        w.close("}");
+
+        render_datatype_impl(w, package, module, name, count);
+        Ok(())
+    }
+
+    /// The Rust name and type of each of `fields`, along with whether the type refers to any type
+    /// parameters.
+    fn fields(
+        &self,
+        fields: &[Field],
+        context: &str,
+    ) -> Result<Vec<(String, String, bool)>, Error> {
+        fields
+            .iter()
+            .map(|field| {
+                let mut parameters = BTreeSet::new();
+                type_parameters_of(&field.type_, &mut parameters);
+                Ok((
+                    ident(field.name.as_str()),
+                    self.rust_type(&field.type_, context)?,
+                    !parameters.is_empty(),
+                ))
+            })
+            .collect()
+    }
+
+    fn rust_type(&self, type_: &OpenType, context: &str) -> Result<String, Error> {
+        let type_ = match type_ {
+            OpenType::Bool => "bool".to_owned(),
+            OpenType::U8 => "u8".to_owned(),
+            OpenType::U16 => "u16".to_owned(),
+            OpenType::U32 => "u32".to_owned(),
+            OpenType::U64 => "u64".to_owned(),
+            OpenType::U128 => "u128".to_owned(),
+            // A u256 is encoded as 32 little-endian bytes
+            OpenType::U256 => "[u8; 32]".to_owned(),
+            OpenType::Address | OpenType::Signer => format!("{TYPES}::Address"),
+            OpenType::Vector(element) => {
+                format!("::std::vec::Vec<{}>", self.rust_type(element, context)?)
+            }
+            OpenType::Datatype {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                let path = self.datatype_path(address, module, name).ok_or_else(|| {
+                    Error::UnsupportedType {
+                        type_: format!("{address}::{module}::{name}"),
+                        context: context.to_owned(),
+                    }
+                })?;
+                if type_arguments.is_empty() {
+                    path
+                } else {
+                    let arguments = type_arguments
+                        .iter()
+                        .map(|argument| self.rust_type(argument, context))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    format!("{path}<{}>", arguments.join(", "))
+                }
+            }
+            OpenType::TypeParameter(index) => format!("T{index}"),
+            OpenType::Reference(_) | OpenType::MutableReference(_) => {
+                return Err(Error::UnsupportedType {
+                    type_: type_.to_string(),
+                    context: context.to_owned(),
+                })
+            }
+        };
+        Ok(type_)
+    }
+
+    /// The path of the Rust type for a Move datatype, relative to a generated Move module.
+    fn datatype_path(
+        &self,
+        address: &Address,
+        module: &Identifier,
+        name: &Identifier,
+    ) -> Option<String> {
+        if let Some(path) = self.types.get(&(*address, module.clone(), name.clone())) {
+            return Some(path.clone());
+        }
+
+        self.packages.iter().find_map(|package| {
+            let defining_module = package.modules.get(module)?;
+            let defined = defining_module.address == *address
+                && (defining_module.structs.contains_key(name)
+                    || defining_module.enums.contains_key(name));
+            defined.then(|| {
+                format!(
+                    "super::super::{}::{}::{}",
+                    ident(&package.name),
+                    ident(module.as_str()),
+                    ident(name.as_str())
+                )
+            })
+        })
+    }
+}
+
+/// Derive the traits of a datatype, bounding its serde implementations on the types of the fields
+/// which refer to its type parameters, rather than on the type parameters themselves, so that
+/// phantom type parameters need not implement serde's traits.
+fn render_derives(w: &mut Writer, count: usize, fields: &[(String, String, bool)]) {
+    w.line("#[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]");
+    if count == 0 {
+        return;
+    }
+
+    let mut types = Vec::new();
+    for (_, type_, generic) in fields {
+        if *generic && !types.contains(&type_) {
+            types.push(type_);
+        }
+    }
+    if types.is_empty() {
+        w.line("#[serde(bound = \"\")]");
+        return;
+    }
+
+    let bound = |bound: &str| {
+        types
+            .iter()
+            .map(|type_| format!("{type_}: {bound}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    w.line(format!(
+        "#[serde(bound(serialize = \"{}\", deserialize = \"{}\"))]",
+        bound("::serde::Serialize"),
+        bound("::serde::Deserialize<'de>"),
+    ));
+}
+
+fn render_datatype_impl(
+    w: &mut Writer,
+    package: &Package,
+    module: &Module,
+    name: &Identifier,
+    count: usize,
+) {
+    let generics = generics(count);
+    let origin = package
+        .type_origins
+        .get(&(module.name.clone(), name.clone()))
+        .copied()
+        .unwrap_or(module.address);
+    let type_arguments = (0..count).map(|i| format!("t{i}")).collect::<Vec<_>>();
+    let parameters = type_arguments
+        .iter()
+        .map(|argument| format!("{argument}: {TYPES}::TypeTag"))
+        .collect::<Vec<_>>();
+
+    w.line("");
+    w.open(format!(
+        "impl{generics} {}{generics} {{",
+        ident(name.as_str())
+    ));
+    w.line(format!(
+        "pub const ADDRESS: {TYPES}::Address = {TYPES}::Address::new({});",
+        bytes_literal(origin.inner())
+    ));
+    w.line("pub const MODULE: &str = MODULE_NAME;");
+    w.line(format!("pub const NAME: &str = \"{name}\";"));
+    w.line("");
+    w.line("/// The `StructTag` of this type, instantiated with the given type arguments.");
+    w.open(format!(
+        "pub fn struct_tag({}) -> {TYPES}::StructTag {{",
+        parameters.join(", ")
+    ));
+    w.open(format!("{TYPES}::StructTag {{"));
+    w.line("address: Self::ADDRESS,");
+    w.line(format!(
+        "module: {TYPES}::Identifier::new(Self::MODULE).unwrap(),"
+    ));
+    w.line(format!(
+        "name: {TYPES}::Identifier::new(Self::NAME).unwrap(),"
+    ));
+    w.line(format!("type_params: vec![{}],", type_arguments.join(", ")));
+    w.close("}");
+    w.close("}");
+    w.close("}");
+}
+
+/// Render a function building a call to `function`, omitting its trailing `TxContext` parameter
+/// which is provided by the runtime.
+fn render_function(w: &mut Writer, module: &Module, name: &Identifier, function: &Function) {
+    let parameters = match function.parameters.split_last() {
+        Some((last, rest)) if is_tx_context(last) => rest,
+        _ => &function.parameters,
+    };
+    let type_arguments = (0..function.type_parameters.len())
+        .map(|i| format!("t{i}"))
+        .collect::<Vec<_>>();
+    let arguments = (0..parameters.len())
+        .map(|i| format!("arg{i}"))
+        .collect::<Vec<_>>();
+    let signature = type_arguments
+        .iter()
+        .map(|argument| format!("{argument}: {TYPES}::TypeTag"))
+        .chain(
+            arguments
+                .iter()
+                .map(|argument| format!("{argument}: {TYPES}::Argument")),
+        )
+        .collect::<Vec<_>>();
+
+    w.line("");
+    w.line(format!(
+        "/// Build a call to the Move function `{}::{name}`:",
+        module.module_id()
+    ));
+    w.line("///");
+    w.line(format!("/// `{}`", function_signature(name, function)));
+    w.open(format!(
+        "pub fn {}({}) -> {TYPES}::MoveCall {{",
+        ident(name.as_str()),
+        signature.join(", ")
+    ));
+    w.open(format!("{TYPES}::MoveCall {{"));
+    w.line("package: super::PACKAGE_ID,");
+    w.line(format!(
+        "module: {TYPES}::Identifier::new(MODULE_NAME).unwrap(),"
+    ));
+    w.line(format!(
+        "function: {TYPES}::Identifier::new(\"{name}\").unwrap(),"
+    ));
+    w.line(format!(
+        "type_arguments: vec![{}],",
+        type_arguments.join(", ")
+    ));
+    w.line(format!("arguments: vec![{}],", arguments.join(", ")));
+    w.close("}");
+    w.close("}");
+}
@@ -0,0 +1,880 @@
+// @generated by sui-move-codegen, do not edit.
+
+/// Bindings for the Move package `0x0000000000000000000000000000000000000000000000000000000000000002`.
+#[allow(clippy::all, dead_code, non_camel_case_types, non_snake_case)]
+pub mod sui {
+    /// The id of the package, which calls to its functions are made against.
+    pub const PACKAGE_ID: ::sui_sdk_types::types::ObjectId = ::sui_sdk_types::types::ObjectId::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+
+    /// Bindings for the Move module `0x0000000000000000000000000000000000000000000000000000000000000002::balance`.
+    pub mod balance {
+        pub const MODULE_NAME: &str = "balance";
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound = "")]
+        pub struct Balance<T0> {
+            pub value: u64,
+            #[serde(skip)]
+            pub _phantom: ::std::marker::PhantomData<T0>,
+        }
+
+        impl<T0> Balance<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "Balance";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound = "")]
+        pub struct Supply<T0> {
+            pub value: u64,
+            #[serde(skip)]
+            pub _phantom: ::std::marker::PhantomData<T0>,
+        }
+
+        impl<T0> Supply<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "Supply";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::create_supply`:
+        ///
+        /// `public fun create_supply<$0: drop>($0): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>`
+        pub fn create_supply(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("create_supply").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::decrease_supply`:
+        ///
+        /// `public fun decrease_supply<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>, 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>): u64`
+        pub fn decrease_supply(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("decrease_supply").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::destroy_zero`:
+        ///
+        /// `public fun destroy_zero<$0>(0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>)`
+        pub fn destroy_zero(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("destroy_zero").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::increase_supply`:
+        ///
+        /// `public fun increase_supply<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>, u64): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn increase_supply(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("increase_supply").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::join`:
+        ///
+        /// `public fun join<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>, 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>): u64`
+        pub fn join(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("join").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::split`:
+        ///
+        /// `public fun split<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>, u64): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn split(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("split").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::supply_value`:
+        ///
+        /// `public fun supply_value<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>): u64`
+        pub fn supply_value(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("supply_value").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::value`:
+        ///
+        /// `public fun value<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>): u64`
+        pub fn value(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("value").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::withdraw_all`:
+        ///
+        /// `public fun withdraw_all<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn withdraw_all(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("withdraw_all").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::balance::zero`:
+        ///
+        /// `public fun zero<$0>(): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn zero(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("zero").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![],
+            }
+        }
+    }
+
+    /// Bindings for the Move module `0x0000000000000000000000000000000000000000000000000000000000000002::coin`.
+    pub mod coin {
+        pub const MODULE_NAME: &str = "coin";
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound(serialize = "super::super::sui::balance::Balance<T0>: ::serde::Serialize", deserialize = "super::super::sui::balance::Balance<T0>: ::serde::Deserialize<'de>"))]
+        pub struct Coin<T0> {
+            pub id: ::sui_sdk_types::types::ObjectId,
+            pub balance: super::super::sui::balance::Balance<T0>,
+        }
+
+        impl<T0> Coin<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "Coin";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound = "")]
+        pub struct CoinMetadata<T0> {
+            pub id: ::sui_sdk_types::types::ObjectId,
+            pub decimals: u8,
+            pub name: ::std::string::String,
+            pub symbol: ::std::string::String,
+            pub description: ::std::string::String,
+            pub icon_url: ::std::option::Option<super::super::sui::url::Url>,
+            #[serde(skip)]
+            pub _phantom: ::std::marker::PhantomData<T0>,
+        }
+
+        impl<T0> CoinMetadata<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "CoinMetadata";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::coin::CurrencyCreated`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound = "")]
+        pub struct CurrencyCreated<T0> {
+            pub decimals: u8,
+            #[serde(skip)]
+            pub _phantom: ::std::marker::PhantomData<T0>,
+        }
+
+        impl<T0> CurrencyCreated<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "CurrencyCreated";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound(serialize = "super::super::sui::balance::Supply<T0>: ::serde::Serialize", deserialize = "super::super::sui::balance::Supply<T0>: ::serde::Deserialize<'de>"))]
+        pub struct TreasuryCap<T0> {
+            pub id: ::sui_sdk_types::types::ObjectId,
+            pub total_supply: super::super::sui::balance::Supply<T0>,
+        }
+
+        impl<T0> TreasuryCap<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "TreasuryCap";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::balance`:
+        ///
+        /// `public fun balance<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>): &0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn balance(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("balance").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::balance_mut`:
+        ///
+        /// `public fun balance_mut<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>): &mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn balance_mut(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("balance_mut").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::burn`:
+        ///
+        /// `public entry fun burn<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>): u64`
+        pub fn burn(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("burn").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::create_currency`:
+        ///
+        /// `public fun create_currency<$0: drop>($0, u8, vector<u8>, vector<u8>, vector<u8>, 0x0000000000000000000000000000000000000000000000000000000000000001::option::Option<0x0000000000000000000000000000000000000000000000000000000000000002::url::Url>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): (0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, 0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>)`
+        pub fn create_currency(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument, arg2: ::sui_sdk_types::types::Argument, arg3: ::sui_sdk_types::types::Argument, arg4: ::sui_sdk_types::types::Argument, arg5: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("create_currency").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1, arg2, arg3, arg4, arg5],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::destroy_zero`:
+        ///
+        /// `public fun destroy_zero<$0>(0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>)`
+        pub fn destroy_zero(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("destroy_zero").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::divide_into_n`:
+        ///
+        /// `public fun divide_into_n<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>, u64, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): vector<0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>>`
+        pub fn divide_into_n(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("divide_into_n").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::from_balance`:
+        ///
+        /// `public fun from_balance<$0>(0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>`
+        pub fn from_balance(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("from_balance").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::get_decimals`:
+        ///
+        /// `public fun get_decimals<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>): u8`
+        pub fn get_decimals(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("get_decimals").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::get_description`:
+        ///
+        /// `public fun get_description<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>): 0x0000000000000000000000000000000000000000000000000000000000000001::string::String`
+        pub fn get_description(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("get_description").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::get_icon_url`:
+        ///
+        /// `public fun get_icon_url<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>): 0x0000000000000000000000000000000000000000000000000000000000000001::option::Option<0x0000000000000000000000000000000000000000000000000000000000000002::url::Url>`
+        pub fn get_icon_url(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("get_icon_url").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::get_name`:
+        ///
+        /// `public fun get_name<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>): 0x0000000000000000000000000000000000000000000000000000000000000001::string::String`
+        pub fn get_name(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("get_name").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::get_symbol`:
+        ///
+        /// `public fun get_symbol<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>): 0x0000000000000000000000000000000000000000000000000000000000000001::ascii::String`
+        pub fn get_symbol(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("get_symbol").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::into_balance`:
+        ///
+        /// `public fun into_balance<$0>(0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn into_balance(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("into_balance").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::join`:
+        ///
+        /// `public entry fun join<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>, 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>)`
+        pub fn join(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("join").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::mint`:
+        ///
+        /// `public fun mint<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, u64, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>`
+        pub fn mint(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("mint").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::mint_and_transfer`:
+        ///
+        /// `public entry fun mint_and_transfer<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, u64, address, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext)`
+        pub fn mint_and_transfer(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument, arg2: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("mint_and_transfer").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1, arg2],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::mint_balance`:
+        ///
+        /// `public fun mint_balance<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, u64): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>`
+        pub fn mint_balance(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("mint_balance").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::put`:
+        ///
+        /// `public fun put<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>, 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>)`
+        pub fn put(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("put").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::split`:
+        ///
+        /// `public fun split<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>, u64, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>`
+        pub fn split(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("split").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::supply`:
+        ///
+        /// `public fun supply<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>): &0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>`
+        pub fn supply(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("supply").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::supply_mut`:
+        ///
+        /// `public fun supply_mut<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>): &mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>`
+        pub fn supply_mut(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("supply_mut").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::take`:
+        ///
+        /// `public fun take<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Balance<$0>, u64, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>`
+        pub fn take(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("take").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::total_supply`:
+        ///
+        /// `public fun total_supply<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>): u64`
+        pub fn total_supply(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("total_supply").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::treasury_into_supply`:
+        ///
+        /// `public fun treasury_into_supply<$0>(0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>): 0x0000000000000000000000000000000000000000000000000000000000000002::balance::Supply<$0>`
+        pub fn treasury_into_supply(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("treasury_into_supply").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::update_description`:
+        ///
+        /// `public entry fun update_description<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>, 0x0000000000000000000000000000000000000000000000000000000000000001::string::String)`
+        pub fn update_description(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument, arg2: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("update_description").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1, arg2],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::update_icon_url`:
+        ///
+        /// `public entry fun update_icon_url<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>, 0x0000000000000000000000000000000000000000000000000000000000000001::ascii::String)`
+        pub fn update_icon_url(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument, arg2: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("update_icon_url").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1, arg2],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::update_name`:
+        ///
+        /// `public entry fun update_name<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>, 0x0000000000000000000000000000000000000000000000000000000000000001::string::String)`
+        pub fn update_name(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument, arg2: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("update_name").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1, arg2],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::update_symbol`:
+        ///
+        /// `public entry fun update_symbol<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::TreasuryCap<$0>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::coin::CoinMetadata<$0>, 0x0000000000000000000000000000000000000000000000000000000000000001::ascii::String)`
+        pub fn update_symbol(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument, arg2: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("update_symbol").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1, arg2],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::value`:
+        ///
+        /// `public fun value<$0>(&0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>): u64`
+        pub fn value(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("value").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::coin::zero`:
+        ///
+        /// `public fun zero<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext): 0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<$0>`
+        pub fn zero(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("zero").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![],
+            }
+        }
+    }
+
+    /// Bindings for the Move module `0x0000000000000000000000000000000000000000000000000000000000000002::url`.
+    pub mod url {
+        pub const MODULE_NAME: &str = "url";
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000002::url::Url`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct Url {
+            pub url: ::std::string::String,
+        }
+
+        impl Url {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "Url";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag() -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![],
+                }
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::url::inner_url`:
+        ///
+        /// `public fun inner_url(&0x0000000000000000000000000000000000000000000000000000000000000002::url::Url): 0x0000000000000000000000000000000000000000000000000000000000000001::ascii::String`
+        pub fn inner_url(arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("inner_url").unwrap(),
+                type_arguments: vec![],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::url::new_unsafe`:
+        ///
+        /// `public fun new_unsafe(0x0000000000000000000000000000000000000000000000000000000000000001::ascii::String): 0x0000000000000000000000000000000000000000000000000000000000000002::url::Url`
+        pub fn new_unsafe(arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("new_unsafe").unwrap(),
+                type_arguments: vec![],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::url::new_unsafe_from_bytes`:
+        ///
+        /// `public fun new_unsafe_from_bytes(vector<u8>): 0x0000000000000000000000000000000000000000000000000000000000000002::url::Url`
+        pub fn new_unsafe_from_bytes(arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("new_unsafe_from_bytes").unwrap(),
+                type_arguments: vec![],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000002::url::update`:
+        ///
+        /// `public fun update(&mut 0x0000000000000000000000000000000000000000000000000000000000000002::url::Url, 0x0000000000000000000000000000000000000000000000000000000000000001::ascii::String)`
+        pub fn update(arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("update").unwrap(),
+                type_arguments: vec![],
+                arguments: vec![arg0, arg1],
+            }
+        }
+    }
+}
+
+/// Bindings for the Move package `0x0000000000000000000000000000000000000000000000000000000000000042`.
+#[allow(clippy::all, dead_code, non_camel_case_types, non_snake_case)]
+pub mod example {
+    /// The id of the package, which calls to its functions are made against.
+    pub const PACKAGE_ID: ::sui_sdk_types::types::ObjectId = ::sui_sdk_types::types::ObjectId::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x42]);
+
+    /// Bindings for the Move module `0x0000000000000000000000000000000000000000000000000000000000000042::shapes`.
+    pub mod shapes {
+        pub const MODULE_NAME: &str = "shapes";
+
+        /// The Move struct `0x0000000000000000000000000000000000000000000000000000000000000042::shapes::Canvas`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound(serialize = "::std::vec::Vec<super::super::example::shapes::Shape<T0>>: ::serde::Serialize, super::super::sui::coin::Coin<T0>: ::serde::Serialize", deserialize = "::std::vec::Vec<super::super::example::shapes::Shape<T0>>: ::serde::Deserialize<'de>, super::super::sui::coin::Coin<T0>: ::serde::Deserialize<'de>"))]
+        pub struct Canvas<T0> {
+            pub id: ::sui_sdk_types::types::ObjectId,
+            pub shapes: ::std::vec::Vec<super::super::example::shapes::Shape<T0>>,
+            pub r#type: ::std::option::Option<::sui_sdk_types::types::Address>,
+            pub fee: super::super::sui::coin::Coin<T0>,
+        }
+
+        impl<T0> Canvas<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x42]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "Canvas";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// The Move enum `0x0000000000000000000000000000000000000000000000000000000000000042::shapes::Shape`.
+        #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+        #[serde(bound = "")]
+        pub enum Shape<T0> {
+            Circle {
+                radius: u64,
+            },
+            Rectangle {
+                width: u64,
+                height: u64,
+            },
+            Empty,
+            #[doc(hidden)]
+            #[serde(skip)]
+            __Phantom(::std::marker::PhantomData<T0>, ::std::convert::Infallible),
+        }
+
+        impl<T0> Shape<T0> {
+            pub const ADDRESS: ::sui_sdk_types::types::Address = ::sui_sdk_types::types::Address::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x42]);
+            pub const MODULE: &str = MODULE_NAME;
+            pub const NAME: &str = "Shape";
+
+            /// The `StructTag` of this type, instantiated with the given type arguments.
+            pub fn struct_tag(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::StructTag {
+                ::sui_sdk_types::types::StructTag {
+                    address: Self::ADDRESS,
+                    module: ::sui_sdk_types::types::Identifier::new(Self::MODULE).unwrap(),
+                    name: ::sui_sdk_types::types::Identifier::new(Self::NAME).unwrap(),
+                    type_params: vec![t0],
+                }
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000042::shapes::area`:
+        ///
+        /// `public fun area<$0>(&0x0000000000000000000000000000000000000000000000000000000000000042::shapes::Shape<$0>): u64`
+        pub fn area(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("area").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000042::shapes::draw`:
+        ///
+        /// `entry fun draw<$0>(&mut 0x0000000000000000000000000000000000000000000000000000000000000042::shapes::Canvas<$0>, 0x0000000000000000000000000000000000000000000000000000000000000042::shapes::Shape<$0>, &mut 0x0000000000000000000000000000000000000000000000000000000000000002::tx_context::TxContext)`
+        pub fn draw(t0: ::sui_sdk_types::types::TypeTag, arg0: ::sui_sdk_types::types::Argument, arg1: ::sui_sdk_types::types::Argument) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("draw").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![arg0, arg1],
+            }
+        }
+
+        /// Build a call to the Move function `0x0000000000000000000000000000000000000000000000000000000000000042::shapes::move`:
+        ///
+        /// `public fun move<$0>()`
+        pub fn r#move(t0: ::sui_sdk_types::types::TypeTag) -> ::sui_sdk_types::types::MoveCall {
+            ::sui_sdk_types::types::MoveCall {
+                package: super::PACKAGE_ID,
+                module: ::sui_sdk_types::types::Identifier::new(MODULE_NAME).unwrap(),
+                function: ::sui_sdk_types::types::Identifier::new("move").unwrap(),
+                type_arguments: vec![t0],
+                arguments: vec![],
+            }
+        }
+    }
+}
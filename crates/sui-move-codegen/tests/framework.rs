@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use sui_move_bytecode::file_format::Ability;
+use sui_move_bytecode::file_format::AbilitySet;
+use sui_move_bytecode::file_format::DatatypeTypeParameter;
+use sui_move_bytecode::file_format::Visibility;
+use sui_move_bytecode::normalized::Enum;
+use sui_move_bytecode::normalized::Field;
+use sui_move_bytecode::normalized::Function;
+use sui_move_bytecode::normalized::Module;
+use sui_move_bytecode::normalized::OpenType;
+use sui_move_bytecode::normalized::Struct;
+use sui_move_bytecode::normalized::Variant;
+use sui_move_codegen::Error;
+use sui_move_codegen::Generator;
+use sui_sdk_types::types::Address;
+use sui_sdk_types::types::Argument;
+use sui_sdk_types::types::GenesisTransaction;
+use sui_sdk_types::types::Identifier;
+use sui_sdk_types::types::MovePackage;
+use sui_sdk_types::types::ObjectData;
+use sui_sdk_types::types::ObjectId;
+use sui_sdk_types::types::StructTag;
+use sui_sdk_types::types::Transaction;
+use sui_sdk_types::types::TransactionKind;
+use sui_sdk_types::types::TypeTag;
+
+mod bindings {
+    include!("bindings/framework.rs");
+}
+
+use bindings::example::shapes;
+use bindings::sui::balance;
+use bindings::sui::coin;
+
+const BINDINGS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/bindings/framework.rs");
+
+/// A phantom type argument, which does not implement serde's traits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Sui;
+
+fn ident(name: &str) -> Identifier {
+    Identifier::new(name).unwrap()
+}
+
+fn datatype(address: Address, module: &str, name: &str, type_arguments: Vec<OpenType>) -> OpenType {
+    OpenType::Datatype {
+        address,
+        module: ident(module),
+        name: ident(name),
+        type_arguments,
+    }
+}
+
+include!("../../sui-sdk-types/src/types/transaction/fixtures/genesis.rs");
+
+/// The 0x2 framework package published in the genesis transaction.
+fn sui_framework() -> MovePackage {
+    genesis_transaction_fixture()
+        .objects
+        .iter()
+        .find_map(|object| match object.data() {
+            ObjectData::Package(package) if package.id == ObjectId::from(Address::TWO) => {
+                Some(package.clone())
+            }
+            _ => None,
+        })
+        .unwrap()
+}
+
+/// A hand written module exercising enums, keywords and references to another package.
+fn shapes_module() -> Module {
+    let address = "0x42".parse().unwrap();
+    let phantom = DatatypeTypeParameter {
+        constraints: AbilitySet::EMPTY,
+        is_phantom: true,
+    };
+    let field = |name: &str, type_| Field {
+        name: ident(name),
+        type_,
+    };
+    let shape = datatype(address, "shapes", "Shape", vec![OpenType::TypeParameter(0)]);
+    let canvas = datatype(
+        address,
+        "shapes",
+        "Canvas",
+        vec![OpenType::TypeParameter(0)],
+    );
+    let tx_context = datatype(Address::TWO, "tx_context", "TxContext", vec![]);
+
+    let shape_enum = Enum {
+        abilities: [Ability::Copy, Ability::Drop, Ability::Store]
+            .into_iter()
+            .collect(),
+        type_parameters: vec![phantom],
+        variants: vec![
+            Variant {
+                name: ident("Circle"),
+                fields: vec![field("radius", OpenType::U64)],
+            },
+            Variant {
+                name: ident("Rectangle"),
+                fields: vec![
+                    field("width", OpenType::U64),
+                    field("height", OpenType::U64),
+                ],
+            },
+            Variant {
+                name: ident("Empty"),
+                fields: vec![],
+            },
+        ],
+    };
+    let canvas_struct = Struct {
+        abilities: [Ability::Key].into_iter().collect(),
+        type_parameters: vec![phantom],
+        fields: vec![
+            field("id", datatype(Address::TWO, "object", "UID", vec![])),
+            field("shapes", OpenType::Vector(Box::new(shape.clone()))),
+            field(
+                "type",
+                datatype(
+                    "0x1".parse().unwrap(),
+                    "option",
+                    "Option",
+                    vec![OpenType::Address],
+                ),
+            ),
+            field(
+                "fee",
+                datatype(
+                    Address::TWO,
+                    "coin",
+                    "Coin",
+                    vec![OpenType::TypeParameter(0)],
+                ),
+            ),
+        ],
+    };
+    let function = |visibility, is_entry, parameters, return_| Function {
+        visibility,
+        is_entry,
+        type_parameters: vec![AbilitySet::EMPTY],
+        parameters,
+        return_,
+    };
+
+    Module {
+        address,
+        name: ident("shapes"),
+        file_format_version: 7,
+        friends: vec![],
+        structs: [(ident("Canvas"), canvas_struct)].into_iter().collect(),
+        enums: [(ident("Shape"), shape_enum)].into_iter().collect(),
+        functions: [
+            (
+                ident("area"),
+                function(
+                    Visibility::Public,
+                    false,
+                    vec![OpenType::Reference(Box::new(shape.clone()))],
+                    vec![OpenType::U64],
+                ),
+            ),
+            (
+                ident("draw"),
+                function(
+                    Visibility::Private,
+                    true,
+                    vec![
+                        OpenType::MutableReference(Box::new(canvas)),
+                        shape,
+                        OpenType::MutableReference(Box::new(tx_context)),
+                    ],
+                    vec![],
+                ),
+            ),
+            (
+                ident("move"),
+                function(Visibility::Public, false, vec![], vec![]),
+            ),
+            (
+                ident("internal"),
+                function(Visibility::Friend, false, vec![], vec![]),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+fn generator() -> Generator {
+    let mut sui = sui_framework();
+    sui.modules = ["balance", "coin", "url"]
+        .into_iter()
+        .map(|name| (ident(name), sui.modules[&ident(name)].clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut generator = Generator::new();
+    generator.package("sui", &sui).unwrap().modules(
+        "example",
+        "0x42".parse().unwrap(),
+        [shapes_module()],
+    );
+    generator
+}
+
+#[test]
+fn bindings_are_up_to_date() {
+    let generated = generator().generate().unwrap();
+    if std::env::var_os("UPDATE_BINDINGS").is_some() {
+        std::fs::write(BINDINGS, &generated).unwrap();
+    }
+    assert!(
+        generated == std::fs::read_to_string(BINDINGS).unwrap(),
+        "generated bindings are out of date, rerun with UPDATE_BINDINGS=1 to update them"
+    );
+}
+
+#[test]
+fn unresolved_type() {
+    let error = Generator::new()
+        .modules("example", "0x42".parse().unwrap(), [shapes_module()])
+        .generate()
+        .unwrap_err();
+    assert!(
+        matches!(&error, Error::UnsupportedType { type_, .. } if type_.ends_with("::coin::Coin")),
+        "{error}"
+    );
+}
+
+#[test]
+fn struct_bcs() {
+    let coin = coin::Coin::<Sui> {
+        id: ObjectId::ZERO,
+        balance: balance::Balance {
+            value: 7,
+            _phantom: PhantomData,
+        },
+    };
+
+    let bytes = bcs::to_bytes(&coin).unwrap();
+    let mut expected = vec![0; 32];
+    expected.extend(7u64.to_le_bytes());
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<coin::Coin<Sui>>(&bytes).unwrap(), coin);
+}
+
+#[test]
+fn enum_bcs() {
+    let rectangle = shapes::Shape::<Sui>::Rectangle {
+        width: 2,
+        height: 3,
+    };
+
+    let bytes = bcs::to_bytes(&rectangle).unwrap();
+    let mut expected = vec![1];
+    expected.extend(2u64.to_le_bytes());
+    expected.extend(3u64.to_le_bytes());
+    assert_eq!(bytes, expected);
+    assert_eq!(
+        bcs::from_bytes::<shapes::Shape<Sui>>(&bytes).unwrap(),
+        rectangle
+    );
+    assert_eq!(
+        bcs::to_bytes(&shapes::Shape::<Sui>::Empty).unwrap(),
+        vec![2]
+    );
+}
+
+#[test]
+fn struct_tags() {
+    let sui: TypeTag = "0x2::sui::SUI".parse().unwrap();
+    assert_eq!(
+        coin::Coin::<Sui>::struct_tag(sui.clone()),
+        "0x2::coin::Coin<0x2::sui::SUI>"
+            .parse::<StructTag>()
+            .unwrap()
+    );
+    assert_eq!(
+        shapes::Shape::<Sui>::struct_tag(sui),
+        "0x42::shapes::Shape<0x2::sui::SUI>"
+            .parse::<StructTag>()
+            .unwrap()
+    );
+    assert_eq!(
+        bindings::sui::url::Url::struct_tag(),
+        "0x2::url::Url".parse::<StructTag>().unwrap()
+    );
+}
+
+#[test]
+fn move_calls() {
+    let sui: TypeTag = "0x2::sui::SUI".parse().unwrap();
+
+    let call = coin::value(sui.clone(), Argument::Input(0));
+    assert_eq!(call.package, ObjectId::from(Address::TWO));
+    assert_eq!(call.module, ident("coin"));
+    assert_eq!(call.function, ident("value"));
+    assert_eq!(call.type_arguments, vec![sui.clone()]);
+    assert_eq!(call.arguments, vec![Argument::Input(0)]);
+
+    // The trailing `TxContext` is provided by the runtime
+    let call = shapes::draw(sui, Argument::Input(0), Argument::Result(1));
+    assert_eq!(call.package, "0x42".parse().unwrap());
+    assert_eq!(call.function, ident("draw"));
+    assert_eq!(
+        call.arguments,
+        vec![Argument::Input(0), Argument::Result(1)]
+    );
+
+    let call = shapes::r#move(TypeTag::U8);
+    assert_eq!(call.function, ident("move"));
+}
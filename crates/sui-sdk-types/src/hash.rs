@@ -160,6 +160,48 @@ impl crate::types::MultisigCommittee {
     }
 }
 
+impl crate::types::MovePackage {
+    /// Compute the digest of a package made up of `modules` and linked against `dependencies`.
+    ///
+    /// This is the digest an `UpgradeTicket` is authorized for, defined as the hash of the sorted
+    /// concatenation of the hash of each module and the id of each dependency.
+    pub fn compute_digest<M, D>(modules: M, dependencies: D) -> Digest
+    where
+        M: IntoIterator,
+        M::Item: AsRef<[u8]>,
+        D: IntoIterator,
+        D::Item: std::borrow::Borrow<crate::types::ObjectId>,
+    {
+        use std::borrow::Borrow;
+
+        let mut components = modules
+            .into_iter()
+            .map(|module| Hasher::digest(module).into_inner())
+            .chain(
+                dependencies
+                    .into_iter()
+                    .map(|dependency| dependency.borrow().into_inner()),
+            )
+            .collect::<Vec<_>>();
+        components.sort();
+
+        let mut hasher = Hasher::new();
+        for component in components {
+            hasher.update(component);
+        }
+        hasher.finalize()
+    }
+
+    /// The digest of this package, computed from its modules and the packages in its linkage
+    /// table.
+    pub fn digest(&self) -> Digest {
+        Self::compute_digest(
+            self.modules.values(),
+            self.linkage_table.values().map(|info| info.upgraded_id),
+        )
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod type_digest {
@@ -238,43 +280,31 @@ mod type_digest {
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 mod signing_message {
     use crate::hash::Hasher;
-    use crate::types::Digest;
     use crate::types::Intent;
-    use crate::types::IntentAppId;
+    use crate::types::IntentMessage;
     use crate::types::IntentScope;
-    use crate::types::IntentVersion;
     use crate::types::PersonalMessage;
     use crate::types::SigningDigest;
     use crate::types::Transaction;
 
-    impl Transaction {
+    impl<T: serde::Serialize> IntentMessage<T> {
+        /// The digest of this intent message, which is what a user signature commits to.
         pub fn signing_digest(&self) -> SigningDigest {
-            const INTENT: Intent = Intent {
-                scope: IntentScope::TransactionData,
-                version: IntentVersion::V0,
-                app_id: IntentAppId::Sui,
-            };
-            let digest = signing_digest(INTENT, self);
-            digest.into_inner()
+            let mut hasher = Hasher::new();
+            bcs::serialize_into(&mut hasher, self).unwrap();
+            hasher.finalize().into_inner()
         }
     }
 
-    fn signing_digest<T: serde::Serialize + ?Sized>(intent: Intent, ty: &T) -> Digest {
-        let mut hasher = Hasher::new();
-        hasher.update(intent.to_bytes());
-        bcs::serialize_into(&mut hasher, ty).unwrap();
-        hasher.finalize()
+    impl Transaction {
+        pub fn signing_digest(&self) -> SigningDigest {
+            IntentMessage::new(Intent::sui_app(IntentScope::TransactionData), self).signing_digest()
+        }
     }
 
     impl<'a> PersonalMessage<'a> {
         pub fn signing_digest(&self) -> SigningDigest {
-            const INTENT: Intent = Intent {
-                scope: IntentScope::PersonalMessage,
-                version: IntentVersion::V0,
-                app_id: IntentAppId::Sui,
-            };
-            let digest = signing_digest(INTENT, &self.0);
-            digest.into_inner()
+            IntentMessage::new(Intent::sui_app(IntentScope::PersonalMessage), self).signing_digest()
         }
     }
 }
@@ -329,6 +359,44 @@ impl crate::types::ObjectId {
 
         Self::new(digest.into_inner())
     }
+
+    /// Derive the ObjectId of the dynamic field of this object named `name`, a value of the Move
+    /// type `name_type`.
+    ///
+    /// `name` is BCS serialized, and so must have the same layout as a value of `name_type`.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+    pub fn derive_dynamic_field_id<T: serde::Serialize + ?Sized>(
+        &self,
+        name_type: &crate::types::TypeTag,
+        name: &T,
+    ) -> Result<Self, bcs::Error> {
+        let name_bytes = bcs::to_bytes(name)?;
+        Ok(self.derive_dynamic_child_id(name_type, &name_bytes))
+    }
+
+    /// Derive the ObjectId of the `0x2::dynamic_field::Field` of the dynamic object field of this
+    /// object named `name`, a value of the Move type `name_type`.
+    ///
+    /// The name of a dynamic object field is wrapped in a `0x2::dynamic_object_field::Wrapper`,
+    /// and the value of its field is the ObjectId of the object stored in the dynamic object
+    /// field.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+    pub fn derive_dynamic_object_field_id<T: serde::Serialize + ?Sized>(
+        &self,
+        name_type: &crate::types::TypeTag,
+        name: &T,
+    ) -> Result<Self, bcs::Error> {
+        use crate::types::StructTag;
+        use crate::types::TypeTag;
+
+        // A `Wrapper` has the same BCS layout as the name it wraps
+        let wrapper_type = TypeTag::Struct(Box::new(StructTag::dynamic_object_field_wrapper(
+            name_type.clone(),
+        )));
+        self.derive_dynamic_field_id(&wrapper_type, name)
+    }
 }
 
 #[cfg(test)]
@@ -364,4 +432,103 @@ mod test {
     fn roundtrip_hashing_intent(intent: HashingIntent) {
         assert_eq!(Ok(intent), HashingIntent::from_byte(intent as u8));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn genesis_dynamic_field_ids() {
+        use crate::types::framework::Field;
+        use crate::types::Address;
+        use crate::types::Object;
+        use crate::types::ObjectData;
+        use crate::types::ObjectId;
+        use crate::types::Owner;
+        use crate::types::TransactionDigest;
+        use crate::types::TypeTag;
+
+        /// Check that the id of a dynamic field can be derived from its parent and name
+        fn check<K, V>(
+            object: &Object,
+            parent: &ObjectId,
+            name_type: &TypeTag,
+            value_type: &TypeTag,
+        ) where
+            K: serde::de::DeserializeOwned + serde::Serialize,
+            V: serde::de::DeserializeOwned,
+        {
+            // Fields of other types are rejected
+            assert!(Field::<K, V>::try_from_object(object, name_type, &TypeTag::Bool).is_none());
+
+            let field = Field::<K, V>::try_from_object(object, name_type, value_type).unwrap();
+            assert_eq!(field.id, object.object_id());
+            assert_eq!(
+                parent
+                    .derive_dynamic_field_id(name_type, &field.name)
+                    .unwrap(),
+                field.id
+            );
+        }
+
+        let genesis = crate::types::genesis_transaction_fixture();
+
+        let mut count = 0;
+        for object in &genesis.objects {
+            let Owner::Object(parent) = *object.owner() else {
+                continue;
+            };
+            let ObjectData::Struct(move_struct) = object.data() else {
+                continue;
+            };
+            let Some((name_type, value_type)) = move_struct.object_type().is_dynamic_field() else {
+                continue;
+            };
+            let object = Object::new(
+                object.data().clone(),
+                *object.owner(),
+                TransactionDigest::ZERO,
+                0,
+            );
+
+            match name_type {
+                // `staking_pool::PoolTokenExchangeRate`s keyed by epoch
+                TypeTag::U64 if move_struct.contents().len() == 56 => {
+                    check::<u64, (u64, u64)>(&object, &parent, name_type, value_type)
+                }
+                // validator addresses keyed by staking pool id
+                TypeTag::Struct(_) => {
+                    check::<ObjectId, Address>(&object, &parent, name_type, value_type)
+                }
+                _ => continue,
+            }
+            count += 1;
+        }
+        assert!(count > 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn dynamic_object_field_id() {
+        use crate::types::ObjectId;
+        use crate::types::TypeTag;
+
+        // Computed independently as blake2b-256 of `0xf0 || parent || len(name) || name ||
+        // bcs(0x2::dynamic_object_field::Wrapper<u64>)`, with the name the BCS encoding of 42u64
+        let expected: ObjectId =
+            "0x9be1d7ea82c058ca4182b81ae28b0b1ffe6adc0680c8e00b98da0471def51adc"
+                .parse()
+                .unwrap();
+
+        let parent = ObjectId::new([7; 32]);
+        let id = parent
+            .derive_dynamic_object_field_id(&TypeTag::U64, &42u64)
+            .unwrap();
+        assert_eq!(id, expected);
+
+        // Dynamic object fields don't share ids with dynamic fields of the same name
+        assert_ne!(
+            id,
+            parent
+                .derive_dynamic_field_id(&TypeTag::U64, &42u64)
+                .unwrap()
+        );
+    }
 }
@@ -9,6 +9,10 @@
 /// The serialization of an Intent is a 3-byte array where each field is represented by a byte and
 /// it is prepended onto a message before it is signed in Sui.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct Intent {
     pub scope: IntentScope,
     pub version: IntentVersion,
@@ -24,6 +28,16 @@ impl Intent {
         }
     }
 
+    /// Construct an intent with the provided scope for the Sui application, using the current
+    /// intent version.
+    pub const fn sui_app(scope: IntentScope) -> Self {
+        Self {
+            scope,
+            version: IntentVersion::V0,
+            app_id: IntentAppId::Sui,
+        }
+    }
+
     pub fn to_bytes(self) -> [u8; 3] {
         [self.scope as u8, self.version as u8, self.app_id as u8]
     }
@@ -71,3 +85,249 @@ pub enum IntentAppId {
     Narwhal = 1,
     Consensus = 2,
 }
+
+/// A message prefixed with the [`Intent`] it is intended for.
+///
+/// The BCS serialization of an `IntentMessage` is the 3-byte serialization of its intent followed
+/// by the BCS serialization of its value, and is what is hashed to produce the digest which a
+/// signature commits to (see [`IntentMessage::signing_digest`]).
+///
+/// Signatures produced by validators, such as those over a [`CheckpointSummary`], additionally
+/// commit to the epoch in which they were produced by appending the little-endian bytes of the
+/// epoch to the serialized intent message before signing.
+///
+/// [`CheckpointSummary`]: crate::types::CheckpointSummary
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct IntentMessage<T> {
+    pub intent: Intent,
+    pub value: T,
+}
+
+impl<T> IntentMessage<T> {
+    pub fn new(intent: Intent, value: T) -> Self {
+        Self { intent, value }
+    }
+
+    pub fn intent(&self) -> Intent {
+        self.intent
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl IntentMessage<crate::types::Transaction> {
+    /// An intent message for a user signature over a transaction.
+    pub fn transaction_data(transaction: crate::types::Transaction) -> Self {
+        Self::new(Intent::sui_app(IntentScope::TransactionData), transaction)
+    }
+}
+
+impl<'a> IntentMessage<crate::types::PersonalMessage<'a>> {
+    /// An intent message for a user signature over a personal message.
+    pub fn personal_message(message: crate::types::PersonalMessage<'a>) -> Self {
+        Self::new(Intent::sui_app(IntentScope::PersonalMessage), message)
+    }
+}
+
+impl IntentMessage<crate::types::CheckpointSummary> {
+    /// An intent message for a validator signature over a checkpoint summary.
+    pub fn checkpoint_summary(summary: crate::types::CheckpointSummary) -> Self {
+        Self::new(Intent::sui_app(IntentScope::CheckpointSummary), summary)
+    }
+}
+
+impl IntentMessage<crate::types::SenderSignedData> {
+    /// An intent message for a validator signature over a transaction signed by its sender.
+    pub fn sender_signed_transaction(transaction: crate::types::SignedTransaction) -> Self {
+        Self::new(
+            Intent::sui_app(IntentScope::SenderSignedTransaction),
+            crate::types::SenderSignedData(transaction),
+        )
+    }
+}
+
+impl IntentMessage<Vec<u8>> {
+    /// An intent message for a validator's proof of possession of its protocol key, which is a
+    /// signature over the validator's protocol public key followed by its account address.
+    ///
+    /// Proofs of possession are always signed for epoch `0`.
+    pub fn proof_of_possession(
+        protocol_public_key: &crate::types::Bls12381PublicKey,
+        address: crate::types::Address,
+    ) -> Self {
+        let mut message = protocol_public_key.inner().to_vec();
+        message.extend_from_slice(address.as_ref());
+        Self::new(Intent::sui_app(IntentScope::ProofOfPossession), message)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<T: serde::Serialize> IntentMessage<T> {
+    /// Serialize this intent message using BCS.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("serialization should not fail")
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<T: serde::de::DeserializeOwned> IntentMessage<T> {
+    /// Deserialize an intent message from its BCS serialization.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+        bcs::from_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+mod serialization {
+    use super::*;
+
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    macro_rules! impl_u8_serde {
+        ($ty:ident { $($variant:ident),* $(,)? }) => {
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    (*self as u8).serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let byte = u8::deserialize(deserializer)?;
+                    $(
+                        if byte == $ty::$variant as u8 {
+                            return Ok($ty::$variant);
+                        }
+                    )*
+                    Err(serde::de::Error::custom(format_args!(
+                        "invalid {} {byte}",
+                        stringify!($ty),
+                    )))
+                }
+            }
+        };
+    }
+
+    impl_u8_serde!(IntentScope {
+        TransactionData,
+        TransactionEffects,
+        CheckpointSummary,
+        PersonalMessage,
+        SenderSignedTransaction,
+        ProofOfPossession,
+        HeaderDigest,
+        BridgeEventUnused,
+        ConsensusBlock,
+    });
+    impl_u8_serde!(IntentVersion { V0 });
+    impl_u8_serde!(IntentAppId {
+        Sui,
+        Narwhal,
+        Consensus
+    });
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use crate::types::PersonalMessage;
+        use crate::types::SignedTransaction;
+        use crate::types::Transaction;
+        use base64ct::Base64;
+        use base64ct::Encoding;
+
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::wasm_bindgen_test as test;
+
+        #[test]
+        fn intent_bytes() {
+            let intent = Intent::sui_app(IntentScope::PersonalMessage);
+            assert_eq!(bcs::to_bytes(&intent).unwrap(), intent.to_bytes());
+            assert_eq!(bcs::from_bytes::<Intent>(&[3, 0, 0]).unwrap(), intent);
+
+            bcs::from_bytes::<Intent>(&[9, 0, 0]).unwrap_err();
+            bcs::from_bytes::<Intent>(&[0, 1, 0]).unwrap_err();
+            bcs::from_bytes::<Intent>(&[0, 0, 3]).unwrap_err();
+        }
+
+        #[test]
+        fn personal_message() {
+            let message = IntentMessage::personal_message(PersonalMessage(b"hello".into()));
+            assert_eq!(
+                message.to_bytes(),
+                [3, 0, 0, 5, b'h', b'e', b'l', b'l', b'o']
+            );
+            assert_eq!(
+                IntentMessage::<PersonalMessage>::from_bytes(&message.to_bytes()).unwrap(),
+                message
+            );
+        }
+
+        #[test]
+        fn transaction_fixtures() {
+            const FIXTURES: &[&str] = &[
+                include_str!("../transaction/fixtures/genesis-transaction"),
+                include_str!("../transaction/fixtures/authenticator_state_update"),
+                include_str!("../transaction/fixtures/wormhole-pyth-transaction"),
+            ];
+
+            for fixture in FIXTURES {
+                let fixture = Base64::decode_vec(fixture.trim()).unwrap();
+                let transaction: Transaction = bcs::from_bytes(&fixture).unwrap();
+
+                let message = IntentMessage::transaction_data(transaction.clone());
+                let bytes = message.to_bytes();
+                assert_eq!(bytes[..3], [0, 0, 0]);
+                assert_eq!(bytes[3..], fixture);
+                assert_eq!(
+                    IntentMessage::<Transaction>::from_bytes(&bytes).unwrap(),
+                    message
+                );
+
+                // The sender signed form of a transaction embeds the transaction's own intent
+                // message
+                let signed = SignedTransaction {
+                    transaction,
+                    signatures: vec![],
+                };
+                let message = IntentMessage::sender_signed_transaction(signed.clone());
+                let bytes = message.to_bytes();
+                assert_eq!(bytes[..3], [4, 0, 0]);
+                // A vector of a single sender signed transaction
+                assert_eq!(bytes[3], 1);
+                assert_eq!(bytes[4..7], [0, 0, 0]);
+                assert_eq!(bytes[7..7 + fixture.len()], fixture);
+                let decoded =
+                    IntentMessage::<crate::types::SenderSignedData>::from_bytes(&bytes).unwrap();
+                assert_eq!(decoded.value.0, signed);
+
+                // Intent messages with an unknown scope can't be decoded
+                IntentMessage::<Transaction>::from_bytes(
+                    &[&[9, 0, 0], fixture.as_slice()].concat(),
+                )
+                .unwrap_err();
+            }
+        }
+    }
+}
@@ -15,6 +15,7 @@ pub use ed25519::Ed25519PublicKey;
 pub use ed25519::Ed25519Signature;
 pub use intent::Intent;
 pub use intent::IntentAppId;
+pub use intent::IntentMessage;
 pub use intent::IntentScope;
 pub use intent::IntentVersion;
 pub use multisig::MultisigAggregatedSignature;
@@ -1,11 +1,14 @@
 //! Rust definitions of move/sui framework types.
 
+use super::Address;
+use super::MoveStruct;
 use super::Object;
 use super::ObjectId;
+use super::StructTag;
 use super::TypeTag;
 use std::borrow::Cow;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Coin<'a> {
     coin_type: Cow<'a, TypeTag>,
     id: ObjectId,
@@ -57,3 +60,1194 @@ impl<'a> Coin<'a> {
         }
     }
 }
+
+/// A balance of a coin, `0x2::balance::Balance<T>`.
+///
+/// A `Balance` does not have the `key` ability, and so is never an object itself, but is
+/// commonly stored as the value of a dynamic field, e.g. in a `0x2::bag::Bag`.
+#[derive(Debug, Clone)]
+pub struct Balance<'a> {
+    coin_type: Cow<'a, TypeTag>,
+    value: u64,
+}
+
+impl<'a> Balance<'a> {
+    pub fn coin_type(&self) -> &TypeTag {
+        &self.coin_type
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Decode a balance from a dynamic field object whose value is a `Balance<T>`.
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let (_, value_type) = move_struct.type_.is_dynamic_field()?;
+        let TypeTag::Struct(value_type) = value_type else {
+            return None;
+        };
+        let [coin_type] = framework_type(value_type, Address::TWO, "balance", "Balance")? else {
+            return None;
+        };
+
+        // The value is the last field of a `Field`, so can be read without knowing the layout of
+        // the field's name
+        let contents = &move_struct.contents;
+        let value = contents.get(contents.len().checked_sub(8)?..)?;
+
+        Some(Self {
+            coin_type: Cow::Borrowed(coin_type),
+            value: u64::from_le_bytes(value.try_into().unwrap()),
+        })
+    }
+
+    pub fn into_owned(self) -> Balance<'static> {
+        Balance {
+            coin_type: Cow::Owned(self.coin_type.into_owned()),
+            value: self.value,
+        }
+    }
+}
+
+/// A capability to mint and burn a coin, `0x2::coin::TreasuryCap<T>`.
+#[derive(Debug, Clone)]
+pub struct TreasuryCap<'a> {
+    coin_type: Cow<'a, TypeTag>,
+    id: ObjectId,
+    total_supply: u64,
+}
+
+impl<'a> TreasuryCap<'a> {
+    pub fn coin_type(&self) -> &TypeTag {
+        &self.coin_type
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    pub fn total_supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [coin_type] = framework_type(&move_struct.type_, Address::TWO, "coin", "TreasuryCap")?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let total_supply = reader.u64()?;
+        reader.finish()?;
+
+        Some(Self {
+            coin_type: Cow::Borrowed(coin_type),
+            id,
+            total_supply,
+        })
+    }
+
+    pub fn into_owned(self) -> TreasuryCap<'static> {
+        TreasuryCap {
+            coin_type: Cow::Owned(self.coin_type.into_owned()),
+            id: self.id,
+            total_supply: self.total_supply,
+        }
+    }
+}
+
+/// The metadata of a coin, `0x2::coin::CoinMetadata<T>`.
+#[derive(Debug, Clone)]
+pub struct CoinMetadata<'a> {
+    coin_type: Cow<'a, TypeTag>,
+    id: ObjectId,
+    decimals: u8,
+    name: Cow<'a, str>,
+    symbol: Cow<'a, str>,
+    description: Cow<'a, str>,
+    icon_url: Option<Cow<'a, str>>,
+}
+
+impl<'a> CoinMetadata<'a> {
+    pub fn coin_type(&self) -> &TypeTag {
+        &self.coin_type
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn icon_url(&self) -> Option<&str> {
+        self.icon_url.as_deref()
+    }
+
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [coin_type] = framework_type(&move_struct.type_, Address::TWO, "coin", "CoinMetadata")?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let decimals = reader.u8()?;
+        let name = reader.str()?;
+        let symbol = reader.str()?;
+        let description = reader.str()?;
+        // `0x2::url::Url` wraps a single ascii string
+        let icon_url = reader.option(Reader::str)?;
+        reader.finish()?;
+
+        Some(Self {
+            coin_type: Cow::Borrowed(coin_type),
+            id,
+            decimals,
+            name: Cow::Borrowed(name),
+            symbol: Cow::Borrowed(symbol),
+            description: Cow::Borrowed(description),
+            icon_url: icon_url.map(Cow::Borrowed),
+        })
+    }
+
+    pub fn into_owned(self) -> CoinMetadata<'static> {
+        CoinMetadata {
+            coin_type: Cow::Owned(self.coin_type.into_owned()),
+            id: self.id,
+            decimals: self.decimals,
+            name: Cow::Owned(self.name.into_owned()),
+            symbol: Cow::Owned(self.symbol.into_owned()),
+            description: Cow::Owned(self.description.into_owned()),
+            icon_url: self.icon_url.map(|url| Cow::Owned(url.into_owned())),
+        }
+    }
+}
+
+/// SUI staked with a validator's staking pool, `0x3::staking_pool::StakedSui`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StakedSui {
+    id: ObjectId,
+    pool_id: ObjectId,
+    stake_activation_epoch: u64,
+    principal: u64,
+}
+
+impl StakedSui {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The id of the staking pool the stake is in.
+    pub fn pool_id(&self) -> &ObjectId {
+        &self.pool_id
+    }
+
+    /// The epoch at which the stake becomes active.
+    pub fn stake_activation_epoch(&self) -> u64 {
+        self.stake_activation_epoch
+    }
+
+    /// The amount of SUI staked, in MIST.
+    pub fn principal(&self) -> u64 {
+        self.principal
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(
+            &move_struct.type_,
+            Address::THREE,
+            "staking_pool",
+            "StakedSui",
+        )?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let pool_id = reader.id()?;
+        let stake_activation_epoch = reader.u64()?;
+        let principal = reader.u64()?;
+        reader.finish()?;
+
+        Some(Self {
+            id,
+            pool_id,
+            stake_activation_epoch,
+            principal,
+        })
+    }
+
+    /// Estimate the rewards earned by this stake, in MIST, given the exchange rate of its staking
+    /// pool at the epoch the stake was activated and at the current epoch.
+    pub fn estimated_reward(
+        &self,
+        activation_rate: &PoolTokenExchangeRate,
+        current_rate: &PoolTokenExchangeRate,
+    ) -> u64 {
+        let pool_tokens = activation_rate.pool_token_amount(self.principal);
+        current_rate
+            .sui_amount(pool_tokens)
+            .saturating_sub(self.principal)
+    }
+}
+
+/// The exchange rate between SUI and the pool tokens of a staking pool at an epoch,
+/// `0x3::staking_pool::PoolTokenExchangeRate`.
+///
+/// The exchange rates of a pool are stored in a table keyed by epoch, and the rate of the current
+/// epoch is also given by the current SUI and pool token balances of the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolTokenExchangeRate {
+    pub sui_amount: u64,
+    pub pool_token_amount: u64,
+}
+
+impl PoolTokenExchangeRate {
+    /// The amount of SUI that `pool_tokens` pool tokens are worth at this rate.
+    pub fn sui_amount(&self, pool_tokens: u64) -> u64 {
+        self.convert(pool_tokens, self.sui_amount, self.pool_token_amount)
+    }
+
+    /// The amount of pool tokens that `sui` MIST are worth at this rate.
+    pub fn pool_token_amount(&self, sui: u64) -> u64 {
+        self.convert(sui, self.pool_token_amount, self.sui_amount)
+    }
+
+    fn convert(&self, amount: u64, numerator: u64, denominator: u64) -> u64 {
+        // A pool without any stake exchanges at 1:1
+        if self.sui_amount == 0 || self.pool_token_amount == 0 {
+            return amount;
+        }
+        let converted = u128::from(amount) * u128::from(numerator) / u128::from(denominator);
+        converted.try_into().unwrap_or(u64::MAX)
+    }
+}
+
+/// The capability to upgrade a package, `0x2::package::UpgradeCap`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeCap {
+    id: ObjectId,
+    package: ObjectId,
+    version: u64,
+    policy: u8,
+}
+
+impl UpgradeCap {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The id of the latest version of the package.
+    pub fn package(&self) -> &ObjectId {
+        &self.package
+    }
+
+    /// The number of upgrades that have been applied to the package.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// The most permissive upgrade policy allowed: `0` for compatible, `128` for additive and
+    /// `192` for dependency only upgrades.
+    pub fn policy(&self) -> u8 {
+        self.policy
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(&move_struct.type_, Address::TWO, "package", "UpgradeCap")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let package = reader.id()?;
+        let version = reader.u64()?;
+        let policy = reader.u8()?;
+        reader.finish()?;
+
+        Some(Self {
+            id,
+            package,
+            version,
+            policy,
+        })
+    }
+}
+
+/// The shared clock object, `0x2::clock::Clock`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clock {
+    id: ObjectId,
+    timestamp_ms: u64,
+}
+
+impl Clock {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The timestamp of the start of the current consensus commit, in milliseconds since the
+    /// Unix epoch.
+    pub fn timestamp_ms(&self) -> u64 {
+        self.timestamp_ms
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(&move_struct.type_, Address::TWO, "clock", "Clock")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let timestamp_ms = reader.u64()?;
+        reader.finish()?;
+
+        Some(Self { id, timestamp_ms })
+    }
+}
+
+/// A kiosk, `0x2::kiosk::Kiosk`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Kiosk {
+    id: ObjectId,
+    profits: u64,
+    owner: Address,
+    item_count: u32,
+    allow_extensions: bool,
+}
+
+impl Kiosk {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The SUI collected from sales, in MIST.
+    pub fn profits(&self) -> u64 {
+        self.profits
+    }
+
+    /// The address of the kiosk's owner, as last set by the holder of its `KioskOwnerCap`.
+    pub fn owner(&self) -> &Address {
+        &self.owner
+    }
+
+    /// The number of items in the kiosk.
+    pub fn item_count(&self) -> u32 {
+        self.item_count
+    }
+
+    pub fn allow_extensions(&self) -> bool {
+        self.allow_extensions
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(&move_struct.type_, Address::TWO, "kiosk", "Kiosk")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let profits = reader.u64()?;
+        let owner = reader.address()?;
+        let item_count = reader.u32()?;
+        let allow_extensions = reader.bool()?;
+        reader.finish()?;
+
+        Some(Self {
+            id,
+            profits,
+            owner,
+            item_count,
+            allow_extensions,
+        })
+    }
+}
+
+/// The capability to manage a kiosk, `0x2::kiosk::KioskOwnerCap`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KioskOwnerCap {
+    id: ObjectId,
+    kiosk: ObjectId,
+}
+
+impl KioskOwnerCap {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The id of the kiosk this capability is for.
+    pub fn kiosk(&self) -> &ObjectId {
+        &self.kiosk
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(&move_struct.type_, Address::TWO, "kiosk", "KioskOwnerCap")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let kiosk = reader.id()?;
+        reader.finish()?;
+
+        Some(Self { id, kiosk })
+    }
+}
+
+/// The policy governing transfers of a type out of kiosks,
+/// `0x2::transfer_policy::TransferPolicy<T>`.
+#[derive(Debug, Clone)]
+pub struct TransferPolicy<'a> {
+    type_: Cow<'a, TypeTag>,
+    id: ObjectId,
+    balance: u64,
+    rules: Vec<Cow<'a, str>>,
+}
+
+impl<'a> TransferPolicy<'a> {
+    /// The type the policy applies to.
+    pub fn type_(&self) -> &TypeTag {
+        &self.type_
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The SUI collected from rule fees, in MIST.
+    pub fn balance(&self) -> u64 {
+        self.balance
+    }
+
+    /// The types of the rules that must be satisfied for a transfer, formatted as by
+    /// `0x1::type_name`, i.e. with full length addresses without a `0x` prefix.
+    pub fn rules(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(AsRef::as_ref)
+    }
+
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [type_] = framework_type(
+            &move_struct.type_,
+            Address::TWO,
+            "transfer_policy",
+            "TransferPolicy",
+        )?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let balance = reader.u64()?;
+        // A `VecSet<TypeName>`, where a `TypeName` wraps a single ascii string
+        let rules = reader.vector(Reader::str)?;
+        reader.finish()?;
+
+        Some(Self {
+            type_: Cow::Borrowed(type_),
+            id,
+            balance,
+            rules: rules.into_iter().map(Cow::Borrowed).collect(),
+        })
+    }
+
+    pub fn into_owned(self) -> TransferPolicy<'static> {
+        TransferPolicy {
+            type_: Cow::Owned(self.type_.into_owned()),
+            id: self.id,
+            balance: self.balance,
+            rules: self
+                .rules
+                .into_iter()
+                .map(|rule| Cow::Owned(rule.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+/// The capability to manage a transfer policy, `0x2::transfer_policy::TransferPolicyCap<T>`.
+#[derive(Debug, Clone)]
+pub struct TransferPolicyCap<'a> {
+    type_: Cow<'a, TypeTag>,
+    id: ObjectId,
+    policy_id: ObjectId,
+}
+
+impl<'a> TransferPolicyCap<'a> {
+    /// The type the policy applies to.
+    pub fn type_(&self) -> &TypeTag {
+        &self.type_
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The id of the `TransferPolicy` this capability is for.
+    pub fn policy_id(&self) -> &ObjectId {
+        &self.policy_id
+    }
+
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [type_] = framework_type(
+            &move_struct.type_,
+            Address::TWO,
+            "transfer_policy",
+            "TransferPolicyCap",
+        )?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let policy_id = reader.id()?;
+        reader.finish()?;
+
+        Some(Self {
+            type_: Cow::Borrowed(type_),
+            id,
+            policy_id,
+        })
+    }
+
+    pub fn into_owned(self) -> TransferPolicyCap<'static> {
+        TransferPolicyCap {
+            type_: Cow::Owned(self.type_.into_owned()),
+            id: self.id,
+            policy_id: self.policy_id,
+        }
+    }
+}
+
+/// The display template of a type, `0x2::display::Display<T>`.
+#[derive(Debug, Clone)]
+pub struct Display<'a> {
+    type_: Cow<'a, TypeTag>,
+    id: ObjectId,
+    fields: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    version: u16,
+}
+
+impl<'a> Display<'a> {
+    /// The type the display template applies to.
+    pub fn type_(&self) -> &TypeTag {
+        &self.type_
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The template of each field of the display, in the order they were added.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .map(|(name, template)| (name.as_ref(), template.as_ref()))
+    }
+
+    /// The version of the display, incremented every time its fields are published.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [type_] = framework_type(&move_struct.type_, Address::TWO, "display", "Display")?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        // A `VecMap<String, String>`
+        let fields = reader.vector(|reader| Some((reader.str()?, reader.str()?)))?;
+        let version = reader.u16()?;
+        reader.finish()?;
+
+        Some(Self {
+            type_: Cow::Borrowed(type_),
+            id,
+            fields: fields
+                .into_iter()
+                .map(|(name, template)| (Cow::Borrowed(name), Cow::Borrowed(template)))
+                .collect(),
+            version,
+        })
+    }
+
+    pub fn into_owned(self) -> Display<'static> {
+        Display {
+            type_: Cow::Owned(self.type_.into_owned()),
+            id: self.id,
+            fields: self
+                .fields
+                .into_iter()
+                .map(|(name, template)| {
+                    (
+                        Cow::Owned(name.into_owned()),
+                        Cow::Owned(template.into_owned()),
+                    )
+                })
+                .collect(),
+            version: self.version,
+        }
+    }
+}
+
+/// A map stored in dynamic fields of its id, `0x2::table::Table<K, V>`.
+///
+/// Only the header of the table is stored in the object, its entries are dynamic fields whose
+/// ids can be derived from the table's id and the entry's key.
+#[derive(Debug, Clone)]
+pub struct Table<'a> {
+    key_type: Cow<'a, TypeTag>,
+    value_type: Cow<'a, TypeTag>,
+    id: ObjectId,
+    size: u64,
+}
+
+impl<'a> Table<'a> {
+    pub fn key_type(&self) -> &TypeTag {
+        &self.key_type
+    }
+
+    pub fn value_type(&self) -> &TypeTag {
+        &self.value_type
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The number of entries in the table.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn try_from_object(object: &'a Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [key_type, value_type] =
+            framework_type(&move_struct.type_, Address::TWO, "table", "Table")?
+        else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let (id, size) = reader.collection()?;
+        reader.finish()?;
+
+        Some(Self {
+            key_type: Cow::Borrowed(key_type),
+            value_type: Cow::Borrowed(value_type),
+            id,
+            size,
+        })
+    }
+
+    pub fn into_owned(self) -> Table<'static> {
+        Table {
+            key_type: Cow::Owned(self.key_type.into_owned()),
+            value_type: Cow::Owned(self.value_type.into_owned()),
+            id: self.id,
+            size: self.size,
+        }
+    }
+}
+
+/// A heterogeneous map stored in dynamic fields of its id, `0x2::bag::Bag`.
+///
+/// Only the header of the bag is stored in the object, its entries are dynamic fields whose ids
+/// can be derived from the bag's id and the entry's key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    id: ObjectId,
+    size: u64,
+}
+
+impl Bag {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The number of entries in the bag.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(&move_struct.type_, Address::TWO, "bag", "Bag")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let (id, size) = reader.collection()?;
+        reader.finish()?;
+
+        Some(Self { id, size })
+    }
+}
+
+/// The shared deny list object, `0x2::deny_list::DenyList`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenyList {
+    id: ObjectId,
+    lists: Bag,
+}
+
+impl DenyList {
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The bag holding the deny list of each kind of denied type, see [`PerTypeList`].
+    pub fn lists(&self) -> &Bag {
+        &self.lists
+    }
+
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let [] = framework_type(&move_struct.type_, Address::TWO, "deny_list", "DenyList")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let id = reader.id()?;
+        let (bag_id, size) = reader.collection()?;
+        reader.finish()?;
+
+        Some(Self {
+            id,
+            lists: Bag { id: bag_id, size },
+        })
+    }
+}
+
+/// An entry of a [`DenyList`], `0x2::deny_list::PerTypeList`, holding the addresses denied for
+/// each type of a kind of types, e.g. for each regulated coin type.
+///
+/// The lists are stored in dynamic fields of the deny list's `lists` bag, keyed by the index of
+/// the kind of types they apply to (`0` for coins).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerTypeList {
+    per_type_index: u64,
+    id: ObjectId,
+    denied_count: ObjectId,
+    denied_addresses: ObjectId,
+}
+
+impl PerTypeList {
+    /// The index of the kind of types the list applies to.
+    pub fn per_type_index(&self) -> u64 {
+        self.per_type_index
+    }
+
+    pub fn id(&self) -> &ObjectId {
+        &self.id
+    }
+
+    /// The id of the `Table<address, u64>` holding the number of types each address is denied
+    /// for.
+    pub fn denied_count(&self) -> &ObjectId {
+        &self.denied_count
+    }
+
+    /// The id of the `Table<vector<u8>, VecSet<address>>` holding the addresses denied for each
+    /// type, keyed by the name of the type as formatted by `0x1::type_name`.
+    pub fn denied_addresses(&self) -> &ObjectId {
+        &self.denied_addresses
+    }
+
+    /// Decode a list from the dynamic field object storing it in the [`DenyList`]'s bag.
+    pub fn try_from_object(object: &Object) -> Option<Self> {
+        let move_struct = move_struct(object)?;
+        let (TypeTag::U64, TypeTag::Struct(value_type)) = move_struct.type_.is_dynamic_field()?
+        else {
+            return None;
+        };
+        let [] = framework_type(value_type, Address::TWO, "deny_list", "PerTypeList")? else {
+            return None;
+        };
+
+        let mut reader = Reader::new(&move_struct.contents);
+        let _field_id = reader.id()?;
+        let per_type_index = reader.u64()?;
+        let id = reader.id()?;
+        let (denied_count, _) = reader.collection()?;
+        let (denied_addresses, _) = reader.collection()?;
+        reader.finish()?;
+
+        Some(Self {
+            per_type_index,
+            id,
+            denied_count,
+            denied_addresses,
+        })
+    }
+}
+
+/// Returns the Move struct of `object`, or `None` if it is a package.
+fn move_struct(object: &Object) -> Option<&MoveStruct> {
+    match &object.data {
+        super::ObjectData::Struct(move_struct) => Some(move_struct),
+        super::ObjectData::Package(_) => None,
+    }
+}
+
+/// Returns the type parameters of `type_` if it is the struct `address::module::name`.
+fn framework_type<'a>(
+    type_: &'a StructTag,
+    address: Address,
+    module: &str,
+    name: &str,
+) -> Option<&'a [TypeTag]> {
+    (type_.address == address && type_.module == *module && type_.name == *name)
+        .then_some(type_.type_params.as_slice())
+}
+
+/// A cursor over the BCS serialized contents of a Move value, shared by the decoders of the
+/// framework and SuiNS types.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.bytes.len() {
+            return None;
+        }
+
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N).map(|bytes| bytes.try_into().unwrap())
+    }
+
+    pub(crate) fn u8(&mut self) -> Option<u8> {
+        self.array().map(u8::from_le_bytes)
+    }
+
+    pub(crate) fn u16(&mut self) -> Option<u16> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    pub(crate) fn u32(&mut self) -> Option<u32> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn u64(&mut self) -> Option<u64> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    pub(crate) fn bool(&mut self) -> Option<bool> {
+        match self.u8()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn address(&mut self) -> Option<Address> {
+        self.array().map(Address::new)
+    }
+
+    pub(crate) fn id(&mut self) -> Option<ObjectId> {
+        self.array().map(ObjectId::new)
+    }
+
+    /// The id and size of a `Table` or `Bag`.
+    pub(crate) fn collection(&mut self) -> Option<(ObjectId, u64)> {
+        Some((self.id()?, self.u64()?))
+    }
+
+    /// A ULEB128 encoded length, which BCS limits to `u32::MAX`.
+    pub(crate) fn length(&mut self) -> Option<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..32).step_by(7) {
+            let byte = self.u8()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return u32::try_from(value).ok().map(|len| len as usize);
+            }
+        }
+        None
+    }
+
+    pub(crate) fn str(&mut self) -> Option<&'a str> {
+        let len = self.length()?;
+        std::str::from_utf8(self.take(len)?).ok()
+    }
+
+    pub(crate) fn option<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Option<T>,
+    ) -> Option<Option<T>> {
+        match self.u8()? {
+            0 => Some(None),
+            1 => f(self).map(Some),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn vector<T>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> Option<T>,
+    ) -> Option<Vec<T>> {
+        let len = self.length()?;
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    /// Succeeds only if all of the contents have been read.
+    pub(crate) fn finish(self) -> Option<()> {
+        self.bytes.is_empty().then_some(())
+    }
+}
+
+/// A dynamic field, `0x2::dynamic_field::Field<K, V>`, storing the value `value` under the name
+/// `name` on its parent object.
+///
+/// The field of a dynamic object field has a name of type `0x2::dynamic_object_field::Wrapper<K>`,
+/// which has the same layout as `K`, and a value of type `0x2::object::ID`, the id of the object
+/// stored in the dynamic object field. It can be decoded as a `Field<K, ObjectId>`.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct Field<K, V> {
+    pub id: ObjectId,
+    pub name: K,
+    pub value: V,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<K, V> Field<K, V>
+where
+    K: serde::de::DeserializeOwned,
+    V: serde::de::DeserializeOwned,
+{
+    /// Decode a dynamic field from its object, returning `None` if the object is not a dynamic
+    /// field with a name of the Move type `name_type` and a value of the Move type `value_type`,
+    /// or if its name and value cannot be decoded as a `K` and `V`.
+    ///
+    /// `K` and `V` must have the same layout as `name_type` and `value_type`.
+    pub fn try_from_object(
+        object: &Object,
+        name_type: &TypeTag,
+        value_type: &TypeTag,
+    ) -> Option<Self> {
+        match &object.data {
+            super::ObjectData::Struct(move_struct) => {
+                let (name, value) = move_struct.type_.is_dynamic_field()?;
+                if name != name_type || value != value_type {
+                    return None;
+                }
+                bcs::from_bytes(&move_struct.contents).ok()
+            }
+            _ => None, // package
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::MoveStruct;
+    use crate::types::ObjectData;
+    use crate::types::Owner;
+    use crate::types::TransactionDigest;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn move_object(type_: &str, contents: Vec<u8>) -> Object {
+        let move_struct = MoveStruct::new(type_.parse().unwrap(), true, 1, contents).unwrap();
+        Object::new(
+            ObjectData::Struct(move_struct),
+            Owner::Immutable,
+            TransactionDigest::ZERO,
+            0,
+        )
+    }
+
+    fn id(byte: u8) -> ObjectId {
+        ObjectId::new([byte; ObjectId::LENGTH])
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn genesis_objects() {
+        let genesis = crate::types::genesis_transaction_fixture();
+
+        let mut clocks = 0;
+        let mut stakes = 0;
+        let mut metadata = 0;
+        for genesis_object in genesis.objects {
+            let object = Object::new(
+                genesis_object.data().clone(),
+                *genesis_object.owner(),
+                TransactionDigest::ZERO,
+                0,
+            );
+
+            if let Some(clock) = Clock::try_from_object(&object) {
+                assert_eq!(clock.id(), &"0x6".parse().unwrap());
+                clocks += 1;
+            }
+            if let Some(staked_sui) = StakedSui::try_from_object(&object) {
+                assert!(staked_sui.principal() > 0);
+                stakes += 1;
+            }
+            if let Some(coin_metadata) = CoinMetadata::try_from_object(&object) {
+                assert_eq!(coin_metadata.coin_type(), &"0x2::sui::SUI".parse().unwrap());
+                assert_eq!(coin_metadata.decimals(), 9);
+                assert_eq!(coin_metadata.name(), "Sui");
+                assert_eq!(coin_metadata.symbol(), "SUI");
+                assert_eq!(coin_metadata.icon_url(), None);
+                metadata += 1;
+            }
+            assert!(UpgradeCap::try_from_object(&object).is_none());
+        }
+
+        assert_eq!(clocks, 1);
+        assert!(stakes > 0);
+        assert_eq!(metadata, 1);
+    }
+
+    #[test]
+    fn staking_rewards() {
+        let mut contents = [id(1).into_inner(), id(2).into_inner()].concat();
+        contents.extend(10u64.to_le_bytes());
+        contents.extend(1_000u64.to_le_bytes());
+
+        let object = move_object("0x3::staking_pool::StakedSui", contents);
+        let staked_sui = StakedSui::try_from_object(&object).unwrap();
+        assert_eq!(staked_sui.pool_id(), &id(2));
+        assert_eq!(staked_sui.stake_activation_epoch(), 10);
+
+        let rate = |sui_amount, pool_token_amount| PoolTokenExchangeRate {
+            sui_amount,
+            pool_token_amount,
+        };
+        assert_eq!(staked_sui.estimated_reward(&rate(0, 0), &rate(0, 0)), 0);
+        assert_eq!(
+            staked_sui.estimated_reward(&rate(100, 100), &rate(110, 100)),
+            100
+        );
+        assert_eq!(
+            staked_sui.estimated_reward(&rate(200, 100), &rate(220, 100)),
+            100
+        );
+        // Rates never decrease, but rounding must not underflow
+        assert_eq!(
+            staked_sui.estimated_reward(&rate(300, 200), &rate(299, 200)),
+            0
+        );
+    }
+
+    #[test]
+    fn upgrade_cap() {
+        let mut contents = [id(1).into_inner(), id(2).into_inner()].concat();
+        contents.extend(3u64.to_le_bytes());
+        contents.push(128);
+
+        let object = move_object("0x2::package::UpgradeCap", contents.clone());
+        let cap = UpgradeCap::try_from_object(&object).unwrap();
+        assert_eq!(cap.id(), &id(1));
+        assert_eq!(cap.package(), &id(2));
+        assert_eq!(cap.version(), 3);
+        assert_eq!(cap.policy(), 128);
+
+        // Wrong type
+        let object = move_object("0x2::package::UpgradeTicket", contents.clone());
+        assert!(UpgradeCap::try_from_object(&object).is_none());
+
+        // Trailing bytes
+        contents.push(0);
+        let object = move_object("0x2::package::UpgradeCap", contents);
+        assert!(UpgradeCap::try_from_object(&object).is_none());
+    }
+
+    #[test]
+    fn kiosk() {
+        let mut contents = id(1).into_inner().to_vec();
+        contents.extend(5u64.to_le_bytes());
+        contents.extend([7; Address::LENGTH]);
+        contents.extend(2u32.to_le_bytes());
+        contents.push(1);
+
+        let object = move_object("0x2::kiosk::Kiosk", contents);
+        let kiosk = Kiosk::try_from_object(&object).unwrap();
+        assert_eq!(kiosk.id(), &id(1));
+        assert_eq!(kiosk.profits(), 5);
+        assert_eq!(kiosk.owner(), &Address::new([7; Address::LENGTH]));
+        assert_eq!(kiosk.item_count(), 2);
+        assert!(kiosk.allow_extensions());
+    }
+
+    #[test]
+    fn display() {
+        let mut contents = id(1).into_inner().to_vec();
+        contents.push(2);
+        for field in [
+            "name",
+            "{name}",
+            "image_url",
+            "https://example.com/{id}.png",
+        ] {
+            contents.push(field.len() as u8);
+            contents.extend(field.as_bytes());
+        }
+        contents.extend(4u16.to_le_bytes());
+
+        let object = move_object("0x2::display::Display<0x42::nft::Nft>", contents.clone());
+        let display = Display::try_from_object(&object).unwrap().into_owned();
+        assert_eq!(display.type_(), &"0x42::nft::Nft".parse().unwrap());
+        assert_eq!(
+            display.fields().collect::<Vec<_>>(),
+            [
+                ("name", "{name}"),
+                ("image_url", "https://example.com/{id}.png")
+            ]
+        );
+        assert_eq!(display.version(), 4);
+
+        // Invalid utf8
+        contents[ObjectId::LENGTH + 2] = 0xff;
+        let object = move_object("0x2::display::Display<0x42::nft::Nft>", contents);
+        assert!(Display::try_from_object(&object).is_none());
+    }
+}
@@ -38,6 +38,7 @@ pub use crypto::Ed25519PublicKey;
 pub use crypto::Ed25519Signature;
 pub use crypto::Intent;
 pub use crypto::IntentAppId;
+pub use crypto::IntentMessage;
 pub use crypto::IntentScope;
 pub use crypto::IntentVersion;
 pub use crypto::Jwk;
@@ -109,6 +110,9 @@ pub use object::TypeOrigin;
 pub use object::UpgradeInfo;
 pub use object::Version;
 pub use object_id::ObjectId;
+pub use transaction::coin_selection;
+pub use transaction::kiosk;
+pub use transaction::suins;
 pub use transaction::unresolved;
 pub use transaction::ActiveJwk;
 pub use transaction::Argument;
@@ -131,6 +135,7 @@ pub use transaction::MoveCall;
 pub use transaction::ProgrammableTransaction;
 pub use transaction::Publish;
 pub use transaction::RandomnessStateUpdate;
+pub use transaction::SenderSignedData;
 pub use transaction::SignedTransaction;
 pub use transaction::SplitCoins;
 pub use transaction::SystemPackage;
@@ -139,8 +144,10 @@ pub use transaction::TransactionExpiration;
 pub use transaction::TransactionKind;
 pub use transaction::TransferObjects;
 pub use transaction::Upgrade;
+pub use transaction::UpgradePolicy;
 pub use transaction::VersionAssignment;
 pub use type_tag::Identifier;
+pub use type_tag::NamedAddresses;
 pub use type_tag::StructTag;
 pub use type_tag::TypeParseError;
 pub use type_tag::TypeTag;
@@ -152,5 +159,22 @@ pub(crate) use transaction::SignedTransactionWithIntentMessage;
 #[cfg(test)]
 mod serialization_proptests;
 
+#[cfg(all(test, feature = "serde"))]
+mod fixtures {
+    use super::GenesisTransaction;
+    use super::Transaction;
+    use super::TransactionKind;
+
+    include!("transaction/fixtures/genesis.rs");
+}
+#[cfg(all(test, feature = "serde"))]
+pub(crate) use fixtures::genesis_transaction_fixture;
+#[cfg(all(test, feature = "serde"))]
+pub(crate) use fixtures::GENESIS_TRANSACTION;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct PersonalMessage<'a>(pub std::borrow::Cow<'a, [u8]>);
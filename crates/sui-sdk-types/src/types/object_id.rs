@@ -12,6 +12,10 @@ pub struct ObjectId(Address);
 impl ObjectId {
     pub const LENGTH: usize = Address::LENGTH;
     pub const ZERO: Self = Self(Address::ZERO);
+    /// The id of the shared `0x3::sui_system::SuiSystemState` object, `0x5`.
+    pub const SYSTEM_STATE: Self = Self(Address::from_u8(5));
+    /// The id of the shared `0x2::clock::Clock` object, `0x6`.
+    pub const CLOCK: Self = Self(Address::from_u8(6));
 
     pub const fn new(bytes: [u8; Self::LENGTH]) -> Self {
         Self(Address::new(bytes))
@@ -0,0 +1,608 @@
+//! Selecting the coins used to pay for a transaction.
+//!
+//! A [`CoinSelector`] picks, from the coins owned by the sender, a set of coins of a given type
+//! holding at least a target amount. The resulting [`CoinSelection`] can then add the commands
+//! gathering the selected coins into a single coin and splitting the payments off of it.
+//!
+//! SUI payments are usually taken from the gas coin rather than from separate coin inputs, as
+//! every coin used for the gas payment is merged into the gas coin before the transaction runs.
+//! [`CoinSelector::select_gas`] selects the coins of the gas payment for this purpose, and the
+//! payments of the resulting selection are split off of [`Argument::Gas`].
+
+use super::Argument;
+use super::Command;
+use super::Input;
+use super::MergeCoins;
+use super::ProgrammableTransaction;
+use super::SplitCoins;
+use crate::types::framework::Coin;
+use crate::types::ObjectId;
+use crate::types::StructTag;
+use crate::types::TypeTag;
+
+/// The strategy used by a [`CoinSelector`] to pick coins.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Pick the coins with the largest balances first.
+    #[default]
+    LargestFirst,
+    /// Pick as few coins as possible, like [`Strategy::LargestFirst`], but use the smallest coin
+    /// which still covers the remaining amount as the last coin, keeping larger coins intact.
+    MinimizeInputs,
+    /// Pick coins like [`Strategy::LargestFirst`], then add any coin holding less than
+    /// `threshold`, smallest first, while the coin limit allows, merging the dust into the
+    /// change.
+    AvoidDust { threshold: u64 },
+}
+
+/// Picks the coins used to cover an amount of a given coin type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinSelector {
+    strategy: Strategy,
+    max_coins: usize,
+    reserved_gas_coin: Option<ObjectId>,
+}
+
+impl CoinSelector {
+    /// The maximum number of coins which can be used for the gas payment of a transaction, as of
+    /// the `max_gas_payment_objects` protocol config at the time of writing.
+    pub const MAX_GAS_PAYMENT_OBJECTS: usize = 256;
+
+    /// Create a selector using `strategy`, selecting at most
+    /// [`MAX_GAS_PAYMENT_OBJECTS`](Self::MAX_GAS_PAYMENT_OBJECTS) coins.
+    pub fn new(strategy: Strategy) -> Self {
+        Self {
+            strategy,
+            max_coins: Self::MAX_GAS_PAYMENT_OBJECTS,
+            reserved_gas_coin: None,
+        }
+    }
+
+    /// Select at most `max_coins` coins, typically the `max_gas_payment_objects` protocol config
+    /// of the network.
+    pub fn with_max_coins(mut self, max_coins: usize) -> Self {
+        self.max_coins = max_coins;
+        self
+    }
+
+    /// Never select the coin `id` with [`select`](Self::select), keeping it available to pay for
+    /// gas.
+    pub fn with_reserved_gas_coin(mut self, id: ObjectId) -> Self {
+        self.reserved_gas_coin = Some(id);
+        self
+    }
+
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    pub fn max_coins(&self) -> usize {
+        self.max_coins
+    }
+
+    pub fn reserved_gas_coin(&self) -> Option<&ObjectId> {
+        self.reserved_gas_coin.as_ref()
+    }
+
+    /// Select coins of type `coin_type` from `coins` holding at least `amount` in total, to be
+    /// used as inputs of the transaction.
+    ///
+    /// Coins of other types, as well as the reserved gas coin, are ignored. At least one coin is
+    /// always selected.
+    pub fn select<'c, 'a>(
+        &self,
+        coins: &'c [Coin<'a>],
+        coin_type: &TypeTag,
+        amount: u64,
+    ) -> Result<CoinSelection<'c, 'a>, CoinSelectionError> {
+        let candidates = coins
+            .iter()
+            .filter(|coin| coin.coin_type() == coin_type)
+            .filter(|coin| Some(coin.id()) != self.reserved_gas_coin.as_ref())
+            .collect();
+        let coins = self.pick(candidates, coin_type, amount)?;
+
+        Ok(CoinSelection::new(coins, false))
+    }
+
+    /// Select SUI coins from `coins` holding at least `amount` in total, to be used as the gas
+    /// payment of the transaction.
+    ///
+    /// `amount` must account for both the payments taken from the gas coin and the gas budget.
+    /// The reserved gas coin, if any, is always selected.
+    pub fn select_gas<'c, 'a>(
+        &self,
+        coins: &'c [Coin<'a>],
+        amount: u64,
+    ) -> Result<CoinSelection<'c, 'a>, CoinSelectionError> {
+        let sui = sui_type();
+        let mut candidates: Vec<_> = coins
+            .iter()
+            .filter(|coin| coin.coin_type() == &sui)
+            .collect();
+
+        let reserved = self.reserved_gas_coin.as_ref().and_then(|id| {
+            let position = candidates.iter().position(|coin| coin.id() == id)?;
+            Some(candidates.remove(position))
+        });
+        let coins = match reserved {
+            Some(reserved) if reserved.balance() >= amount || self.max_coins <= 1 => {
+                if reserved.balance() < amount {
+                    candidates.push(reserved);
+                    return Err(self.error(candidates, &sui, amount));
+                }
+                vec![reserved]
+            }
+            Some(reserved) => {
+                let selector = Self {
+                    max_coins: self.max_coins - 1,
+                    ..self.clone()
+                };
+                let mut coins = selector
+                    .pick(candidates, &sui, amount - reserved.balance())
+                    .map_err(|error| match error {
+                        CoinSelectionError::InsufficientBalance {
+                            coin_type,
+                            available,
+                            ..
+                        } => CoinSelectionError::InsufficientBalance {
+                            coin_type,
+                            available: available.saturating_add(reserved.balance()),
+                            required: amount,
+                        },
+                        CoinSelectionError::TooManyCoins { coin_type, .. } => {
+                            CoinSelectionError::TooManyCoins {
+                                coin_type,
+                                max_coins: self.max_coins,
+                            }
+                        }
+                    })?;
+                coins.insert(0, reserved);
+                coins
+            }
+            None => self.pick(candidates, &sui, amount)?,
+        };
+
+        Ok(CoinSelection::new(coins, true))
+    }
+
+    fn pick<'c, 'a>(
+        &self,
+        mut candidates: Vec<&'c Coin<'a>>,
+        coin_type: &TypeTag,
+        amount: u64,
+    ) -> Result<Vec<&'c Coin<'a>>, CoinSelectionError> {
+        candidates.sort_by_key(|coin| std::cmp::Reverse(coin.balance()));
+
+        let mut total = 0u64;
+        let mut count = 0;
+        while total < amount || count == 0 {
+            if count == candidates.len() || count == self.max_coins {
+                return Err(self.error(candidates, coin_type, amount));
+            }
+            total = total.saturating_add(candidates[count].balance());
+            count += 1;
+        }
+
+        match self.strategy {
+            Strategy::LargestFirst => {}
+            Strategy::MinimizeInputs => {
+                // Replace the last coin by the smallest coin still covering the remaining amount.
+                // As candidates are sorted by decreasing balance, search from the end.
+                let last = count - 1;
+                let remaining = amount - (total - candidates[last].balance());
+                if let Some(smallest) = candidates[last..]
+                    .iter()
+                    .rposition(|coin| coin.balance() >= remaining)
+                {
+                    candidates.swap(last, last + smallest);
+                }
+            }
+            Strategy::AvoidDust { threshold } => {
+                let dust = candidates[count..]
+                    .iter()
+                    .rev()
+                    .take_while(|coin| coin.balance() < threshold)
+                    .take(self.max_coins - count)
+                    .count();
+                // Move the dust, sorted by increasing balance, right after the selected coins.
+                candidates[count..].reverse();
+                count += dust;
+            }
+        }
+
+        candidates.truncate(count);
+        Ok(candidates)
+    }
+
+    fn error(
+        &self,
+        candidates: Vec<&Coin<'_>>,
+        coin_type: &TypeTag,
+        amount: u64,
+    ) -> CoinSelectionError {
+        let available = candidates
+            .iter()
+            .fold(0u64, |total, coin| total.saturating_add(coin.balance()));
+
+        if available < amount || candidates.is_empty() {
+            CoinSelectionError::InsufficientBalance {
+                coin_type: coin_type.clone(),
+                available,
+                required: amount,
+            }
+        } else {
+            CoinSelectionError::TooManyCoins {
+                coin_type: coin_type.clone(),
+                max_coins: self.max_coins,
+            }
+        }
+    }
+}
+
+impl Default for CoinSelector {
+    fn default() -> Self {
+        Self::new(Strategy::default())
+    }
+}
+
+/// The coins picked by a [`CoinSelector`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoinSelection<'c, 'a> {
+    coins: Vec<&'c Coin<'a>>,
+    total: u64,
+    gas: bool,
+}
+
+impl<'c, 'a> CoinSelection<'c, 'a> {
+    fn new(coins: Vec<&'c Coin<'a>>, gas: bool) -> Self {
+        let total = coins
+            .iter()
+            .fold(0u64, |total, coin| total.saturating_add(coin.balance()));
+        Self { coins, total, gas }
+    }
+
+    /// The selected coins.
+    pub fn coins(&self) -> &[&'c Coin<'a>] {
+        &self.coins
+    }
+
+    /// The total balance of the selected coins.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Whether the selected coins are meant to be used as the gas payment of the transaction.
+    pub fn is_gas_payment(&self) -> bool {
+        self.gas
+    }
+
+    /// Add the commands splitting `amounts` off of the selected coins to `ptb`, returning the
+    /// arguments holding the resulting coins, in the order of `amounts`.
+    ///
+    /// For a gas payment, the amounts are split off of [`Argument::Gas`]. Otherwise, each
+    /// selected coin is added as the input returned by `input`, typically
+    /// [`Input::ImmutableOrOwned`], and all of them are merged into the first one before
+    /// splitting. The remainder stays in that coin, which is returned to its owner at the end of
+    /// the transaction.
+    pub fn split(
+        &self,
+        ptb: &mut ProgrammableTransaction,
+        amounts: &[u64],
+        input: impl FnMut(&Coin<'a>) -> Input,
+    ) -> Vec<Argument> {
+        let coin = self.merge(ptb, input);
+        let amounts = amounts
+            .iter()
+            .map(|amount| {
+                ptb.add_input(Input::Pure {
+                    value: amount.to_le_bytes().to_vec(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let count = amounts.len() as u16;
+        let result = ptb.add_command(Command::SplitCoins(SplitCoins { coin, amounts }));
+
+        (0..count).map(|ix| result.nested(ix).unwrap()).collect()
+    }
+
+    /// Add the commands merging all of the selected coins into a single coin to `ptb`, returning
+    /// the argument holding it.
+    ///
+    /// For a gas payment this is [`Argument::Gas`], and no command is added. Otherwise, each
+    /// selected coin is added as the input returned by `input`.
+    pub fn merge(
+        &self,
+        ptb: &mut ProgrammableTransaction,
+        mut input: impl FnMut(&Coin<'a>) -> Input,
+    ) -> Argument {
+        if self.gas {
+            return Argument::Gas;
+        }
+
+        let mut coins = self
+            .coins
+            .iter()
+            .map(|coin| ptb.add_input(input(coin)))
+            .collect::<Vec<_>>();
+        let coin = coins.remove(0);
+        if !coins.is_empty() {
+            ptb.add_command(Command::MergeCoins(MergeCoins {
+                coin,
+                coins_to_merge: coins,
+            }));
+        }
+
+        coin
+    }
+}
+
+/// An error selecting coins.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoinSelectionError {
+    /// The coins of type `coin_type` hold less than the required amount.
+    InsufficientBalance {
+        coin_type: TypeTag,
+        available: u64,
+        required: u64,
+    },
+    /// The required amount can't be covered with at most `max_coins` coins of type `coin_type`.
+    /// Merging some of the coins beforehand allows covering it.
+    TooManyCoins {
+        coin_type: TypeTag,
+        max_coins: usize,
+    },
+}
+
+impl std::fmt::Display for CoinSelectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsufficientBalance {
+                coin_type,
+                available,
+                required,
+            } => write!(
+                f,
+                "insufficient balance of {coin_type}: {available} available, {required} required"
+            ),
+            Self::TooManyCoins {
+                coin_type,
+                max_coins,
+            } => write!(
+                f,
+                "more than {max_coins} coins of {coin_type} are required to cover the amount"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CoinSelectionError {}
+
+fn sui_type() -> TypeTag {
+    StructTag::gas_coin().type_params.remove(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::MoveStruct;
+    use crate::types::Object;
+    use crate::types::ObjectData;
+    use crate::types::ObjectDigest;
+    use crate::types::ObjectReference;
+    use crate::types::Owner;
+    use crate::types::TransactionDigest;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    const USDC: &str =
+        "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN";
+
+    fn coin_object(coin_type: &str, byte: u8, balance: u64) -> Object {
+        let mut contents = vec![byte; ObjectId::LENGTH];
+        contents.extend_from_slice(&balance.to_le_bytes());
+        let type_ = format!("0x2::coin::Coin<{coin_type}>").parse().unwrap();
+        let move_struct = MoveStruct::new(type_, true, 1, contents).unwrap();
+        Object::new(
+            ObjectData::Struct(move_struct),
+            Owner::Address("0x1".parse().unwrap()),
+            TransactionDigest::ZERO,
+            0,
+        )
+    }
+
+    fn coins(objects: &[Object]) -> Vec<Coin<'_>> {
+        objects
+            .iter()
+            .map(|object| Coin::try_from_object(object).unwrap())
+            .collect()
+    }
+
+    fn balances(selection: &CoinSelection<'_, '_>) -> Vec<u64> {
+        selection
+            .coins()
+            .iter()
+            .map(|coin| coin.balance())
+            .collect()
+    }
+
+    fn owned(coin: &Coin<'_>) -> Input {
+        Input::ImmutableOrOwned(ObjectReference::new(*coin.id(), 1, ObjectDigest::ZERO))
+    }
+
+    #[test]
+    fn strategies() {
+        let objects = [
+            coin_object(USDC, 1, 5),
+            coin_object(USDC, 2, 100),
+            coin_object(USDC, 3, 1),
+            coin_object(USDC, 4, 40),
+            coin_object(USDC, 5, 70),
+            coin_object("0x2::sui::SUI", 6, 1000),
+        ];
+        let coins = coins(&objects);
+        let usdc: TypeTag = USDC.parse().unwrap();
+
+        let selection = CoinSelector::new(Strategy::LargestFirst)
+            .select(&coins, &usdc, 150)
+            .unwrap();
+        assert_eq!(balances(&selection), [100, 70]);
+        assert_eq!(selection.total(), 170);
+        assert!(!selection.is_gas_payment());
+
+        let selection = CoinSelector::new(Strategy::MinimizeInputs)
+            .select(&coins, &usdc, 130)
+            .unwrap();
+        assert_eq!(balances(&selection), [100, 40]);
+        let selection = CoinSelector::new(Strategy::MinimizeInputs)
+            .select(&coins, &usdc, 3)
+            .unwrap();
+        assert_eq!(balances(&selection), [5]);
+
+        let selection = CoinSelector::new(Strategy::AvoidDust { threshold: 10 })
+            .select(&coins, &usdc, 150)
+            .unwrap();
+        assert_eq!(balances(&selection), [100, 70, 1, 5]);
+        let selection = CoinSelector::new(Strategy::AvoidDust { threshold: 10 })
+            .with_max_coins(3)
+            .select(&coins, &usdc, 150)
+            .unwrap();
+        assert_eq!(balances(&selection), [100, 70, 1]);
+
+        let selection = CoinSelector::default()
+            .with_reserved_gas_coin(ObjectId::new([2; ObjectId::LENGTH]))
+            .select(&coins, &usdc, 50)
+            .unwrap();
+        assert_eq!(balances(&selection), [70]);
+    }
+
+    #[test]
+    fn errors() {
+        let objects = [
+            coin_object(USDC, 1, 50),
+            coin_object(USDC, 2, 50),
+            coin_object(USDC, 3, 50),
+        ];
+        let coins = coins(&objects);
+        let usdc: TypeTag = USDC.parse().unwrap();
+
+        assert_eq!(
+            CoinSelector::default().select(&coins, &usdc, 200),
+            Err(CoinSelectionError::InsufficientBalance {
+                coin_type: usdc.clone(),
+                available: 150,
+                required: 200,
+            })
+        );
+        assert_eq!(
+            CoinSelector::default()
+                .with_max_coins(2)
+                .select(&coins, &usdc, 150),
+            Err(CoinSelectionError::TooManyCoins {
+                coin_type: usdc.clone(),
+                max_coins: 2,
+            })
+        );
+        assert_eq!(
+            CoinSelector::default().select_gas(&coins, 1),
+            Err(CoinSelectionError::InsufficientBalance {
+                coin_type: sui_type(),
+                available: 0,
+                required: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn split() {
+        let objects = [
+            coin_object(USDC, 1, 50),
+            coin_object(USDC, 2, 60),
+            coin_object(USDC, 3, 70),
+        ];
+        let coins = coins(&objects);
+        let usdc: TypeTag = USDC.parse().unwrap();
+
+        let selection = CoinSelector::default().select(&coins, &usdc, 120).unwrap();
+        let mut ptb = ProgrammableTransaction::default();
+        let payments = selection.split(&mut ptb, &[100, 20], owned);
+        assert_eq!(
+            payments,
+            [Argument::NestedResult(1, 0), Argument::NestedResult(1, 1)]
+        );
+        assert_eq!(
+            ptb.inputs,
+            [
+                owned(&coins[2]),
+                owned(&coins[1]),
+                Input::Pure {
+                    value: 100u64.to_le_bytes().to_vec()
+                },
+                Input::Pure {
+                    value: 20u64.to_le_bytes().to_vec()
+                },
+            ]
+        );
+        assert_eq!(
+            ptb.commands,
+            [
+                Command::MergeCoins(MergeCoins {
+                    coin: Argument::Input(0),
+                    coins_to_merge: vec![Argument::Input(1)],
+                }),
+                Command::SplitCoins(SplitCoins {
+                    coin: Argument::Input(0),
+                    amounts: vec![Argument::Input(2), Argument::Input(3)],
+                }),
+            ]
+        );
+
+        // A single coin isn't merged
+        let selection = CoinSelector::default().select(&coins, &usdc, 10).unwrap();
+        let mut ptb = ProgrammableTransaction::default();
+        assert_eq!(selection.merge(&mut ptb, owned), Argument::Input(0));
+        assert!(ptb.commands.is_empty());
+    }
+
+    #[test]
+    fn gas() {
+        let objects = [
+            coin_object("0x2::sui::SUI", 1, 500),
+            coin_object("0x2::sui::SUI", 2, 300),
+            coin_object("0x2::sui::SUI", 3, 100),
+            coin_object(USDC, 4, 1000),
+        ];
+        let coins = coins(&objects);
+
+        let selection = CoinSelector::default().select_gas(&coins, 700).unwrap();
+        assert_eq!(balances(&selection), [500, 300]);
+        assert!(selection.is_gas_payment());
+
+        // The reserved gas coin is always part of the gas payment
+        let selector =
+            CoinSelector::default().with_reserved_gas_coin(ObjectId::new([3; ObjectId::LENGTH]));
+        let selection = selector.select_gas(&coins, 50).unwrap();
+        assert_eq!(balances(&selection), [100]);
+        let selection = selector.select_gas(&coins, 700).unwrap();
+        assert_eq!(balances(&selection), [100, 500, 300]);
+        assert_eq!(
+            selector.with_max_coins(2).select_gas(&coins, 700),
+            Err(CoinSelectionError::TooManyCoins {
+                coin_type: sui_type(),
+                max_coins: 2,
+            })
+        );
+
+        let mut ptb = ProgrammableTransaction::default();
+        let payments = selection.split(&mut ptb, &[200], |_| unreachable!());
+        assert_eq!(payments, [Argument::NestedResult(0, 0)]);
+        assert_eq!(
+            ptb.commands,
+            [Command::SplitCoins(SplitCoins {
+                coin: Argument::Gas,
+                amounts: vec![Argument::Input(0)],
+            })]
+        );
+    }
+}
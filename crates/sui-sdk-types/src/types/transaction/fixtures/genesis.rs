@@ -0,0 +1,19 @@
+// The genesis transaction fixture, shared by the tests of the crates of this workspace with
+// `include!`. `GenesisTransaction`, `Transaction` and `TransactionKind` must be in scope where it is
+// included.
+
+/// The base64 encoded genesis transaction of a network.
+pub(crate) const GENESIS_TRANSACTION: &str = include_str!("genesis-transaction");
+
+/// Decode the genesis transaction fixture, whose objects are a convenient source of real on-chain
+/// data for tests.
+pub(crate) fn genesis_transaction_fixture() -> GenesisTransaction {
+    use base64ct::Encoding;
+
+    let bytes = base64ct::Base64::decode_vec(GENESIS_TRANSACTION.trim()).unwrap();
+    let transaction: Transaction = bcs::from_bytes(&bytes).unwrap();
+    let TransactionKind::Genesis(genesis) = transaction.kind else {
+        panic!("not a genesis transaction");
+    };
+    genesis
+}
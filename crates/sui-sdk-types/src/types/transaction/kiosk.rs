@@ -0,0 +1,478 @@
+//! Helpers for building programmable transactions operating on kiosks, `0x2::kiosk::Kiosk`,
+//! and resolving the transfer requests produced when purchasing from a kiosk.
+//!
+//! Purchasing an item from a kiosk produces a `0x2::transfer_policy::TransferRequest`, which must
+//! be confirmed against the item type's `TransferPolicy` once each of the policy's rules has been
+//! satisfied. The rules of a policy are stored as dynamic fields of the policy, which can be
+//! decoded with [`Rule::from_dynamic_field`] and resolved by [`KioskTransaction::purchase`].
+
+use super::Argument;
+use super::Command;
+use super::Input;
+use super::MoveCall;
+use super::ProgrammableTransaction;
+use super::SplitCoins;
+use crate::types::Address;
+use crate::types::Identifier;
+use crate::types::ObjectId;
+use crate::types::StructTag;
+use crate::types::TypeTag;
+
+/// A rule of a transfer policy, along with its configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// `royalty_rule`: a fee of `amount_bp` basis points of the price, but at least `min_amount`
+    /// MIST, must be paid to the policy.
+    Royalty {
+        package: ObjectId,
+        amount_bp: u16,
+        min_amount: u64,
+    },
+    /// `kiosk_lock_rule`: the item must be locked in the buyer's kiosk.
+    KioskLock { package: ObjectId },
+    /// `personal_kiosk_rule`: the item must be placed in a personal kiosk.
+    PersonalKiosk { package: ObjectId },
+    /// `floor_price_rule`: the item must be sold for at least `floor_price` MIST.
+    FloorPrice { package: ObjectId, floor_price: u64 },
+    /// A rule that is not known to this module, and so cannot be resolved.
+    Unknown { rule_type: StructTag },
+}
+
+impl Rule {
+    /// Decode a rule from a dynamic field of a `TransferPolicy`, given the type of its name, a
+    /// `0x2::transfer_policy::RuleKey<Rule>`, and the BCS serialized value of its configuration.
+    ///
+    /// Returns `None` if the field is not a rule, or if the configuration of a known rule is
+    /// malformed.
+    pub fn from_dynamic_field(name_type: &TypeTag, value: &[u8]) -> Option<Self> {
+        let TypeTag::Struct(name_type) = name_type else {
+            return None;
+        };
+        let [TypeTag::Struct(rule_type)] = name_type.type_params.as_slice() else {
+            return None;
+        };
+        if name_type.address != Address::TWO
+            || name_type.module != *"transfer_policy"
+            || name_type.name != *"RuleKey"
+        {
+            return None;
+        }
+
+        let package = ObjectId::from(rule_type.address);
+        let rule = match (rule_type.module.as_str(), rule_type.name.as_str()) {
+            ("royalty_rule", "Rule") => {
+                let value: [u8; 10] = value.try_into().ok()?;
+                Self::Royalty {
+                    package,
+                    amount_bp: u16::from_le_bytes(value[..2].try_into().unwrap()),
+                    min_amount: u64::from_le_bytes(value[2..].try_into().unwrap()),
+                }
+            }
+            ("kiosk_lock_rule", "Rule") => Self::KioskLock { package },
+            ("personal_kiosk_rule", "Rule") => Self::PersonalKiosk { package },
+            ("floor_price_rule", "Rule") => Self::FloorPrice {
+                package,
+                floor_price: u64::from_le_bytes(value.try_into().ok()?),
+            },
+            _ => Self::Unknown {
+                rule_type: (**rule_type).clone(),
+            },
+        };
+        Some(rule)
+    }
+
+    /// The royalty owed on an item sold for `price` MIST.
+    fn royalty(amount_bp: u16, min_amount: u64, price: u64) -> u64 {
+        let amount = u128::from(price) * u128::from(amount_bp) / 10_000;
+        // `amount_bp` is at most 10_000, so the amount cannot exceed the price
+        (amount as u64).max(min_amount)
+    }
+}
+
+/// An error resolving the rules of a transfer policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsupportedRuleError {
+    rule_type: StructTag,
+}
+
+impl UnsupportedRuleError {
+    /// The type of the rule which could not be resolved.
+    pub fn rule_type(&self) -> &StructTag {
+        &self.rule_type
+    }
+}
+
+impl std::fmt::Display for UnsupportedRuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsupported transfer policy rule {}", self.rule_type)
+    }
+}
+
+impl std::error::Error for UnsupportedRuleError {}
+
+/// An item listed for sale in a kiosk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Listing {
+    /// The argument holding the `&mut Kiosk` the item is listed in.
+    pub kiosk: Argument,
+    /// The id of the listed item.
+    pub item_id: ObjectId,
+    /// The price of the item, in MIST.
+    pub price: u64,
+}
+
+/// Builds commands operating on a kiosk owned by the sender of a transaction.
+///
+/// `kiosk` and `cap` are the arguments holding the `&mut Kiosk` and its `&KioskOwnerCap`. For a
+/// personal kiosk, `cap` is the capability borrowed with `personal_kiosk::borrow_val`, which must
+/// be returned once all of the kiosk's operations have been added.
+pub struct KioskTransaction<'a> {
+    ptb: &'a mut ProgrammableTransaction,
+    kiosk: Argument,
+    cap: Argument,
+}
+
+impl<'a> KioskTransaction<'a> {
+    pub fn new(ptb: &'a mut ProgrammableTransaction, kiosk: Argument, cap: Argument) -> Self {
+        Self { ptb, kiosk, cap }
+    }
+
+    /// Place `item`, of type `item_type`, in the kiosk.
+    pub fn place(&mut self, item_type: TypeTag, item: Argument) {
+        let arguments = vec![self.kiosk, self.cap, item];
+        self.ptb
+            .add_command(kiosk_call("place", item_type, arguments));
+    }
+
+    /// Place and lock `item`, of type `item_type`, in the kiosk, so that it can only be removed
+    /// by being purchased. `policy` is the argument holding the item type's `&TransferPolicy`.
+    pub fn lock(&mut self, item_type: TypeTag, policy: Argument, item: Argument) {
+        let arguments = vec![self.kiosk, self.cap, policy, item];
+        self.ptb
+            .add_command(kiosk_call("lock", item_type, arguments));
+    }
+
+    /// Take the item with id `item_id`, of type `item_type`, out of the kiosk, returning the
+    /// argument holding it.
+    pub fn take(&mut self, item_type: TypeTag, item_id: ObjectId) -> Argument {
+        let item_id = self.pure_id(item_id);
+        let arguments = vec![self.kiosk, self.cap, item_id];
+        self.ptb
+            .add_command(kiosk_call("take", item_type, arguments))
+    }
+
+    /// List the item with id `item_id`, of type `item_type`, for sale at `price` MIST.
+    pub fn list(&mut self, item_type: TypeTag, item_id: ObjectId, price: u64) {
+        let item_id = self.pure_id(item_id);
+        let price = self.ptb.add_input(Input::Pure {
+            value: price.to_le_bytes().to_vec(),
+        });
+        let arguments = vec![self.kiosk, self.cap, item_id, price];
+        self.ptb
+            .add_command(kiosk_call("list", item_type, arguments));
+    }
+
+    /// Remove the listing of the item with id `item_id`, of type `item_type`.
+    pub fn delist(&mut self, item_type: TypeTag, item_id: ObjectId) {
+        let item_id = self.pure_id(item_id);
+        let arguments = vec![self.kiosk, self.cap, item_id];
+        self.ptb
+            .add_command(kiosk_call("delist", item_type, arguments));
+    }
+
+    /// Purchase the item of type `item_type` in `listing` into this kiosk.
+    ///
+    /// The price and any royalties are split from `payment`, a `Coin<SUI>` such as
+    /// [`Argument::Gas`]. The transfer request of the purchase is resolved against `rules`, the
+    /// rules of the item type's transfer policy held in `policy`: the item is locked in this
+    /// kiosk if required by a `kiosk_lock_rule`, and is otherwise placed in it.
+    ///
+    /// Returns an error, without adding any commands, if one of the rules is not supported.
+    pub fn purchase(
+        &mut self,
+        item_type: TypeTag,
+        listing: Listing,
+        policy: Argument,
+        rules: &[Rule],
+        payment: Argument,
+    ) -> Result<(), UnsupportedRuleError> {
+        if let Some(Rule::Unknown { rule_type }) = rules
+            .iter()
+            .find(|rule| matches!(rule, Rule::Unknown { .. }))
+        {
+            return Err(UnsupportedRuleError {
+                rule_type: rule_type.clone(),
+            });
+        }
+
+        let Listing {
+            kiosk: seller_kiosk,
+            item_id,
+            price,
+        } = listing;
+        let coin = split_coin(self.ptb, payment, price);
+        let item_id = self.pure_id(item_id);
+        let purchase = self.ptb.add_command(kiosk_call(
+            "purchase",
+            item_type.clone(),
+            vec![seller_kiosk, item_id, coin],
+        ));
+        let item = purchase.nested(0).unwrap();
+        let request = purchase.nested(1).unwrap();
+
+        if rules
+            .iter()
+            .any(|rule| matches!(rule, Rule::KioskLock { .. }))
+        {
+            self.lock(item_type.clone(), policy, item);
+        } else {
+            self.place(item_type.clone(), item);
+        }
+
+        for rule in rules {
+            let (package, module, function, arguments) = match *rule {
+                Rule::Royalty {
+                    package,
+                    amount_bp,
+                    min_amount,
+                } => {
+                    let royalty = Rule::royalty(amount_bp, min_amount, price);
+                    let fee = split_coin(self.ptb, payment, royalty);
+                    (package, "royalty_rule", "pay", vec![policy, request, fee])
+                }
+                Rule::KioskLock { package } => (
+                    package,
+                    "kiosk_lock_rule",
+                    "prove",
+                    vec![request, self.kiosk],
+                ),
+                Rule::PersonalKiosk { package } => (
+                    package,
+                    "personal_kiosk_rule",
+                    "prove",
+                    vec![self.kiosk, request],
+                ),
+                Rule::FloorPrice { package, .. } => {
+                    (package, "floor_price_rule", "prove", vec![policy, request])
+                }
+                Rule::Unknown { .. } => unreachable!("unknown rules are rejected above"),
+            };
+            self.ptb.add_command(Command::MoveCall(MoveCall {
+                package,
+                module: Identifier::new(module).unwrap(),
+                function: Identifier::new(function).unwrap(),
+                type_arguments: vec![item_type.clone()],
+                arguments,
+            }));
+        }
+
+        confirm_request(self.ptb, item_type, policy, request);
+        Ok(())
+    }
+
+    fn pure_id(&mut self, id: ObjectId) -> Argument {
+        self.ptb.add_input(Input::Pure {
+            value: id.as_bytes().to_vec(),
+        })
+    }
+}
+
+/// Confirm the transfer request held in `request`, for an item of type `item_type`, once all of
+/// the rules of the `&TransferPolicy` held in `policy` have been satisfied.
+pub fn confirm_request(
+    ptb: &mut ProgrammableTransaction,
+    item_type: TypeTag,
+    policy: Argument,
+    request: Argument,
+) -> Argument {
+    ptb.add_command(Command::MoveCall(MoveCall {
+        package: ObjectId::from(Address::TWO),
+        module: Identifier::new("transfer_policy").unwrap(),
+        function: Identifier::new("confirm_request").unwrap(),
+        type_arguments: vec![item_type],
+        arguments: vec![policy, request],
+    }))
+}
+
+fn kiosk_call(function: &str, item_type: TypeTag, arguments: Vec<Argument>) -> Command {
+    Command::MoveCall(MoveCall {
+        package: ObjectId::from(Address::TWO),
+        module: Identifier::new("kiosk").unwrap(),
+        function: Identifier::new(function).unwrap(),
+        type_arguments: vec![item_type],
+        arguments,
+    })
+}
+
+/// Split a coin of `amount` MIST from `coin`.
+fn split_coin(ptb: &mut ProgrammableTransaction, coin: Argument, amount: u64) -> Argument {
+    let amount = ptb.add_input(Input::Pure {
+        value: amount.to_le_bytes().to_vec(),
+    });
+    ptb.add_command(Command::SplitCoins(SplitCoins {
+        coin,
+        amounts: vec![amount],
+    }))
+    .nested(0)
+    .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn rule_key(rule: &str) -> TypeTag {
+        format!("0x2::transfer_policy::RuleKey<{rule}>")
+            .parse()
+            .unwrap()
+    }
+
+    fn functions(ptb: &ProgrammableTransaction) -> Vec<String> {
+        ptb.commands
+            .iter()
+            .map(|command| match command {
+                Command::MoveCall(call) => format!("{}::{}", call.module, call.function),
+                Command::SplitCoins(_) => "split".to_owned(),
+                _ => panic!("unexpected command"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rules_from_dynamic_fields() {
+        let package: ObjectId = "0x434b".parse().unwrap();
+
+        let mut config = 250u16.to_le_bytes().to_vec();
+        config.extend(1_000u64.to_le_bytes());
+        assert_eq!(
+            Rule::from_dynamic_field(&rule_key("0x434b::royalty_rule::Rule"), &config),
+            Some(Rule::Royalty {
+                package,
+                amount_bp: 250,
+                min_amount: 1_000
+            })
+        );
+        assert_eq!(
+            Rule::from_dynamic_field(&rule_key("0x434b::kiosk_lock_rule::Rule"), &[0]),
+            Some(Rule::KioskLock { package })
+        );
+        assert_eq!(
+            Rule::from_dynamic_field(&rule_key("0x434b::custom::Rule"), &[0]),
+            Some(Rule::Unknown {
+                rule_type: "0x434b::custom::Rule".parse().unwrap()
+            })
+        );
+
+        // Malformed configurations and fields that are not rules
+        assert_eq!(
+            Rule::from_dynamic_field(&rule_key("0x434b::royalty_rule::Rule"), &[0]),
+            None
+        );
+        assert_eq!(Rule::from_dynamic_field(&TypeTag::U64, &config), None);
+    }
+
+    #[test]
+    fn royalty() {
+        assert_eq!(Rule::royalty(250, 0, 10_000), 250);
+        assert_eq!(Rule::royalty(250, 1_000, 10_000), 1_000);
+        assert_eq!(Rule::royalty(10_000, 0, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn owner_operations() {
+        let item_type: TypeTag = "0x42::nft::Nft".parse().unwrap();
+        let mut ptb = ProgrammableTransaction::default();
+        let mut kiosk = KioskTransaction::new(&mut ptb, Argument::Input(0), Argument::Input(1));
+
+        let item = kiosk.take(item_type.clone(), ObjectId::ZERO);
+        kiosk.place(item_type.clone(), item);
+        kiosk.list(item_type.clone(), ObjectId::ZERO, 100);
+        kiosk.delist(item_type, ObjectId::ZERO);
+
+        assert_eq!(
+            functions(&ptb),
+            [
+                "kiosk::take",
+                "kiosk::place",
+                "kiosk::list",
+                "kiosk::delist"
+            ]
+        );
+        let Command::MoveCall(place) = &ptb.commands[1] else {
+            panic!("expected a move call");
+        };
+        assert_eq!(
+            place.arguments,
+            [Argument::Input(0), Argument::Input(1), Argument::Result(0)]
+        );
+    }
+
+    #[test]
+    fn purchase() {
+        let item_type: TypeTag = "0x42::nft::Nft".parse().unwrap();
+        let package: ObjectId = "0x434b".parse().unwrap();
+        let rules = [
+            Rule::Royalty {
+                package,
+                amount_bp: 500,
+                min_amount: 0,
+            },
+            Rule::KioskLock { package },
+        ];
+        let mut ptb = ProgrammableTransaction::default();
+        let (kiosk, cap, policy) = (Argument::Input(0), Argument::Input(1), Argument::Input(2));
+        let listing = Listing {
+            kiosk: Argument::Input(3),
+            item_id: ObjectId::ZERO,
+            price: 1_000,
+        };
+        KioskTransaction::new(&mut ptb, kiosk, cap)
+            .purchase(item_type.clone(), listing, policy, &rules, Argument::Gas)
+            .unwrap();
+
+        assert_eq!(
+            functions(&ptb),
+            [
+                "split",
+                "kiosk::purchase",
+                "kiosk::lock",
+                "split",
+                "royalty_rule::pay",
+                "kiosk_lock_rule::prove",
+                "transfer_policy::confirm_request",
+            ]
+        );
+        // The royalty is 5% of the price
+        assert_eq!(
+            ptb.inputs.last(),
+            Some(&Input::Pure {
+                value: 50u64.to_le_bytes().to_vec()
+            })
+        );
+        let Command::MoveCall(confirm) = ptb.commands.last().unwrap() else {
+            panic!("expected a move call");
+        };
+        assert_eq!(confirm.type_arguments, std::slice::from_ref(&item_type));
+        assert_eq!(confirm.arguments, [policy, Argument::NestedResult(1, 1)]);
+
+        // Unknown rules are rejected without adding any commands
+        let mut ptb = ProgrammableTransaction::default();
+        let rule_type: StructTag = "0x434b::custom::Rule".parse().unwrap();
+        let error = KioskTransaction::new(&mut ptb, kiosk, cap)
+            .purchase(
+                item_type,
+                listing,
+                policy,
+                &[Rule::Unknown {
+                    rule_type: rule_type.clone(),
+                }],
+                Argument::Gas,
+            )
+            .unwrap_err();
+        assert_eq!(error.rule_type(), &rule_type);
+        assert!(ptb.commands.is_empty());
+    }
+}
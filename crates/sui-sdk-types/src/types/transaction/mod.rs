@@ -21,8 +21,15 @@ mod serialization;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
 pub(crate) use serialization::SignedTransactionWithIntentMessage;
 
+pub mod coin_selection;
+pub mod kiosk;
+pub mod suins;
 pub mod unresolved;
 
+mod package;
+pub use package::UpgradePolicy;
+mod staking;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(test, derive(test_strategy::Arbitrary))]
 pub struct Transaction {
@@ -44,6 +51,23 @@ pub struct SignedTransaction {
     pub signatures: Vec<UserSignature>,
 }
 
+/// A [`SignedTransaction`] in the form which is signed by validators, where the transaction is
+/// wrapped in its [`IntentMessage`](crate::types::IntentMessage).
+///
+/// See [`IntentMessage::sender_signed_transaction`](crate::types::IntentMessage::sender_signed_transaction).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct SenderSignedData(
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "::serde_with::As::<SignedTransactionWithIntentMessage>")
+    )]
+    pub SignedTransaction,
+);
+
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(test, derive(test_strategy::Arbitrary))]
 pub enum TransactionExpiration {
@@ -426,7 +450,7 @@ pub struct GenesisTransaction {
 
 /// A series of commands where the results of one command can be used in future
 /// commands
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde_derive::Serialize, serde_derive::Deserialize)
@@ -443,6 +467,22 @@ pub struct ProgrammableTransaction {
     pub commands: Vec<Command>,
 }
 
+impl ProgrammableTransaction {
+    /// Append an input to this transaction, returning the argument referring to it.
+    pub fn add_input(&mut self, input: Input) -> Argument {
+        let index = u16::try_from(self.inputs.len()).expect("too many inputs");
+        self.inputs.push(input);
+        Argument::Input(index)
+    }
+
+    /// Append a command to this transaction, returning the argument referring to its result.
+    pub fn add_command(&mut self, command: Command) -> Argument {
+        let index = u16::try_from(self.commands.len()).expect("too many commands");
+        self.commands.push(command);
+        Argument::Result(index)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
     feature = "schemars",
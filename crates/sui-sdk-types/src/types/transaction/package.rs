@@ -0,0 +1,262 @@
+use super::Argument;
+use super::Command;
+use super::ProgrammableTransaction;
+use super::Publish;
+use crate::types::ObjectId;
+
+/// The policy restricting the upgrades that may be made to a package.
+///
+/// Policies are ordered from least to most restrictive, with each policy enforcing all of the
+/// rules of the policies before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UpgradePolicy {
+    /// Modules may be added, and existing modules may change their implementation, so long as
+    /// the layout and abilities of their datatypes and the signatures of their public functions
+    /// are preserved.
+    Compatible,
+    /// Modules, datatypes and functions may be added, but existing code may not change.
+    Additive,
+    /// Only the dependencies of the package may change.
+    DependencyOnly,
+}
+
+impl UpgradePolicy {
+    /// The on-chain representation of this policy, as used by `0x2::package::UpgradeCap`.
+    pub const fn as_u8(self) -> u8 {
+        match self {
+            UpgradePolicy::Compatible => 0,
+            UpgradePolicy::Additive => 128,
+            UpgradePolicy::DependencyOnly => 192,
+        }
+    }
+
+    /// The policy with the on-chain representation `policy`, if there is one.
+    pub const fn from_u8(policy: u8) -> Option<Self> {
+        match policy {
+            0 => Some(UpgradePolicy::Compatible),
+            128 => Some(UpgradePolicy::Additive),
+            192 => Some(UpgradePolicy::DependencyOnly),
+            _ => None,
+        }
+    }
+}
+
+impl ProgrammableTransaction {
+    /// Publish a package made up of `modules`, linked against the packages in `dependencies`.
+    ///
+    /// Returns the argument holding the `0x2::package::UpgradeCap` of the new package, which must
+    /// be consumed by a later command, typically by transferring it to the sender.
+    pub fn publish(&mut self, modules: Vec<Vec<u8>>, dependencies: Vec<ObjectId>) -> Argument {
+        self.add_command(Command::Publish(Publish {
+            modules,
+            dependencies,
+        }))
+    }
+}
+
+#[cfg(feature = "hash")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "hash")))]
+mod upgrade {
+    use super::super::Input;
+    use super::super::MoveCall;
+    use super::super::Upgrade;
+    use super::Argument;
+    use super::Command;
+    use super::ProgrammableTransaction;
+    use super::UpgradePolicy;
+    use crate::types::Address;
+    use crate::types::Digest;
+    use crate::types::Identifier;
+    use crate::types::MovePackage;
+    use crate::types::ObjectId;
+
+    impl ProgrammableTransaction {
+        /// Upgrade `package` to a new version made up of `modules`, linked against the packages in
+        /// `dependencies`.
+        ///
+        /// This adds the standard sequence of commands performing an upgrade:
+        ///
+        /// 1. `0x2::package::authorize_upgrade`, producing an `UpgradeTicket` for the digest of
+        ///    the new package (see [`MovePackage::compute_digest`]) using `upgrade_cap`, the
+        ///    argument holding the package's `0x2::package::UpgradeCap`;
+        /// 2. the upgrade itself, consuming the ticket and producing an `UpgradeReceipt`;
+        /// 3. `0x2::package::commit_upgrade`, updating `upgrade_cap` with the receipt.
+        ///
+        /// `policy` is the upgrade policy requested for the upgrade, which must be at least as
+        /// restrictive as the policy of `upgrade_cap`.
+        pub fn upgrade(
+            &mut self,
+            package: ObjectId,
+            upgrade_cap: Argument,
+            policy: UpgradePolicy,
+            modules: Vec<Vec<u8>>,
+            dependencies: Vec<ObjectId>,
+        ) {
+            let digest = MovePackage::compute_digest(&modules, &dependencies);
+
+            let policy = self.add_input(Input::Pure {
+                value: vec![policy.as_u8()],
+            });
+            // BCS encoding of the digest as a `vector<u8>`
+            let mut digest_bytes = vec![Digest::LENGTH as u8];
+            digest_bytes.extend_from_slice(digest.inner());
+            let digest = self.add_input(Input::Pure {
+                value: digest_bytes,
+            });
+
+            let ticket = self.add_command(package_call(
+                "authorize_upgrade",
+                vec![upgrade_cap, policy, digest],
+            ));
+            let receipt = self.add_command(Command::Upgrade(Upgrade {
+                modules,
+                dependencies,
+                package,
+                ticket,
+            }));
+            self.add_command(package_call("commit_upgrade", vec![upgrade_cap, receipt]));
+        }
+    }
+
+    fn package_call(function: &str, arguments: Vec<Argument>) -> Command {
+        Command::MoveCall(MoveCall {
+            package: ObjectId::from(Address::TWO),
+            module: Identifier::new("package").unwrap(),
+            function: Identifier::new(function).unwrap(),
+            type_arguments: vec![],
+            arguments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Input;
+    use super::*;
+    use crate::types::ObjectDigest;
+    use crate::types::ObjectReference;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn publish() {
+        let mut ptb = ProgrammableTransaction::default();
+        let dependencies = vec!["0x1".parse().unwrap(), "0x2".parse().unwrap()];
+        let cap = ptb.publish(vec![vec![1, 2, 3]], dependencies.clone());
+        assert_eq!(cap, Argument::Result(0));
+        assert_eq!(
+            ptb.commands,
+            vec![Command::Publish(Publish {
+                modules: vec![vec![1, 2, 3]],
+                dependencies,
+            })]
+        );
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn upgrade() {
+        use super::super::MoveCall;
+        use super::super::Upgrade;
+        use crate::types::MovePackage;
+
+        let mut ptb = ProgrammableTransaction::default();
+        let cap = ptb.add_input(Input::ImmutableOrOwned(ObjectReference::new(
+            "0x5".parse().unwrap(),
+            3,
+            ObjectDigest::ZERO,
+        )));
+        let package: ObjectId = "0x42".parse().unwrap();
+        let modules = vec![vec![1, 2, 3], vec![4, 5]];
+        let dependencies = vec!["0x1".parse().unwrap(), "0x2".parse().unwrap()];
+        ptb.upgrade(
+            package,
+            cap,
+            UpgradePolicy::Additive,
+            modules.clone(),
+            dependencies.clone(),
+        );
+
+        let digest = MovePackage::compute_digest(&modules, &dependencies);
+        let mut digest_bytes = vec![32];
+        digest_bytes.extend_from_slice(digest.inner());
+        assert_eq!(
+            ptb.inputs[1..],
+            [
+                Input::Pure { value: vec![128] },
+                Input::Pure {
+                    value: digest_bytes
+                },
+            ]
+        );
+
+        let Command::MoveCall(MoveCall {
+            function,
+            arguments,
+            ..
+        }) = &ptb.commands[0]
+        else {
+            panic!("expected a move call");
+        };
+        assert_eq!(function.as_str(), "authorize_upgrade");
+        assert_eq!(arguments, &[cap, Argument::Input(1), Argument::Input(2)]);
+
+        assert_eq!(
+            ptb.commands[1],
+            Command::Upgrade(Upgrade {
+                modules,
+                dependencies,
+                package,
+                ticket: Argument::Result(0),
+            })
+        );
+
+        let Command::MoveCall(MoveCall {
+            function,
+            arguments,
+            ..
+        }) = &ptb.commands[2]
+        else {
+            panic!("expected a move call");
+        };
+        assert_eq!(function.as_str(), "commit_upgrade");
+        assert_eq!(arguments, &[cap, Argument::Result(1)]);
+    }
+
+    #[test]
+    fn upgrade_policy() {
+        for policy in [
+            UpgradePolicy::Compatible,
+            UpgradePolicy::Additive,
+            UpgradePolicy::DependencyOnly,
+        ] {
+            assert_eq!(UpgradePolicy::from_u8(policy.as_u8()), Some(policy));
+        }
+        assert_eq!(UpgradePolicy::from_u8(1), None);
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn package_digest() {
+        use crate::types::MovePackage;
+
+        let modules = [vec![1, 2, 3], vec![4, 5]];
+        let dependencies: [ObjectId; 2] = ["0x1".parse().unwrap(), "0x2".parse().unwrap()];
+        let digest = MovePackage::compute_digest(&modules, &dependencies);
+
+        // The digest does not depend on the order of the modules or dependencies
+        assert_eq!(
+            digest,
+            MovePackage::compute_digest(modules.iter().rev(), dependencies.iter().rev())
+        );
+        assert_ne!(
+            digest,
+            MovePackage::compute_digest(&modules[..1], &dependencies)
+        );
+        assert_ne!(
+            digest,
+            MovePackage::compute_digest(&modules, &dependencies[..1])
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use super::Argument;
+use super::Command;
+use super::Input;
+use super::MoveCall;
+use super::ProgrammableTransaction;
+use crate::types::Address;
+use crate::types::Identifier;
+use crate::types::ObjectId;
+
+impl ProgrammableTransaction {
+    /// Stake `coin`, a `Coin<SUI>`, with the validator at `validator`.
+    ///
+    /// `coin` is taken by value, so it can't be the gas coin: split the amount to stake off of
+    /// [`Argument::Gas`] with a `SplitCoins` command and pass its result instead.
+    ///
+    /// The resulting `0x3::staking_pool::StakedSui` is transferred to the sender, and becomes
+    /// active at the start of the next epoch.
+    pub fn request_add_stake(&mut self, coin: Argument, validator: Address) {
+        let system_state = self.system_state();
+        let validator = self.add_input(Input::Pure {
+            value: validator.as_bytes().to_vec(),
+        });
+        self.add_command(system_call(
+            "request_add_stake",
+            vec![system_state, coin, validator],
+        ));
+    }
+
+    /// Withdraw the stake held in `staked_sui`, a `StakedSui`, along with its rewards.
+    ///
+    /// The withdrawn SUI is transferred to the sender.
+    pub fn request_withdraw_stake(&mut self, staked_sui: Argument) {
+        let system_state = self.system_state();
+        self.add_command(system_call(
+            "request_withdraw_stake",
+            vec![system_state, staked_sui],
+        ));
+    }
+
+    /// Split `amount` MIST of principal from the `&mut StakedSui` held in `staked_sui`, returning
+    /// the argument holding the new `StakedSui`.
+    ///
+    /// Both the split off stake and the remainder must hold at least 1 SUI.
+    pub fn split_staked_sui(&mut self, staked_sui: Argument, amount: u64) -> Argument {
+        let amount = self.add_input(Input::Pure {
+            value: amount.to_le_bytes().to_vec(),
+        });
+        self.add_command(staking_pool_call("split", vec![staked_sui, amount]))
+    }
+
+    /// Merge the `StakedSui` held in `other` into the `&mut StakedSui` held in `staked_sui`.
+    ///
+    /// Both stakes must be in the same staking pool and have been activated in the same epoch.
+    pub fn join_staked_sui(&mut self, staked_sui: Argument, other: Argument) {
+        self.add_command(staking_pool_call(
+            "join_staked_sui",
+            vec![staked_sui, other],
+        ));
+    }
+
+    /// The argument holding the shared `0x3::sui_system::SuiSystemState`, adding it as an input
+    /// if it isn't one already.
+    fn system_state(&mut self) -> Argument {
+        const SUI_SYSTEM_STATE: Input = Input::Shared {
+            object_id: ObjectId::SYSTEM_STATE,
+            initial_shared_version: 1,
+            mutable: true,
+        };
+
+        match self
+            .inputs
+            .iter()
+            .position(|input| *input == SUI_SYSTEM_STATE)
+        {
+            Some(index) => Argument::Input(index as u16),
+            None => self.add_input(SUI_SYSTEM_STATE),
+        }
+    }
+}
+
+fn system_call(function: &str, arguments: Vec<Argument>) -> Command {
+    Command::MoveCall(MoveCall {
+        package: ObjectId::from(Address::THREE),
+        module: Identifier::new("sui_system").unwrap(),
+        function: Identifier::new(function).unwrap(),
+        type_arguments: vec![],
+        arguments,
+    })
+}
+
+fn staking_pool_call(function: &str, arguments: Vec<Argument>) -> Command {
+    Command::MoveCall(MoveCall {
+        package: ObjectId::from(Address::THREE),
+        module: Identifier::new("staking_pool").unwrap(),
+        function: Identifier::new(function).unwrap(),
+        type_arguments: vec![],
+        arguments,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::transaction::SplitCoins;
+    use crate::types::ObjectDigest;
+    use crate::types::ObjectReference;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn staking() {
+        let mut ptb = ProgrammableTransaction::default();
+        let staked_sui = ptb.add_input(Input::ImmutableOrOwned(ObjectReference::new(
+            ObjectId::new([1; 32]),
+            1,
+            ObjectDigest::ZERO,
+        )));
+        let other_staked_sui = ptb.add_input(Input::ImmutableOrOwned(ObjectReference::new(
+            ObjectId::new([2; 32]),
+            1,
+            ObjectDigest::ZERO,
+        )));
+        let amount = ptb.add_input(Input::Pure {
+            value: 1_000_000_000u64.to_le_bytes().to_vec(),
+        });
+        let coin = ptb
+            .add_command(Command::SplitCoins(SplitCoins {
+                coin: Argument::Gas,
+                amounts: vec![amount],
+            }))
+            .nested(0)
+            .unwrap();
+        ptb.request_add_stake(coin, Address::TWO);
+        let stake = ptb.split_staked_sui(staked_sui, 1_000_000_000);
+        ptb.join_staked_sui(other_staked_sui, stake);
+        ptb.request_withdraw_stake(other_staked_sui);
+
+        // The system state is only added as an input once
+        assert_eq!(ptb.inputs.len(), 6);
+        assert!(matches!(
+            ptb.inputs[3],
+            Input::Shared {
+                initial_shared_version: 1,
+                mutable: true,
+                ..
+            }
+        ));
+
+        let calls = ptb
+            .commands
+            .iter()
+            .skip(1)
+            .map(|command| match command {
+                Command::MoveCall(call) => (call.module.as_str(), call.function.as_str()),
+                _ => panic!("expected a move call"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            calls,
+            [
+                ("sui_system", "request_add_stake"),
+                ("staking_pool", "split"),
+                ("staking_pool", "join_staked_sui"),
+                ("sui_system", "request_withdraw_stake"),
+            ]
+        );
+
+        let Command::MoveCall(add_stake) = &ptb.commands[1] else {
+            unreachable!()
+        };
+        assert_eq!(
+            add_stake.arguments,
+            [
+                Argument::Input(3),
+                Argument::NestedResult(0, 0),
+                Argument::Input(4)
+            ]
+        );
+
+        let Command::MoveCall(split) = &ptb.commands[2] else {
+            unreachable!()
+        };
+        assert_eq!(split.arguments, [Argument::Input(0), Argument::Input(5)]);
+
+        let Command::MoveCall(join) = &ptb.commands[3] else {
+            unreachable!()
+        };
+        assert_eq!(join.arguments, [Argument::Input(1), Argument::Result(2)]);
+
+        let Command::MoveCall(withdraw) = &ptb.commands[4] else {
+            unreachable!()
+        };
+        assert_eq!(withdraw.arguments, [Argument::Input(3), Argument::Input(1)]);
+    }
+}
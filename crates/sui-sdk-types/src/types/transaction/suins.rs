@@ -0,0 +1,500 @@
+//! SuiNS names and name records, and helpers for building programmable transactions managing
+//! them.
+//!
+//! A [`SuinsName`] is a name such as `example.sui`, or a subname such as `sub.example.sui`, which
+//! can also be written `@example` and `sub@example`. The record of a name is stored in the
+//! registry of the SuiNS shared object, keyed by the name's [`domain
+//! labels`](SuinsName::domain_labels), and can be decoded with [`NameRecord::from_bcs`].
+//!
+//! The SuiNS packages differ from one network to another, so [`SuinsTransaction`] is given their
+//! addresses with a [`Suins`].
+
+use super::Argument;
+use super::Command;
+use super::Input;
+use super::MoveCall;
+use super::ProgrammableTransaction;
+use crate::types::framework::Reader;
+use crate::types::Address;
+use crate::types::Identifier;
+use crate::types::ObjectId;
+use crate::types::Version;
+
+/// The only top-level domain supported by SuiNS.
+const SUI_TLD: &str = "sui";
+
+/// The maximal length of a label of a name.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// A SuiNS name, such as `example.sui`, or a subname, such as `sub.example.sui`.
+///
+/// Names are parsed either in the dot format, `sub.example.sui`, or in the at format,
+/// `sub@example`, and are displayed in the dot format.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SuinsName {
+    /// The labels of the name, from the leaf to the top-level domain.
+    labels: Vec<String>,
+}
+
+impl SuinsName {
+    /// The labels of the name, from the leaf to the top-level domain, e.g. `["sub", "example",
+    /// "sui"]` for `sub.example.sui`.
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Whether this is a subname of another name, e.g. `sub.example.sui`.
+    pub fn is_subname(&self) -> bool {
+        self.labels.len() > 2
+    }
+
+    /// The name this is a subname of, e.g. `example.sui` for `sub.example.sui`, or `None` if this
+    /// is not a subname.
+    pub fn parent(&self) -> Option<Self> {
+        self.is_subname().then(|| Self {
+            labels: self.labels[1..].to_vec(),
+        })
+    }
+
+    /// The labels of the name in the layout of a `suins::domain::Domain`, from the top-level
+    /// domain to the leaf, under which the record of the name is stored in the registry.
+    pub fn domain_labels(&self) -> Vec<String> {
+        self.labels.iter().rev().cloned().collect()
+    }
+
+    /// The name in the dot format, e.g. `sub.example.sui`.
+    pub fn to_dot_format(&self) -> String {
+        self.labels.join(".")
+    }
+
+    /// The name in the at format, e.g. `sub@example`.
+    pub fn to_at_format(&self) -> String {
+        let (sld, subnames) = self.labels[..self.labels.len() - 1].split_last().unwrap();
+        let mut name = subnames.join(".");
+        name.push('@');
+        name.push_str(sld);
+        name
+    }
+}
+
+impl std::fmt::Display for SuinsName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_dot_format())
+    }
+}
+
+impl std::str::FromStr for SuinsName {
+    type Err = SuinsNameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase();
+        let labels: Vec<String> = match name.split_once('@') {
+            Some((subnames, sld)) => {
+                if sld.contains('.') {
+                    return Err(SuinsNameParseError::new(s));
+                }
+                let mut labels: Vec<_> = if subnames.is_empty() {
+                    vec![]
+                } else {
+                    subnames.split('.').map(ToOwned::to_owned).collect()
+                };
+                labels.push(sld.to_owned());
+                labels.push(SUI_TLD.to_owned());
+                labels
+            }
+            None => name.split('.').map(ToOwned::to_owned).collect(),
+        };
+
+        if labels.len() < 2
+            || labels.last().map(String::as_str) != Some(SUI_TLD)
+            || !labels.iter().all(|label| is_valid_label(label))
+        {
+            return Err(SuinsNameParseError::new(s));
+        }
+
+        Ok(Self { labels })
+    }
+}
+
+/// Whether `label` is a valid label of a name: 1 to 63 lowercase alphanumeric characters or
+/// hyphens, not starting or ending with a hyphen.
+fn is_valid_label(label: &str) -> bool {
+    (1..=MAX_LABEL_LENGTH).contains(&label.len())
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// An error parsing a [`SuinsName`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuinsNameParseError {
+    name: String,
+}
+
+impl SuinsNameParseError {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for SuinsNameParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid SuiNS name `{}`", self.name)
+    }
+}
+
+impl std::error::Error for SuinsNameParseError {}
+
+/// The record of a name in the SuiNS registry, a `suins::name_record::NameRecord`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameRecord {
+    /// The id of the registration NFT of the name.
+    pub nft_id: ObjectId,
+    /// The time the name expires at, in milliseconds since the unix epoch. Leaf subnames, which
+    /// expire with their parent, have an expiration of 0.
+    pub expiration_timestamp_ms: u64,
+    /// The address the name resolves to, if any.
+    pub target_address: Option<Address>,
+    /// The user data of the name, such as its `avatar`, in insertion order.
+    pub data: Vec<(String, String)>,
+}
+
+impl NameRecord {
+    /// The key of the user data holding the avatar of a name.
+    pub const AVATAR: &'static str = "avatar";
+    /// The key of the user data holding the content hash of a name.
+    pub const CONTENT_HASH: &'static str = "content_hash";
+
+    /// Decode a record from its BCS serialized value, e.g. the value of its dynamic field in the
+    /// registry.
+    ///
+    /// Returns `None` if the value is malformed.
+    pub fn from_bcs(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::new(bytes);
+        let nft_id = reader.id()?;
+        let expiration_timestamp_ms = reader.u64()?;
+        let target_address = reader.option(Reader::address)?;
+        let data =
+            reader.vector(|reader| Some((reader.str()?.to_owned(), reader.str()?.to_owned())))?;
+        reader.finish()?;
+
+        Some(Self {
+            nft_id,
+            expiration_timestamp_ms,
+            target_address,
+            data,
+        })
+    }
+
+    /// The value of the user data `key`, if set.
+    pub fn data(&self, key: &str) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The avatar of the name, the id of an NFT, if set.
+    pub fn avatar(&self) -> Option<&str> {
+        self.data(Self::AVATAR)
+    }
+
+    /// The content hash of the name, if set.
+    pub fn content_hash(&self) -> Option<&str> {
+        self.data(Self::CONTENT_HASH)
+    }
+
+    /// Whether this is the record of a leaf subname, which expires with its parent.
+    pub fn is_leaf(&self) -> bool {
+        self.expiration_timestamp_ms == 0
+    }
+
+    /// Whether the name has expired at `timestamp_ms`, in milliseconds since the unix epoch. The
+    /// expiration of a leaf subname is that of its parent, and isn't known from its record.
+    pub fn is_expired(&self, timestamp_ms: u64) -> bool {
+        !self.is_leaf() && self.expiration_timestamp_ms <= timestamp_ms
+    }
+}
+
+/// The addresses of the SuiNS packages and shared object on a network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Suins {
+    /// The package of the `controller` and `subdomain_registration` modules, managing the
+    /// records of names.
+    pub package: ObjectId,
+    /// The id of the shared `suins::suins::SuiNS` object.
+    pub object_id: ObjectId,
+    /// The initial shared version of the `SuiNS` object.
+    pub initial_shared_version: Version,
+}
+
+/// Builds commands managing SuiNS names.
+///
+/// Records are updated through the registration NFT of a name, a `suins_registration::
+/// SuinsRegistration`, which must be owned by the sender of the transaction. Subnames are
+/// registered with a `subdomain_registration::SubDomainRegistration` wrapping their NFT instead,
+/// which is borrowed with [`subname_nft`](Self::subname_nft) to update their records.
+pub struct SuinsTransaction<'a> {
+    ptb: &'a mut ProgrammableTransaction,
+    suins: Suins,
+}
+
+impl<'a> SuinsTransaction<'a> {
+    pub fn new(ptb: &'a mut ProgrammableTransaction, suins: Suins) -> Self {
+        Self { ptb, suins }
+    }
+
+    /// Set `name` as the default name of the sender, which it must resolve to.
+    pub fn set_default_name(&mut self, name: &SuinsName) {
+        let suins = self.suins_object();
+        let name = self.pure_string(&name.to_dot_format());
+        self.controller_call("set_reverse_lookup", vec![suins, name]);
+    }
+
+    /// Unset the default name of the sender.
+    pub fn unset_default_name(&mut self) {
+        let suins = self.suins_object();
+        self.controller_call("unset_reverse_lookup", vec![suins]);
+    }
+
+    /// Borrow the registration NFT of a subname from the `&SubDomainRegistration` held in
+    /// `subdomain`, returning the argument holding the `&SuinsRegistration` to pass to
+    /// [`set_target_address`](Self::set_target_address),
+    /// [`set_user_data`](Self::set_user_data) or [`unset_user_data`](Self::unset_user_data).
+    pub fn subname_nft(&mut self, subdomain: Argument) -> Argument {
+        self.ptb.add_command(Command::MoveCall(MoveCall {
+            package: self.suins.package,
+            module: Identifier::new("subdomain_registration").unwrap(),
+            function: Identifier::new("nft").unwrap(),
+            type_arguments: vec![],
+            arguments: vec![subdomain],
+        }))
+    }
+
+    /// Set the address the name of the registration NFT held in `nft` resolves to, or clear it if
+    /// `target_address` is `None`.
+    pub fn set_target_address(&mut self, nft: Argument, target_address: Option<Address>) {
+        let suins = self.suins_object();
+        let mut value = vec![u8::from(target_address.is_some())];
+        value.extend(
+            target_address
+                .iter()
+                .flat_map(|address| address.into_inner()),
+        );
+        let target_address = self.ptb.add_input(Input::Pure { value });
+        let clock = self.clock();
+        self.controller_call(
+            "set_target_address",
+            vec![suins, nft, target_address, clock],
+        );
+    }
+
+    /// Set the user data `key`, such as [`NameRecord::AVATAR`], of the name of the registration
+    /// NFT held in `nft` to `value`.
+    pub fn set_user_data(&mut self, nft: Argument, key: &str, value: &str) {
+        let suins = self.suins_object();
+        let key = self.pure_string(key);
+        let value = self.pure_string(value);
+        let clock = self.clock();
+        self.controller_call("set_user_data", vec![suins, nft, key, value, clock]);
+    }
+
+    /// Unset the user data `key` of the name of the registration NFT held in `nft`.
+    pub fn unset_user_data(&mut self, nft: Argument, key: &str) {
+        let suins = self.suins_object();
+        let key = self.pure_string(key);
+        let clock = self.clock();
+        self.controller_call("unset_user_data", vec![suins, nft, key, clock]);
+    }
+
+    fn controller_call(&mut self, function: &str, arguments: Vec<Argument>) {
+        self.ptb.add_command(Command::MoveCall(MoveCall {
+            package: self.suins.package,
+            module: Identifier::new("controller").unwrap(),
+            function: Identifier::new(function).unwrap(),
+            type_arguments: vec![],
+            arguments,
+        }));
+    }
+
+    /// The argument holding the `&mut SuiNS`, adding it as an input if it isn't one already.
+    fn suins_object(&mut self) -> Argument {
+        self.shared(Input::Shared {
+            object_id: self.suins.object_id,
+            initial_shared_version: self.suins.initial_shared_version,
+            mutable: true,
+        })
+    }
+
+    /// The argument holding the `&Clock`, adding it as an input if it isn't one already.
+    fn clock(&mut self) -> Argument {
+        self.shared(Input::Shared {
+            object_id: ObjectId::CLOCK,
+            initial_shared_version: 1,
+            mutable: false,
+        })
+    }
+
+    fn shared(&mut self, object: Input) -> Argument {
+        match self.ptb.inputs.iter().position(|input| *input == object) {
+            Some(index) => Argument::Input(index as u16),
+            None => self.ptb.add_input(object),
+        }
+    }
+
+    fn pure_string(&mut self, string: &str) -> Argument {
+        let mut value = Vec::with_capacity(string.len() + 2);
+        let mut len = string.len();
+        while len >= 0x80 {
+            value.push((len & 0x7f) as u8 | 0x80);
+            len >>= 7;
+        }
+        value.push(len as u8);
+        value.extend_from_slice(string.as_bytes());
+        self.ptb.add_input(Input::Pure { value })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn names() {
+        let name: SuinsName = "Sub.Example.sui".parse().unwrap();
+        assert_eq!(name, "sub@example".parse().unwrap());
+        assert_eq!(name.to_string(), "sub.example.sui");
+        assert_eq!(name.to_at_format(), "sub@example");
+        assert_eq!(name.domain_labels(), ["sui", "example", "sub"]);
+        assert!(name.is_subname());
+
+        let parent = name.parent().unwrap();
+        assert_eq!(parent, "@example".parse().unwrap());
+        assert_eq!(parent.to_at_format(), "@example");
+        assert!(!parent.is_subname());
+        assert_eq!(parent.parent(), None);
+
+        for invalid in [
+            "sui",
+            "example.move",
+            "-example.sui",
+            "exa_mple.sui",
+            "sub..example.sui",
+            "sub@example.sui",
+            "@",
+        ] {
+            assert!(invalid.parse::<SuinsName>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn name_record() {
+        let nft_id = ObjectId::new([7; 32]);
+        let mut bytes = nft_id.into_inner().to_vec();
+        bytes.extend(1_000u64.to_le_bytes());
+        bytes.push(1);
+        bytes.extend([8; 32]);
+        bytes.push(1);
+        bytes.extend([6].iter().chain(b"avatar"));
+        bytes.extend([4].iter().chain(b"0x42"));
+
+        let record = NameRecord::from_bcs(&bytes).unwrap();
+        assert_eq!(record.nft_id, nft_id);
+        assert_eq!(record.target_address, Some(Address::new([8; 32])));
+        assert_eq!(record.avatar(), Some("0x42"));
+        assert_eq!(record.content_hash(), None);
+        assert!(!record.is_expired(999));
+        assert!(record.is_expired(1_000));
+
+        assert_eq!(NameRecord::from_bcs(&bytes[..bytes.len() - 1]), None);
+        bytes.push(0);
+        assert_eq!(NameRecord::from_bcs(&bytes), None);
+    }
+
+    #[test]
+    fn manage_name() {
+        let suins = Suins {
+            package: "0x5c".parse().unwrap(),
+            object_id: "0x6e".parse().unwrap(),
+            initial_shared_version: 7,
+        };
+        let mut ptb = ProgrammableTransaction::default();
+        let nft = ptb.add_input(Input::ImmutableOrOwned(crate::types::ObjectReference::new(
+            ObjectId::ZERO,
+            1,
+            crate::types::ObjectDigest::ZERO,
+        )));
+
+        let mut transaction = SuinsTransaction::new(&mut ptb, suins);
+        transaction.set_target_address(nft, Some(Address::TWO));
+        transaction.set_user_data(nft, NameRecord::AVATAR, "0x42");
+        transaction.set_default_name(&"example.sui".parse().unwrap());
+
+        // The SuiNS object and the clock are only added once
+        let shared = ptb
+            .inputs
+            .iter()
+            .filter(|input| matches!(input, Input::Shared { .. }))
+            .count();
+        assert_eq!(shared, 2);
+        assert_eq!(
+            ptb.inputs[2],
+            Input::Pure {
+                value: [[1].as_slice(), Address::TWO.as_bytes()].concat()
+            }
+        );
+        assert_eq!(
+            ptb.inputs[6],
+            Input::Pure {
+                value: [[11].as_slice(), b"example.sui"].concat()
+            }
+        );
+
+        let Command::MoveCall(call) = &ptb.commands[2] else {
+            panic!("expected a move call");
+        };
+        assert_eq!(call.package, suins.package);
+        assert_eq!(call.function, *"set_reverse_lookup");
+        assert_eq!(call.arguments, [Argument::Input(1), Argument::Input(6)]);
+    }
+
+    #[test]
+    fn manage_subname() {
+        let suins = Suins {
+            package: "0x5c".parse().unwrap(),
+            object_id: "0x6e".parse().unwrap(),
+            initial_shared_version: 7,
+        };
+        let mut ptb = ProgrammableTransaction::default();
+        let subdomain = ptb.add_input(Input::ImmutableOrOwned(crate::types::ObjectReference::new(
+            ObjectId::ZERO,
+            1,
+            crate::types::ObjectDigest::ZERO,
+        )));
+
+        let mut transaction = SuinsTransaction::new(&mut ptb, suins);
+        let nft = transaction.subname_nft(subdomain);
+        transaction.unset_user_data(nft, NameRecord::AVATAR);
+
+        let calls = ptb
+            .commands
+            .iter()
+            .map(|command| match command {
+                Command::MoveCall(call) => call,
+                _ => panic!("expected a move call"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(calls[0].module, *"subdomain_registration");
+        assert_eq!(calls[0].function, *"nft");
+        assert_eq!(calls[0].arguments, [subdomain]);
+        assert_eq!(calls[1].function, *"unset_user_data");
+        assert_eq!(calls[1].arguments[1], Argument::Result(0));
+    }
+}
@@ -23,8 +23,22 @@ pub enum TypeTag {
     Struct(Box<StructTag>),
 }
 
-impl std::fmt::Display for TypeTag {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TypeTag {
+    /// Format this type with its addresses in their canonical long form, e.g.
+    /// `0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI`.
+    ///
+    /// This is the same as its `Display` implementation.
+    pub fn to_canonical_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Format this type with its addresses in their short form, without leading zeros, e.g.
+    /// `0x2::sui::SUI`.
+    pub fn to_short_string(&self) -> String {
+        Short(self).to_string()
+    }
+
+    fn fmt_with(&self, f: &mut std::fmt::Formatter<'_>, short: bool) -> std::fmt::Result {
         match self {
             TypeTag::U8 => write!(f, "u8"),
             TypeTag::U16 => write!(f, "u16"),
@@ -36,18 +50,26 @@ impl std::fmt::Display for TypeTag {
             TypeTag::Address => write!(f, "address"),
             TypeTag::Signer => write!(f, "signer"),
             TypeTag::Vector(t) => {
-                write!(f, "vector<{t}>")
+                write!(f, "vector<")?;
+                t.fmt_with(f, short)?;
+                write!(f, ">")
             }
-            TypeTag::Struct(s) => s.fmt(f),
+            TypeTag::Struct(s) => s.fmt_with(f, short),
         }
     }
 }
 
+impl std::fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with(f, false)
+    }
+}
+
 impl std::str::FromStr for TypeTag {
     type Err = TypeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse::parse_type_tag(s).map_err(|_| TypeParseError { source: s.into() })
+        parse::parse_type_tag(s, None).map_err(|_| TypeParseError { source: s.into() })
     }
 }
 
@@ -157,6 +179,37 @@ impl StructTag {
         }
     }
 
+    /// The type of the id of an object, `0x2::object::ID`.
+    pub fn object_id() -> Self {
+        Self {
+            address: Address::TWO,
+            module: Identifier::new("object").unwrap(),
+            name: Identifier::new("ID").unwrap(),
+            type_params: vec![],
+        }
+    }
+
+    /// The type of a dynamic field, `0x2::dynamic_field::Field<name, value>`.
+    pub fn dynamic_field(name: TypeTag, value: TypeTag) -> Self {
+        Self {
+            address: Address::TWO,
+            module: Identifier::new("dynamic_field").unwrap(),
+            name: Identifier::new("Field").unwrap(),
+            type_params: vec![name, value],
+        }
+    }
+
+    /// The type wrapping the name of a dynamic object field,
+    /// `0x2::dynamic_object_field::Wrapper<name>`.
+    pub fn dynamic_object_field_wrapper(name: TypeTag) -> Self {
+        Self {
+            address: Address::TWO,
+            module: Identifier::new("dynamic_object_field").unwrap(),
+            name: Identifier::new("Wrapper").unwrap(),
+            type_params: vec![name],
+        }
+    }
+
     /// Checks if this is a Coin type
     pub fn is_coin(&self) -> Option<&TypeTag> {
         let Self {
@@ -173,17 +226,57 @@ impl StructTag {
             None
         }
     }
+
+    /// Checks if this is a dynamic field type, returning the types of its name and value
+    pub fn is_dynamic_field(&self) -> Option<(&TypeTag, &TypeTag)> {
+        let Self {
+            address,
+            module,
+            name,
+            type_params,
+        } = self;
+
+        match type_params.as_slice() {
+            [name_type, value_type]
+                if address == &Address::TWO && module == "dynamic_field" && name == "Field" =>
+            {
+                Some((name_type, value_type))
+            }
+            _ => None,
+        }
+    }
 }
 
-impl std::fmt::Display for StructTag {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}::{}::{}", self.address, self.module, self.name)?;
+impl StructTag {
+    /// Format this type with its addresses in their canonical long form.
+    ///
+    /// This is the same as its `Display` implementation.
+    pub fn to_canonical_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Format this type with its addresses in their short form, without leading zeros, e.g.
+    /// `0x2::coin::Coin<0x2::sui::SUI>`.
+    pub fn to_short_string(&self) -> String {
+        Short(self).to_string()
+    }
+
+    fn fmt_with(&self, f: &mut std::fmt::Formatter<'_>, short: bool) -> std::fmt::Result {
+        if short {
+            let hex = self.address.to_string();
+            let digits = hex[2..].trim_start_matches('0');
+            write!(f, "0x{}", if digits.is_empty() { "0" } else { digits })?;
+        } else {
+            write!(f, "{}", self.address)?;
+        }
+        write!(f, "::{}::{}", self.module, self.name)?;
 
         if let Some(first_type) = self.type_params.first() {
             write!(f, "<")?;
-            write!(f, "{first_type}")?;
+            first_type.fmt_with(f, short)?;
             for ty in self.type_params.iter().skip(1) {
-                write!(f, ", {ty}")?;
+                write!(f, ", ")?;
+                ty.fmt_with(f, short)?;
             }
             write!(f, ">")?;
         }
@@ -191,10 +284,94 @@ impl std::fmt::Display for StructTag {
     }
 }
 
+impl std::fmt::Display for StructTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with(f, false)
+    }
+}
+
 impl std::str::FromStr for StructTag {
     type Err = TypeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse::parse_struct_tag(s).map_err(|_| TypeParseError { source: s.into() })
+        parse::parse_struct_tag(s, None).map_err(|_| TypeParseError { source: s.into() })
+    }
+}
+
+/// Displays a type with its addresses in their short form.
+struct Short<'a, T>(&'a T);
+
+impl std::fmt::Display for Short<'_, TypeTag> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_with(f, true)
+    }
+}
+
+impl std::fmt::Display for Short<'_, StructTag> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_with(f, true)
+    }
+}
+
+/// A set of named addresses which can be used in place of addresses when parsing types.
+///
+/// Named addresses are prefixed with `@`, e.g. `@sui::coin::Coin<@sui::sui::SUI>`. Names may
+/// contain `/`, `-` and `.`, so that packages registered with the Move Registry can be named by
+/// their MVR name, e.g. `@mysten/kiosk::kiosk::Kiosk`.
+///
+/// [`NamedAddresses::new`] starts out with the names of the framework packages: `std`, `sui`,
+/// `sui_system` and `bridge`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedAddresses {
+    addresses: std::collections::BTreeMap<String, Address>,
+}
+
+impl NamedAddresses {
+    pub fn new() -> Self {
+        let mut names = Self::empty();
+        names.insert("std", Address::ONE);
+        names.insert("sui", Address::TWO);
+        names.insert("sui_system", Address::THREE);
+        names.insert("bridge", Address::BRIDGE);
+        names
+    }
+
+    /// A set without any named address.
+    pub fn empty() -> Self {
+        Self {
+            addresses: Default::default(),
+        }
+    }
+
+    /// Name `address` `name`, returning the address previously named `name`, if any.
+    ///
+    /// A leading `@` in `name` is ignored.
+    pub fn insert(&mut self, name: &str, address: Address) -> Option<Address> {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.addresses.insert(name.to_owned(), address)
+    }
+
+    /// The address named `name`, if any.
+    ///
+    /// A leading `@` in `name` is ignored.
+    pub fn get(&self, name: &str) -> Option<Address> {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.addresses.get(name).copied()
+    }
+
+    /// Parse a [`TypeTag`], resolving the named addresses it contains.
+    pub fn parse_type_tag(&self, s: &str) -> Result<TypeTag, TypeParseError> {
+        parse::parse_type_tag(s, Some(self)).map_err(|_| TypeParseError { source: s.into() })
+    }
+
+    /// Parse a [`StructTag`], resolving the named addresses it contains.
+    pub fn parse_struct_tag(&self, s: &str) -> Result<StructTag, TypeParseError> {
+        parse::parse_struct_tag(s, Some(self)).map_err(|_| TypeParseError { source: s.into() })
+    }
+}
+
+impl Default for NamedAddresses {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -1,5 +1,6 @@
 use super::Address;
 use super::Identifier;
+use super::NamedAddresses;
 use super::StructTag;
 use super::TypeTag;
 
@@ -50,11 +51,41 @@ fn parse_address<'s>(input: &mut &'s str) -> PResult<&'s str> {
         .parse_next(input)
 }
 
-pub(super) fn parse_type_tag(mut input: &str) -> PResult<TypeTag> {
-    (type_tag, eof).parse_next(&mut input).map(|(t, _)| t)
+fn named_address<'s>(input: &mut &'s str) -> PResult<&'s str> {
+    (
+        '@',
+        take_while(
+            1..,
+            (
+                b'_',
+                b'-',
+                b'/',
+                b'.',
+                b'a'..=b'z',
+                b'A'..=b'Z',
+                b'0'..=b'9',
+            ),
+        ),
+    )
+        .map(|(_, name)| name)
+        .parse_next(input)
+}
+
+fn address(input: &mut &str, names: Option<&NamedAddresses>) -> PResult<Address> {
+    alt((
+        parse_address.try_map(|s| s.parse::<Address>()),
+        named_address.verify_map(|name| names?.get(name)),
+    ))
+    .parse_next(input)
+}
+
+pub(super) fn parse_type_tag(mut input: &str, names: Option<&NamedAddresses>) -> PResult<TypeTag> {
+    (|i: &mut &str| type_tag(i, names), eof)
+        .parse_next(&mut input)
+        .map(|(t, _)| t)
 }
 
-fn type_tag(input: &mut &str) -> PResult<TypeTag> {
+fn type_tag(input: &mut &str, names: Option<&NamedAddresses>) -> PResult<TypeTag> {
     alt((
         "u8".value(TypeTag::U8),
         "u16".value(TypeTag::U16),
@@ -65,19 +96,29 @@ fn type_tag(input: &mut &str) -> PResult<TypeTag> {
         "bool".value(TypeTag::Bool),
         "address".value(TypeTag::Address),
         "signer".value(TypeTag::Signer),
-        delimited("vector<", type_tag, ">").map(|ty| TypeTag::Vector(Box::new(ty))),
-        struct_tag.map(|s| TypeTag::Struct(Box::new(s))),
+        delimited(
+            ("vector<", space0),
+            |i: &mut &str| type_tag(i, names),
+            (space0, ">"),
+        )
+        .map(|ty| TypeTag::Vector(Box::new(ty))),
+        (|i: &mut &str| struct_tag(i, names)).map(|s| TypeTag::Struct(Box::new(s))),
     ))
     .parse_next(input)
 }
 
-pub(super) fn parse_struct_tag(mut input: &str) -> PResult<StructTag> {
-    (struct_tag, eof).parse_next(&mut input).map(|(s, _)| s)
+pub(super) fn parse_struct_tag(
+    mut input: &str,
+    names: Option<&NamedAddresses>,
+) -> PResult<StructTag> {
+    (|i: &mut &str| struct_tag(i, names), eof)
+        .parse_next(&mut input)
+        .map(|(s, _)| s)
 }
 
-fn struct_tag(input: &mut &str) -> PResult<StructTag> {
+fn struct_tag(input: &mut &str, names: Option<&NamedAddresses>) -> PResult<StructTag> {
     let (address, _, module, _, name) = (
-        parse_address.try_map(|s| s.parse::<Address>()),
+        |i: &mut &str| address(i, names),
         "::",
         identifier.map(|ident| Identifier(ident.into())),
         "::",
@@ -86,7 +127,7 @@ fn struct_tag(input: &mut &str) -> PResult<StructTag> {
         .parse_next(input)?;
 
     // optional generic
-    let generics = opt(delimited("<", generics, ">"))
+    let generics = opt(delimited("<", |i: &mut &str| generics(i, names), ">"))
         .parse_next(input)?
         .unwrap_or_default();
 
@@ -98,16 +139,21 @@ fn struct_tag(input: &mut &str) -> PResult<StructTag> {
     })
 }
 
-fn generics(input: &mut &str) -> PResult<Vec<TypeTag>> {
-    separated(1.., delimited(space0, type_tag, space0), ",").parse_next(input)
+fn generics(input: &mut &str, names: Option<&NamedAddresses>) -> PResult<Vec<TypeTag>> {
+    separated(
+        1..,
+        delimited(space0, |i: &mut &str| type_tag(i, names), space0),
+        ",",
+    )
+    .parse_next(input)
 }
 
-//TODO add proptests
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use std::str::FromStr;
+    use test_strategy::proptest;
 
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::wasm_bindgen_test as test;
@@ -153,7 +199,7 @@ mod tests {
             "0x1::__::__<0x2::_____::______fooo______, 0xff::Bar____::_______foo>",
             "0x5d32d749705c5f07c741f1818df3db466128bf01677611a959b03040ac5dc774::slippage::HopSwapEvent<0x2::sui::SUI, 0x3c86bba6a3d3ce958615ae51cc5604f58956b1583323f664cf5f048da0fcbb19::_spd::_SPD>",
         ] {
-            assert!(parse_type_tag(s).is_ok(), "Failed to parse tag {}", s);
+            assert!(parse_type_tag(s, None).is_ok(), "Failed to parse tag {}", s);
         }
     }
 
@@ -192,7 +238,7 @@ mod tests {
         for s in valid {
             let mut input = s;
             assert!(
-                dbg!((type_tag, eof).parse_next(&mut input)).is_ok(),
+                dbg!((|i: &mut &str| type_tag(i, None), eof).parse_next(&mut input)).is_ok(),
                 "Failed to parse struct {s}, remainder {input}",
             );
         }
@@ -219,7 +265,7 @@ mod tests {
         }
 
         for text in tests.iter().chain(instantiations.iter()) {
-            let st = parse_struct_tag(text).expect("valid StructTag");
+            let st = parse_struct_tag(text, None).expect("valid StructTag");
             assert_eq!(
                 st.to_string().replace(' ', ""),
                 text.replace(' ', "")
@@ -230,4 +276,83 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_named_addresses() {
+        let mut names = NamedAddresses::new();
+        let kiosk: Address = "0xabc".parse().unwrap();
+        names.insert("@mysten/kiosk", kiosk);
+
+        let coin = names
+            .parse_type_tag("@sui::coin::Coin<@sui::sui::SUI>")
+            .unwrap();
+        assert_eq!(
+            coin,
+            TypeTag::from_str("0x2::coin::Coin<0x2::sui::SUI>").unwrap()
+        );
+        assert_eq!(coin.to_short_string(), "0x2::coin::Coin<0x2::sui::SUI>");
+
+        let tag = names
+            .parse_struct_tag("@mysten/kiosk::kiosk::Kiosk<vector< @std::string::String >>")
+            .unwrap();
+        assert_eq!(tag.address, kiosk);
+        assert_eq!(
+            tag.to_short_string(),
+            "0xabc::kiosk::Kiosk<vector<0x1::string::String>>"
+        );
+
+        // Unknown names, or names without a registry, are rejected
+        names.parse_type_tag("@unknown::m::T").unwrap_err();
+        TypeTag::from_str("@sui::sui::SUI").unwrap_err();
+        NamedAddresses::empty()
+            .parse_type_tag("@sui::sui::SUI")
+            .unwrap_err();
+    }
+
+    #[proptest]
+    fn roundtrip_display_fromstr(type_tag: TypeTag) {
+        let long = type_tag.to_canonical_string();
+        assert_eq!(TypeTag::from_str(&long).unwrap(), type_tag);
+        let short = type_tag.to_short_string();
+        assert_eq!(TypeTag::from_str(&short).unwrap(), type_tag);
+        assert_eq!(
+            NamedAddresses::new().parse_type_tag(&short).unwrap(),
+            type_tag
+        );
+    }
+
+    #[proptest]
+    fn roundtrip_nested_display_fromstr(struct_tag: StructTag, type_param: TypeTag) {
+        // Nest types to cover vectors and generics, which aren't generated by the strategies
+        let type_tag = TypeTag::Struct(Box::new(StructTag {
+            type_params: vec![
+                TypeTag::Vector(Box::new(type_param.clone())),
+                TypeTag::Struct(Box::new(struct_tag.clone())),
+                type_param,
+            ],
+            ..struct_tag
+        }));
+        assert_eq!(
+            TypeTag::from_str(&type_tag.to_canonical_string()).unwrap(),
+            type_tag
+        );
+        assert_eq!(
+            TypeTag::from_str(&type_tag.to_short_string()).unwrap(),
+            type_tag
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[proptest]
+    fn roundtrip_serializer(type_tag: TypeTag) {
+        // The human readable serializer accepts both display forms
+        let json = serde_json::to_value(&type_tag).unwrap();
+        assert_eq!(json, serde_json::json!(type_tag.to_canonical_string()));
+        let short: TypeTag =
+            serde_json::from_value(serde_json::json!(type_tag.to_short_string())).unwrap();
+        assert_eq!(short, type_tag);
+
+        let bcs = bcs::to_bytes(&TypeTag::from_str(&type_tag.to_short_string()).unwrap()).unwrap();
+        assert_eq!(bcs, bcs::to_bytes(&type_tag).unwrap());
+    }
 }